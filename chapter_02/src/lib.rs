@@ -1,69 +1,42 @@
 #![no_std]
 
-use core::ffi::c_void;
+pub mod pool_tags;
 
 use wdk::println;
+use wdk_strings::{Pool, UnicodeString};
+use windows_drivers_util::{Driver, NtResult, driver_entry};
 use wdk_sys::{
-   DRIVER_OBJECT, NTSTATUS, PCUNICODE_STRING, PDRIVER_OBJECT, POOL_FLAG_PAGED, RTL_OSVERSIONINFOW, SIZE_T, STATUS_INSUFFICIENT_RESOURCES, STATUS_INVALID_PARAMETER, STATUS_SUCCESS, UNICODE_STRING, ntddk::{ExAllocatePool2, ExFreePool, RtlCopyUnicodeString, RtlGetVersion}
+   DRIVER_OBJECT, RTL_OSVERSIONINFOW, STATUS_INSUFFICIENT_RESOURCES, UNICODE_STRING, ntddk::RtlGetVersion
 };
 
-#[cfg(not(test))]
-extern crate wdk_panic;
+use crate::pool_tags::DRIVER_TAG;
 
-#[cfg(not(test))]
-use wdk_alloc::WdkAllocator;
-
-#[cfg(not(test))]
-#[global_allocator]
-static GLOBAL_ALLOCATOR: WdkAllocator = WdkAllocator;
-
-const DRIVER_TAG: u32 = u32::from_ne_bytes(*b"dcba");
-
-static mut REGISTRY_PATH: UNICODE_STRING = UNICODE_STRING {
-   Length: 0,
-   MaximumLength: 0,
-   Buffer: core::ptr::null_mut(),
-};
-
-unsafe extern "C" fn sample_unload(_driver: PDRIVER_OBJECT) {
-	unsafe {
-        ExFreePool(REGISTRY_PATH.Buffer as *mut c_void);
-	    println!("Sample driver Unload called");
-    }
+struct Sample {
+    /// Dropping the copy returns its buffer to the pool.
+    _registry_path: UnicodeString,
 }
 
-// SAFETY: "DriverEntry" is the required symbol name for Windows driver entry points.
-// No other function in this compilation unit exports this name, preventing symbol conflicts.
-#[unsafe(export_name = "DriverEntry")] // WDF expects a symbol with the name DriverEntrypub unsafe extern "system" fn 
-#[allow(static_mut_refs)]
-fn driver_entry(
-   driver: &mut DRIVER_OBJECT,
-   registry_path: PCUNICODE_STRING,
-) -> NTSTATUS {
-    unsafe {
-        let Some(registry_path) = registry_path.as_ref() else {
-            return STATUS_INVALID_PARAMETER;
-        };
-
-        REGISTRY_PATH.Buffer = ExAllocatePool2(POOL_FLAG_PAGED, registry_path.Length as SIZE_T, DRIVER_TAG) as *mut u16;
-        if REGISTRY_PATH.Buffer.is_null() {
+impl Driver for Sample {
+    fn init(_driver: &mut DRIVER_OBJECT, registry_path: &UNICODE_STRING) -> NtResult<Self> {
+        let Ok(copy) = (unsafe { UnicodeString::copy_from_raw_in(registry_path, Pool::paged(DRIVER_TAG)) }) else {
             println!("Failed to allocate memory");
-            return STATUS_INSUFFICIENT_RESOURCES;
-        }
+            return Err(STATUS_INSUFFICIENT_RESOURCES);
+        };
 
-        REGISTRY_PATH.MaximumLength = registry_path.Length;
-        RtlCopyUnicodeString(&mut REGISTRY_PATH, registry_path);
         println!("original registry path: {:?}", registry_path);
-        println!("Copied registry path: {:?}", &REGISTRY_PATH);
+        println!("Copied registry path: {}", copy);
 
-        driver.DriverUnload = Some(sample_unload);
-        
         let mut info = RTL_OSVERSIONINFOW::default();
-        let _ = RtlGetVersion(&mut info);
+        let _ = unsafe { RtlGetVersion(&mut info) };
         println!("Windows version: {}.{}.{}", info.dwMajorVersion, info.dwMinorVersion, info.dwBuildNumber);
 
         println!("Sample driver initialized successfully");
+        Ok(Self { _registry_path: copy })
+    }
+
+    fn unload(self) {
+        println!("Sample driver Unload called");
     }
-    
-    STATUS_SUCCESS
 }
+
+driver_entry!(Sample);
@@ -0,0 +1,11 @@
+//! The pool tags of the driver. `pooltag-export` includes this module to
+//! write their `pooltag.txt` entries, as the driver itself only builds for
+//! the kernel.
+
+use windows_driver_common_util::pool_tag;
+use windows_driver_common_util::pool_tag::PoolTag;
+
+/// Tag of the copy of the registry path.
+pub const DRIVER_TAG: PoolTag = pool_tag!("dcba");
+
+pub const POOL_TAGS: &[(PoolTag, &str)] = &[(DRIVER_TAG, "Copy of the registry path")];
@@ -8,6 +8,7 @@ use windows::Win32::System::Threading::Sleep;
 use windows::Win32::System::WindowsProgramming::RtlInitUnicodeString;
 use windows::Win32::System::IO::{DeviceIoControl, IO_STATUS_BLOCK};
 use windows::Wdk::Storage::FileSystem::NtOpenFile;
+use windows_driver_common_util::status::NtStatus;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -81,7 +82,7 @@ fn main() {
             Sleep(duration);
         }
         else {
-            eprintln!("Failed in NtOpenFile (status=0x{:X})", status.0);
+            eprintln!("Failed in NtOpenFile (status={})", NtStatus::from(status));
         }
     }
 }
@@ -2,6 +2,9 @@
 
 use core::ffi::c_int;
 
+use windows_driver_common_util::wire::WireStruct;
+
+#[derive(WireStruct, Clone, Copy)]
 #[repr(C)]
 pub struct ThreadData {
     pub thread_id: u32,
@@ -0,0 +1,12 @@
+use booster_common::ThreadData;
+use windows_driver_common_util::wire::WireStruct;
+
+/// Changing this snapshot breaks clients built against the old layout.
+#[test]
+fn thread_data_layout_is_stable() {
+    assert_eq!(
+        ThreadData::layout().to_string(),
+        "{ thread_id: u32 @ 0, priority: c_int @ 4 } size 8 align 4"
+    );
+    assert_eq!(ThreadData::FINGERPRINT, 0x0e50_a1b8_ae17_456f);
+}
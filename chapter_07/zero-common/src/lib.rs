@@ -1,21 +1,35 @@
 #![no_std]
 
-#[cfg(feature = "kernel")]
-pub use wdk_sys::{
-    METHOD_BUFFERED, METHOD_NEITHER, FILE_ANY_ACCESS,
-};
-#[cfg(feature = "user")]
-pub use windows::Win32::System::Ioctl::{
-    METHOD_BUFFERED, METHOD_NEITHER, FILE_ANY_ACCESS,
-};
-use windows_driver_common_util::ctl_code;
+use wdk_strings::sys::{PCWSTR, UNICODE_STRING};
+use wdk_strings::u;
+use windows_driver_common_util::define_ioctls;
+use windows_driver_common_util::ioctl::{FILE_ANY_ACCESS, METHOD_BUFFERED, METHOD_NEITHER};
+use windows_driver_common_util::wire::WireStruct;
+
+/// The name shared by the device object and its symbolic link.
+pub const DEVICE: &str = "Zero";
+
+pub const DEVICE_NAME: UNICODE_STRING = u!(r"\Device\", DEVICE);
+pub const DEVICE_SYMLINK: UNICODE_STRING = u!(r"\??\", DEVICE);
+/// The path user mode opens the device with.
+pub const DEVICE_PATH: PCWSTR = u!(pcwstr: r"\\.\", DEVICE);
 
 pub const DEVICE_ZERO: u32 = 0x8022;
 
-pub const IOCTL_ZERO_GET_STATS: u32 = ctl_code!(DEVICE_ZERO, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS);
-pub const IOCTL_ZERO_CLEAR_STATS: u32 = ctl_code!(DEVICE_ZERO, 0x801, METHOD_NEITHER, FILE_ANY_ACCESS);
+define_ioctls! {
+    device_type = DEVICE_ZERO;
+    /// Device control requests of the Zero device.
+    pub enum ZeroRequest {
+        /// Returns the number of bytes read from and written to the device.
+        IOCTL_ZERO_GET_STATS = (0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)
+            => GetStats -> ZeroStats, fn get_stats;
+        /// Resets the counters.
+        IOCTL_ZERO_CLEAR_STATS = (0x801, METHOD_NEITHER, FILE_ANY_ACCESS)
+            => ClearStats, fn clear_stats, user_pointers = false;
+    }
+}
 
-#[derive(Default)]
+#[derive(WireStruct, Default, Clone, Copy)]
 #[repr(C)]
 pub struct ZeroStats {
     pub total_read: u64,
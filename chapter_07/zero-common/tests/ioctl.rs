@@ -0,0 +1,25 @@
+use windows_driver_common_util::ioctl::{IoctlCode, RequiredAccess, TransferMethod};
+use zero_common::{
+    DEVICE_ZERO, IOCTL_ZERO_CLEAR_STATS, IOCTL_ZERO_GET_STATS, ZeroRequest, ZeroStats,
+};
+
+#[test]
+fn codes_match_the_c_headers() {
+    assert_eq!(IOCTL_ZERO_GET_STATS, 0x8022_2000);
+    assert_eq!(IOCTL_ZERO_CLEAR_STATS, 0x8022_2007);
+
+    let clear = IoctlCode::from_raw(IOCTL_ZERO_CLEAR_STATS);
+    assert_eq!(u32::from(clear.device_type()), DEVICE_ZERO);
+    assert_eq!(clear.method(), TransferMethod::Neither);
+    assert_eq!(clear.access(), RequiredAccess::Any);
+}
+
+#[test]
+fn get_stats_returns_the_counters() {
+    assert_eq!(ZeroRequest::GetStats.code(), IOCTL_ZERO_GET_STATS);
+    assert_eq!(
+        ZeroRequest::GetStats.output_size(),
+        core::mem::size_of::<ZeroStats>()
+    );
+    assert_eq!(ZeroRequest::ClearStats.output_size(), 0);
+}
@@ -0,0 +1,12 @@
+use windows_driver_common_util::wire::WireStruct;
+use zero_common::ZeroStats;
+
+/// Changing this snapshot breaks clients built against the old layout.
+#[test]
+fn zero_stats_layout_is_stable() {
+    assert_eq!(
+        ZeroStats::layout().to_string(),
+        "{ total_read: u64 @ 0, total_written: u64 @ 8 } size 16 align 8"
+    );
+    assert_eq!(ZeroStats::FINGERPRINT, 0xb8e4_a16b_b995_2aa9);
+}
@@ -3,11 +3,7 @@ use windows::Win32::Storage::FileSystem::{
     CreateFileW, FILE_FLAGS_AND_ATTRIBUTES, FILE_SHARE_MODE, OPEN_EXISTING, WriteFile,
     ReadFile,
 };
-use windows::Win32::System::IO::DeviceIoControl;
-use windows_strings::{PCWSTR, w};
-use zero_common::{IOCTL_ZERO_GET_STATS, ZeroStats};
-
-const DEVICE_PATH: PCWSTR = w!(r"\\.\Zero");
+use zero_common::{DEVICE_PATH, get_stats};
 
 /// A wrapper around HANDLE that ensures the handle is closed when dropped.
 struct Handle(HANDLE);
@@ -81,21 +77,7 @@ fn main() {
             );
         }
 
-        let mut stats = ZeroStats::default();
-        let mut bytes_read = 0;
-        DeviceIoControl(
-            device.0,
-            IOCTL_ZERO_GET_STATS,
-            None,
-            0,
-            Some(&mut stats as *mut _ as *mut core::ffi::c_void),
-            core::mem::size_of::<ZeroStats>() as u32,
-            Some(&mut bytes_read),
-            None,
-        )
-        .expect("failed in DeviceIoControl");
-
-        debug_assert_eq!(bytes_read as usize, core::mem::size_of::<ZeroStats>());
+        let stats = get_stats(device.0).expect("failed to get stats");
         println!("Total Read: {}, Total Write: {}", stats.total_read, stats.total_written);
     }
 }
@@ -0,0 +1,124 @@
+//! Consistency checks over the IOCTLs the `*-common` crates declare with
+//! `define_ioctls!`, which the compiler cannot see across crates: two
+//! drivers claiming the same code, transfer methods contradicting the
+//! declared buffers, and function numbers reserved for Microsoft.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use windows_driver_common_util::ioctl::{IoctlInfo, TransferMethod};
+
+/// The lowest function number available outside Microsoft.
+pub const FIRST_CUSTOM_FUNCTION: u16 = 0x800;
+
+/// The crates checked by the `ioctl-check` binary.
+pub const SOURCES: &[Source] = &[Source {
+    name: "zero-common",
+    ioctls: zero_common::ZeroRequest::IOCTLS,
+}];
+
+/// The IOCTLs declared by a crate.
+#[derive(Debug, Clone, Copy)]
+pub struct Source {
+    pub name: &'static str,
+    pub ioctls: &'static [IoctlInfo],
+}
+
+/// An IOCTL and the crate declaring it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub source: &'static str,
+    pub info: IoctlInfo,
+}
+
+/// Writes `zero-common::IOCTL_ZERO_GET_STATS`.
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.source, self.info.name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// Two IOCTLs have the same code, so a driver cannot tell them apart.
+    DuplicateCode { first: Entry, second: Entry },
+    /// The transfer method contradicts the declared buffers.
+    MethodMismatch { entry: Entry, reason: &'static str },
+    /// The function number is below [`FIRST_CUSTOM_FUNCTION`].
+    ReservedFunction { entry: Entry },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::DuplicateCode { first, second } => write!(
+                f,
+                "{first} and {second} share the code {:#010x}, {}",
+                first.info.code.as_u32(),
+                first.info.code
+            ),
+            Problem::MethodMismatch { entry, reason } => write!(
+                f,
+                "{entry} uses {}, but {reason}",
+                entry.info.code.method().name()
+            ),
+            Problem::ReservedFunction { entry } => write!(
+                f,
+                "{entry} uses function {:#x}, but functions below {FIRST_CUSTOM_FUNCTION:#x} are reserved for Microsoft",
+                entry.info.code.function()
+            ),
+        }
+    }
+}
+
+/// Checks the IOCTLs of all `sources` and returns the problems found, in
+/// declaration order.
+pub fn check(sources: &[Source]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut codes = HashMap::new();
+    for source in sources {
+        for &info in source.ioctls {
+            let entry = Entry {
+                source: source.name,
+                info,
+            };
+            if let Some(&first) = codes.get(&info.code.as_u32()) {
+                problems.push(Problem::DuplicateCode {
+                    first,
+                    second: entry,
+                });
+            } else {
+                codes.insert(info.code.as_u32(), entry);
+            }
+            if let Some(reason) = method_mismatch(&info) {
+                problems.push(Problem::MethodMismatch { entry, reason });
+            }
+            if info.code.function() < FIRST_CUSTOM_FUNCTION {
+                problems.push(Problem::ReservedFunction { entry });
+            }
+        }
+    }
+    problems
+}
+
+/// The rules of `ioctl::check`, which `define_ioctls!` enforces at compile
+/// time, plus those that only apply to the buffers actually declared.
+fn method_mismatch(info: &IoctlInfo) -> Option<&'static str> {
+    match (info.code.method(), info.user_pointers) {
+        (TransferMethod::Neither, None) => {
+            Some("it does not declare whether it carries user pointers")
+        }
+        (TransferMethod::Neither, Some(false)) if info.input_size != 0 || info.output_size != 0 => {
+            Some("it has buffers without carrying user pointers")
+        }
+        (TransferMethod::Neither, Some(true)) if info.output_size != 0 => {
+            Some("drivers cannot write its output to user memory safely")
+        }
+        (TransferMethod::Neither, _) => None,
+        (_, Some(_)) => Some("only METHOD_NEITHER ioctls can carry user pointers"),
+        (TransferMethod::InDirect | TransferMethod::OutDirect, None) if info.output_size == 0 => {
+            Some("it declares no output buffer for the MDL to describe")
+        }
+        _ => None,
+    }
+}
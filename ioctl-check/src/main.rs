@@ -0,0 +1,22 @@
+//! Checks the IOCTLs of the `*-common` crates and exits with an error if any
+//! of them collide or are inconsistent.
+
+use std::process::ExitCode;
+
+use ioctl_check::{SOURCES, check};
+
+fn main() -> ExitCode {
+    let problems = check(SOURCES);
+    for problem in &problems {
+        eprintln!("error: {problem}");
+    }
+
+    let count: usize = SOURCES.iter().map(|source| source.ioctls.len()).sum();
+    if problems.is_empty() {
+        println!("{count} IOCTLs checked, no problems");
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{} problems in {count} IOCTLs", problems.len());
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,125 @@
+use ioctl_check::{Entry, Problem, SOURCES, Source, check};
+use windows_driver_common_util::define_ioctls;
+use windows_driver_common_util::ioctl::{
+    FILE_ANY_ACCESS, IoctlCode, IoctlInfo, METHOD_BUFFERED, METHOD_IN_DIRECT, METHOD_NEITHER,
+};
+use zero_common::{IOCTL_ZERO_GET_STATS, ZeroRequest};
+
+define_ioctls! {
+    device_type = 0x8022;
+    pub enum OtherRequest {
+        IOCTL_OTHER_GET_STATS = (0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)
+            => OtherGetStats -> u32, fn other_get_stats;
+        IOCTL_OTHER_RESET = (0x7ff, METHOD_BUFFERED, FILE_ANY_ACCESS)
+            => OtherReset, fn other_reset;
+    }
+}
+
+const fn info(
+    name: &'static str,
+    function: u32,
+    method: u32,
+    user_pointers: Option<bool>,
+) -> IoctlInfo {
+    IoctlInfo {
+        name,
+        code: IoctlCode::new(0x8023, function, method, FILE_ANY_ACCESS),
+        input_size: 4,
+        output_size: 0,
+        user_pointers,
+    }
+}
+
+#[test]
+fn workspace_is_consistent() {
+    let problems = check(SOURCES);
+    assert!(problems.is_empty(), "{problems:#?}");
+}
+
+#[test]
+fn reports_duplicates_and_reserved_functions() {
+    let sources = [
+        Source {
+            name: "zero-common",
+            ioctls: ZeroRequest::IOCTLS,
+        },
+        Source {
+            name: "other-common",
+            ioctls: OtherRequest::IOCTLS,
+        },
+    ];
+    let problems = check(&sources);
+    assert_eq!(
+        problems,
+        [
+            Problem::DuplicateCode {
+                first: Entry {
+                    source: "zero-common",
+                    info: ZeroRequest::IOCTLS[0],
+                },
+                second: Entry {
+                    source: "other-common",
+                    info: OtherRequest::IOCTLS[0],
+                },
+            },
+            Problem::ReservedFunction {
+                entry: Entry {
+                    source: "other-common",
+                    info: OtherRequest::IOCTLS[1],
+                },
+            },
+        ]
+    );
+    assert_eq!(IOCTL_OTHER_GET_STATS, IOCTL_ZERO_GET_STATS);
+    assert_eq!(
+        problems[0].to_string(),
+        "zero-common::IOCTL_ZERO_GET_STATS and other-common::IOCTL_OTHER_GET_STATS share the code \
+         0x80222000, CTL_CODE(0x8022, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)"
+    );
+    assert_eq!(
+        problems[1].to_string(),
+        "other-common::IOCTL_OTHER_RESET uses function 0x7ff, but functions below 0x800 are \
+         reserved for Microsoft"
+    );
+}
+
+#[test]
+fn reports_method_mismatches() {
+    static IOCTLS: [IoctlInfo; 5] = [
+        info("IOCTL_UNDECLARED", 0x900, METHOD_NEITHER, None),
+        info("IOCTL_NO_POINTERS", 0x901, METHOD_NEITHER, Some(false)),
+        info(
+            "IOCTL_BUFFERED_POINTERS",
+            0x902,
+            METHOD_BUFFERED,
+            Some(true),
+        ),
+        info("IOCTL_DIRECT", 0x903, METHOD_IN_DIRECT, None),
+        IoctlInfo {
+            output_size: 8,
+            ..info("IOCTL_USER_OUTPUT", 0x904, METHOD_NEITHER, Some(true))
+        },
+    ];
+    let problems: Vec<_> = check(&[Source {
+        name: "test",
+        ioctls: &IOCTLS,
+    }])
+    .iter()
+    .map(ToString::to_string)
+    .collect();
+    assert_eq!(
+        problems,
+        [
+            "test::IOCTL_UNDECLARED uses METHOD_NEITHER, but it does not declare whether it \
+             carries user pointers",
+            "test::IOCTL_NO_POINTERS uses METHOD_NEITHER, but it has buffers without carrying \
+             user pointers",
+            "test::IOCTL_BUFFERED_POINTERS uses METHOD_BUFFERED, but only METHOD_NEITHER ioctls \
+             can carry user pointers",
+            "test::IOCTL_DIRECT uses METHOD_IN_DIRECT, but it declares no output buffer for the \
+             MDL to describe",
+            "test::IOCTL_USER_OUTPUT uses METHOD_NEITHER, but drivers cannot write its output \
+             to user memory safely",
+        ]
+    );
+}
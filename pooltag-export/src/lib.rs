@@ -0,0 +1,77 @@
+//! The `pooltag.txt` entries for the pool tags the crates of the repository
+//! list in their `POOL_TAGS`, so pool usage can be attributed in WinDbg's
+//! `!poolused` and PoolMon.
+
+use std::fmt;
+use std::io::{self, Write};
+
+use windows_driver_common_util::pool_tag::PoolTag;
+
+/// The driver crate only builds for the kernel, so its list is included
+/// directly.
+#[path = "../../chapter_02/src/pool_tags.rs"]
+pub mod chapter_02;
+
+/// The crates exported by the `pooltag-export` binary.
+pub const SOURCES: &[Source] = &[
+    Source {
+        binary: "chapter_02.sys",
+        tags: chapter_02::POOL_TAGS,
+    },
+    Source {
+        binary: "wdk-strings",
+        tags: wdk_strings::POOL_TAGS,
+    },
+];
+
+/// The pool tags of a crate, with the binary they are attributed to: the
+/// driver, e.g. `zero.sys`, or the name of a library crate.
+#[derive(Debug, Clone, Copy)]
+pub struct Source {
+    pub binary: &'static str,
+    pub tags: &'static [(PoolTag, &'static str)],
+}
+
+/// One line of `pooltag.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Entry {
+    pub tag: PoolTag,
+    pub binary: &'static str,
+    pub description: &'static str,
+}
+
+/// Writes `dcba - chapter_02.sys - Copy of the registry path`, leaving out
+/// an empty description.
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.tag, self.binary)?;
+        if !self.description.is_empty() {
+            write!(f, " - {}", self.description)?;
+        }
+        Ok(())
+    }
+}
+
+/// The entries of all `sources`, sorted by tag and binary.
+pub fn entries(sources: &[Source]) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = sources
+        .iter()
+        .flat_map(|source| {
+            source.tags.iter().map(|&(tag, description)| Entry {
+                tag,
+                binary: source.binary,
+                description,
+            })
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+/// Writes `entries` in the format of the debugger's `pooltag.txt`.
+pub fn write_pooltag_txt(entries: &[Entry], out: &mut impl Write) -> io::Result<()> {
+    for entry in entries {
+        writeln!(out, "{entry}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Writes the `pooltag.txt` entries for the pool tags the crates of the
+//! repository declare, to the given file or to stdout.
+
+use std::io;
+use std::process::ExitCode;
+
+use pooltag_export::{SOURCES, entries, write_pooltag_txt};
+
+fn main() -> ExitCode {
+    let entries = entries(SOURCES);
+    let result = match std::env::args_os().nth(1) {
+        Some(path) => {
+            std::fs::File::create(path).and_then(|mut file| write_pooltag_txt(&entries, &mut file))
+        }
+        None => write_pooltag_txt(&entries, &mut io::stdout().lock()),
+    };
+
+    match result {
+        Ok(()) => {
+            eprintln!("{} pool tags exported", entries.len());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use pooltag_export::{Entry, SOURCES, Source, entries, write_pooltag_txt};
+use windows_driver_common_util::pool_tag;
+
+fn pooltag_txt(entries: &[Entry]) -> String {
+    let mut out = Vec::new();
+    write_pooltag_txt(entries, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn exports_the_tags_of_drivers_and_libraries() {
+    assert_eq!(
+        pooltag_txt(&entries(SOURCES)),
+        "dcba - chapter_02.sys - Copy of the registry path\n\
+         UStr - wdk-strings - wdk-strings buffers of the default Pool\n"
+    );
+}
+
+#[test]
+fn sorts_by_tag_and_binary() {
+    let sources = [
+        Source {
+            binary: "zero.sys",
+            tags: &[(pool_tag!("Zero"), "Statistics"), (pool_tag!("dcba"), "")],
+        },
+        Source {
+            binary: "booster.sys",
+            tags: &[(pool_tag!("dcba"), "Thread list")],
+        },
+    ];
+    assert_eq!(
+        pooltag_txt(&entries(&sources)),
+        "dcba - booster.sys - Thread list\n\
+         dcba - zero.sys\n\
+         Zero - zero.sys - Statistics\n"
+    );
+}
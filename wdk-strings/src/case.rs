@@ -0,0 +1,99 @@
+//! Case-insensitive comparison with the semantics of the kernel's `Rtl*`
+//! string routines, implemented without calling into ntoskrnl.
+//!
+//! The kernel folds case one UTF-16 code unit at a time using the upcase
+//! table in its NLS data. [`upcase`] reproduces that table: the simple
+//! (one-to-one) uppercase mapping of every BMP character as of Unicode 6.0,
+//! when the table was frozen, generated by `tools/upcase_table.pl`. Case
+//! pairs added in later Unicode versions and surrogates are left unchanged.
+//! Mappings that expand to several characters, such as `ß` → `SS`, are not
+//! applied, just like in the kernel.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::upcase_table::UPCASE_RUNS;
+
+/// Upcases a single UTF-16 code unit like `RtlUpcaseUnicodeChar`.
+pub fn upcase(unit: u16) -> u16 {
+    if unit < 0x80 {
+        return (unit as u8).to_ascii_uppercase() as u16;
+    }
+    let index = UPCASE_RUNS.partition_point(|&(_, last, _, _)| last < unit);
+    match UPCASE_RUNS.get(index) {
+        Some(&(first, _, step, offset)) if first <= unit && (unit - first).is_multiple_of(step) => {
+            unit.wrapping_add(offset)
+        }
+        _ => unit,
+    }
+}
+
+fn fold(unit: u16, case_insensitive: bool) -> u16 {
+    if case_insensitive { upcase(unit) } else { unit }
+}
+
+/// Compares two strings like `RtlCompareUnicodeString`.
+///
+/// Characters are compared by code unit value (after upcasing if
+/// `case_insensitive` is set); if one string is a prefix of the other, the
+/// shorter one orders first.
+pub fn compare(a: &[u16], b: &[u16], case_insensitive: bool) -> Ordering {
+    let folded_a = a.iter().map(|&unit| fold(unit, case_insensitive));
+    let folded_b = b.iter().map(|&unit| fold(unit, case_insensitive));
+    folded_a.cmp(folded_b)
+}
+
+/// Checks two strings for equality like `RtlEqualUnicodeString`.
+pub fn equal(a: &[u16], b: &[u16], case_insensitive: bool) -> bool {
+    a.len() == b.len() && compare(a, b, case_insensitive) == Ordering::Equal
+}
+
+/// Checks whether `prefix` is a prefix of `s` like `RtlPrefixUnicodeString`.
+pub fn has_prefix(prefix: &[u16], s: &[u16], case_insensitive: bool) -> bool {
+    s.len() >= prefix.len() && equal(prefix, &s[..prefix.len()], case_insensitive)
+}
+
+/// Checks whether `suffix` is a suffix of `s`.
+pub fn has_suffix(suffix: &[u16], s: &[u16], case_insensitive: bool) -> bool {
+    s.len() >= suffix.len() && equal(suffix, &s[s.len() - suffix.len()..], case_insensitive)
+}
+
+/// Hashes a string like `RtlHashUnicodeString` with
+/// `HASH_STRING_ALGORITHM_X65599`.
+pub fn hash_x65599(s: &[u16], case_insensitive: bool) -> u32 {
+    s.iter().fold(0u32, |hash, &unit| {
+        hash.wrapping_mul(65599)
+            .wrapping_add(fold(unit, case_insensitive) as u32)
+    })
+}
+
+/// Wrapper that compares, orders and hashes strings case-insensitively,
+/// e.g. for use as a map key.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive<T>(pub T);
+
+impl<T: AsRef<[u16]>, U: AsRef<[u16]>> PartialEq<CaseInsensitive<U>> for CaseInsensitive<T> {
+    fn eq(&self, other: &CaseInsensitive<U>) -> bool {
+        equal(self.0.as_ref(), other.0.as_ref(), true)
+    }
+}
+
+impl<T: AsRef<[u16]>> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<[u16]>> PartialOrd for CaseInsensitive<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<[u16]>> Ord for CaseInsensitive<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(self.0.as_ref(), other.0.as_ref(), true)
+    }
+}
+
+impl<T: AsRef<[u16]>> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(hash_x65599(self.0.as_ref(), true));
+    }
+}
@@ -0,0 +1,57 @@
+use core::fmt;
+
+/// Errors returned by the string types of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The string does not fit into the 16-bit length fields of a `UNICODE_STRING`.
+    TooLong,
+    /// The allocator could not provide a buffer.
+    AllocationFailed,
+    /// A pointer to a `UNICODE_STRING` was null.
+    NullPointer,
+    /// `Length`, `MaximumLength` or the size of a UTF-16 block is not a
+    /// multiple of two.
+    OddLength,
+    /// `Length` is larger than `MaximumLength`.
+    LengthExceedsMaximum,
+    /// The buffer is not aligned for `u16`, or is null while `Length` is not zero.
+    InvalidBuffer,
+    /// The path does not have the form required by the operation.
+    InvalidPath,
+    /// The result did not fit into a fixed-size buffer and was truncated.
+    Truncated,
+    /// A `REG_MULTI_SZ` block does not end with a string and list terminator.
+    MissingTerminator,
+    /// A `REG_MULTI_SZ` block has data after its list terminator.
+    TrailingData,
+    /// An empty string was added to a `REG_MULTI_SZ` list, where it would end the list.
+    EmptyItem,
+    /// A string added to a `REG_MULTI_SZ` list contains a NUL character.
+    EmbeddedNul,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TooLong => f.write_str("string exceeds the maximum UNICODE_STRING length"),
+            Error::AllocationFailed => f.write_str("failed to allocate string buffer"),
+            Error::NullPointer => f.write_str("UNICODE_STRING pointer is null"),
+            Error::OddLength => f.write_str("UNICODE_STRING length is not a multiple of two"),
+            Error::LengthExceedsMaximum => {
+                f.write_str("UNICODE_STRING length exceeds its maximum length")
+            }
+            Error::InvalidBuffer => f.write_str("UNICODE_STRING buffer is null or misaligned"),
+            Error::InvalidPath => f.write_str("path has an unexpected form"),
+            Error::Truncated => f.write_str("string was truncated to fit its buffer"),
+            Error::MissingTerminator => {
+                f.write_str("REG_MULTI_SZ block is not double-NUL-terminated")
+            }
+            Error::TrailingData => f.write_str("REG_MULTI_SZ block has data after its terminator"),
+            Error::EmptyItem => f.write_str("REG_MULTI_SZ list cannot contain an empty string"),
+            Error::EmbeddedNul => f.write_str("REG_MULTI_SZ list item contains a NUL character"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
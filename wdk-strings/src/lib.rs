@@ -1,35 +1,168 @@
 #![no_std]
 
+extern crate alloc;
+
+pub mod case;
+mod error;
+pub mod multi_sz;
+pub mod path;
+pub mod pool;
+pub mod sys;
+mod unicode_str;
+mod unicode_string;
+mod unicode_string_buf;
+mod upcase_table;
+pub mod wildcard;
+
+pub use case::CaseInsensitive;
+pub use error::Error;
+pub use multi_sz::{MultiSz, MultiSzBuf};
+pub use path::{NtPath, NtPathBuf, Prefix};
+pub use pool::{Pool, PoolAllocator, PoolType};
+pub use unicode_str::{Chars, Split, UnicodeStr};
+pub use unicode_string::{MAX_LENGTH, UnicodeString};
+pub use unicode_string_buf::UnicodeStringBuf;
+
+use windows_driver_common_util::pool_tag::PoolTag;
+
+/// The pool tags this crate allocates with, for `pooltag-export`.
+pub const POOL_TAGS: &[(PoolTag, &str)] = &[(Pool::DEFAULT_TAG, "wdk-strings buffers of the default Pool")];
+
 /// A literal UTF-16 wide string with a trailing null terminator.
+///
+/// The string may be given in several parts, each a string literal or a
+/// `&str` constant, which are concatenated. This allows deriving related names
+/// from one definition:
+///
+/// ```
+/// # use wdk_strings::{sys::{PCWSTR, UNICODE_STRING}, u};
+/// const DEVICE: &str = "Zero";
+/// const DEVICE_NAME: UNICODE_STRING = u!(r"\Device\", DEVICE);
+/// const DEVICE_PATH: PCWSTR = u!(pcwstr: r"\\.\", DEVICE);
+/// const CONTENTS: &[u16] = u!(slice: DEVICE);
+/// ```
+///
+/// The output type is selected by the leading keyword:
+/// * none: a [`sys::UNICODE_STRING`] (from `wdk-sys` with the `kernel`
+///   feature, from `windows` with the `user` feature).
+/// * `pcwstr:`: a [`sys::PCWSTR`] to the null-terminated string.
+/// * `slice:`: a `&'static [u16]` of the characters, without the terminator
+///   (which is still present in memory).
+///
+/// Prefixing the keyword with `ascii_only` (`u!(ascii_only: ...)`,
+/// `u!(ascii_only, pcwstr: ...)`) additionally rejects non-ASCII characters.
+///
+/// Strings that do not fit a `UNICODE_STRING` or that contain an embedded
+/// NUL, which would truncate them for consumers such as `DbgPrint`'s `%wZ`,
+/// fail to compile:
+///
+/// ```compile_fail
+/// # use wdk_strings::{sys::UNICODE_STRING, u};
+/// const NAME: UNICODE_STRING = u!("Zero\0");
+/// ```
 #[macro_export]
 macro_rules! u {
-    ($s:literal) => {{
-        const INPUT: &[u8] = $s.as_bytes();
-        const OUTPUT_LEN: usize = $crate::utf16_len(INPUT) + 1;
-        const OUTPUT: &[u16; OUTPUT_LEN] = {
-            let mut buffer = [0; OUTPUT_LEN];
-            let mut input_pos = 0;
-            let mut output_pos = 0;
-            while let Some((mut code_point, new_pos)) = $crate::decode_utf8_char(INPUT, input_pos) {
-                input_pos = new_pos;
-                if code_point <= 0xffff {
-                    buffer[output_pos] = code_point as u16;
-                    output_pos += 1;
-                } else {
-                    code_point -= 0x10000;
-                    buffer[output_pos] = 0xd800 + (code_point >> 10) as u16;
-                    output_pos += 1;
-                    buffer[output_pos] = 0xdc00 + (code_point & 0x3ff) as u16;
-                    output_pos += 1;
-                }
-            }
-            &{ buffer }
+    (ascii_only, pcwstr: $($s:expr),+ $(,)?) => {
+        $crate::__u!(pcwstr, true; $($s),+)
+    };
+    (ascii_only, slice: $($s:expr),+ $(,)?) => {
+        $crate::__u!(slice, true; $($s),+)
+    };
+    (ascii_only: $($s:expr),+ $(,)?) => {
+        $crate::__u!(unicode_string, true; $($s),+)
+    };
+    (pcwstr: $($s:expr),+ $(,)?) => {
+        $crate::__u!(pcwstr, false; $($s),+)
+    };
+    (slice: $($s:expr),+ $(,)?) => {
+        $crate::__u!(slice, false; $($s),+)
+    };
+    ($($s:expr),+ $(,)?) => {
+        $crate::__u!(unicode_string, false; $($s),+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __u {
+    (pcwstr, $ascii_only:expr; $($s:expr),+) => {{
+        const OUTPUT: &[u16] = $crate::__utf16_literal!($crate::MAX_LENGTH, $ascii_only; $($s),+);
+        $crate::sys::pcwstr(OUTPUT.as_ptr())
+    }};
+    (slice, $ascii_only:expr; $($s:expr),+) => {{
+        const OUTPUT: &[u16] = $crate::__utf16_literal!($crate::MAX_LENGTH, $ascii_only; $($s),+);
+        const CONTENTS: &[u16] = OUTPUT.split_last().unwrap().1;
+        CONTENTS
+    }};
+    (unicode_string, $ascii_only:expr; $($s:expr),+) => {{
+        // `MaximumLength` must also cover the terminator.
+        const OUTPUT: &[u16] =
+            $crate::__utf16_literal!($crate::MAX_LENGTH - 1, $ascii_only; $($s),+);
+        $crate::sys::unicode_string_literal(OUTPUT)
+    }};
+}
+
+/// A literal [`UnicodeStr`] with `'static` lifetime, accepting the same parts
+/// and `ascii_only` form as [`u!`].
+///
+/// The backing buffer is null-terminated, like the one produced by [`u!`].
+#[macro_export]
+macro_rules! ustr {
+    (ascii_only: $($s:expr),+ $(,)?) => {
+        $crate::__ustr!(true; $($s),+)
+    };
+    ($($s:expr),+ $(,)?) => {
+        $crate::__ustr!(false; $($s),+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ustr {
+    ($ascii_only:expr; $($s:expr),+) => {{
+        const OUTPUT: $crate::UnicodeStr<'static> = $crate::UnicodeStr::from_nul_terminated(
+            $crate::__utf16_literal!($crate::MAX_LENGTH, $ascii_only; $($s),+),
+        );
+        OUTPUT
+    }};
+}
+
+/// Encodes the concatenation of string constants as a null-terminated
+/// `&'static [u16; N]`, failing to compile if the string has more than `$max`
+/// code units or violates [`check_literal`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __utf16_literal {
+    ($max:expr, $ascii_only:expr; $($s:expr),+) => {{
+        const INPUT: &[&[u8]] = &[$($s.as_bytes()),+];
+        const OUTPUT_LEN: usize = $crate::check_literal(INPUT, $max, $ascii_only) + 1;
+        const OUTPUT: &[u16; OUTPUT_LEN] = &$crate::encode_utf16_parts(INPUT);
+        OUTPUT
+    }};
+}
+
+/// A literal [`MultiSz`] with `'static` lifetime, e.g. a default for a list
+/// read from the registry:
+///
+/// ```
+/// # use wdk_strings::{MultiSz, multi_sz};
+/// const DEFAULT_NAMES: MultiSz<'static> = multi_sz!("notepad.exe", "calc.exe");
+/// assert_eq!(DEFAULT_NAMES.iter().count(), 2);
+/// ```
+///
+/// Items are given like the parts of [`u!`]; empty items and items
+/// containing NUL fail to compile, as they would end the list early.
+#[macro_export]
+macro_rules! multi_sz {
+    ($($s:expr),* $(,)?) => {{
+        const ITEMS: &[&[u8]] = &[$($s.as_bytes()),*];
+        const OUTPUT_LEN: usize = $crate::multi_sz_len(ITEMS);
+        const OUTPUT: &[u16; OUTPUT_LEN] = &$crate::encode_multi_sz(ITEMS);
+        const LIST: $crate::MultiSz<'static> = match $crate::MultiSz::from_u16_slice(OUTPUT) {
+            Ok(list) => list,
+            Err(_) => panic!("invalid REG_MULTI_SZ literal"),
         };
-        ::wdk_sys::UNICODE_STRING {
-            Length: ((OUTPUT_LEN - 1) * 2) as u16,
-            MaximumLength: (OUTPUT_LEN * 2) as u16,
-            Buffer: OUTPUT.as_ptr() as *mut u16,
-        }
+        LIST
     }};
 }
 
@@ -107,4 +240,107 @@ pub const fn utf16_len(bytes: &[u8]) -> usize {
         len += if code_point <= 0xffff { 1 } else { 2 };
     }
     len
-}
\ No newline at end of file
+}
+
+/// Validates the parts of a string literal and returns its length in UTF-16
+/// code units. Panics, which fails compilation when evaluated in a constant,
+/// if the string
+/// * is not valid UTF-8 (only possible for byte strings passed by mistake),
+/// * contains a NUL character,
+/// * contains a non-ASCII character and `ascii_only` is set, or
+/// * is longer than `max_len` code units.
+#[doc(hidden)]
+pub const fn check_literal(parts: &[&[u8]], max_len: usize, ascii_only: bool) -> usize {
+    let mut len = 0;
+    let mut part = 0;
+    while part < parts.len() {
+        let input = parts[part];
+        let mut pos = 0;
+        while pos < input.len() {
+            let Some((code_point, new_pos)) = decode_utf8_char(input, pos) else {
+                panic!("string literal is not valid UTF-8");
+            };
+            if code_point == 0 {
+                panic!("string literal contains an embedded NUL character");
+            }
+            if ascii_only && code_point > 0x7f {
+                panic!("string literal contains a non-ASCII character");
+            }
+            pos = new_pos;
+            len += if code_point <= 0xffff { 1 } else { 2 };
+        }
+        part += 1;
+    }
+    if len > max_len {
+        panic!("string literal exceeds the maximum UNICODE_STRING length");
+    }
+    len
+}
+
+/// Encodes `parts` as UTF-16 into a buffer of `N` code units. Unused code
+/// units, including the terminator, are left zero.
+#[doc(hidden)]
+pub const fn encode_utf16_parts<const N: usize>(parts: &[&[u8]]) -> [u16; N] {
+    let mut buffer = [0; N];
+    let mut pos = 0;
+    let mut part = 0;
+    while part < parts.len() {
+        pos = encode_utf16_into(&mut buffer, pos, parts[part]);
+        part += 1;
+    }
+    buffer
+}
+
+/// Encodes `input` as UTF-16 into `buffer` starting at `pos`, returning the
+/// position after the last code unit written.
+const fn encode_utf16_into(buffer: &mut [u16], mut pos: usize, input: &[u8]) -> usize {
+    let mut input_pos = 0;
+    while let Some((mut code_point, new_pos)) = decode_utf8_char(input, input_pos) {
+        input_pos = new_pos;
+        if code_point <= 0xffff {
+            buffer[pos] = code_point as u16;
+            pos += 1;
+        } else {
+            code_point -= 0x10000;
+            buffer[pos] = 0xd800 + (code_point >> 10) as u16;
+            pos += 1;
+            buffer[pos] = 0xdc00 + (code_point & 0x3ff) as u16;
+            pos += 1;
+        }
+    }
+    pos
+}
+
+/// Validates the items of a [`multi_sz!`] literal and returns the length of
+/// the encoded block in code units, including all terminators.
+#[doc(hidden)]
+pub const fn multi_sz_len(items: &[&[u8]]) -> usize {
+    let mut len = 1;
+    let mut item = 0;
+    while item < items.len() {
+        let item_len = check_literal(&[items[item]], MAX_LENGTH, false);
+        if item_len == 0 {
+            panic!("REG_MULTI_SZ list cannot contain an empty string");
+        }
+        len += item_len + 1;
+        item += 1;
+    }
+    if len > MAX_LENGTH {
+        panic!("REG_MULTI_SZ block exceeds the maximum UNICODE_STRING length");
+    }
+    len
+}
+
+/// Encodes `items` as a `REG_MULTI_SZ` block of `N` code units.
+#[doc(hidden)]
+pub const fn encode_multi_sz<const N: usize>(items: &[&[u8]]) -> [u16; N] {
+    let mut buffer = [0; N];
+    let mut pos = 0;
+    let mut item = 0;
+    while item < items.len() {
+        // Skip over the zero left as the item's terminator.
+        pos = encode_utf16_into(&mut buffer, pos, items[item]) + 1;
+        item += 1;
+    }
+    buffer
+}
@@ -0,0 +1,245 @@
+//! `REG_MULTI_SZ` lists: a sequence of null-terminated UTF-16 strings ended
+//! by an additional null terminator, e.g. `a\0bc\0\0`.
+//!
+//! The empty list is stored as a single terminator. Since an empty string
+//! would end the list, lists cannot contain empty strings.
+
+use core::fmt;
+
+use crate::pool::{Pool, PoolAllocator};
+use crate::unicode_string::MAX_LENGTH;
+use crate::{Error, UnicodeStr, UnicodeString};
+
+/// A borrowed, validated `REG_MULTI_SZ` block.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MultiSz<'a> {
+    units: &'a [u16],
+}
+
+impl<'a> MultiSz<'a> {
+    /// Parses a `REG_MULTI_SZ` block, including all of its terminators.
+    ///
+    /// Besides the single terminator of the empty list, two terminators are
+    /// accepted for it, as written by some tools. Every string must fit into a
+    /// `UNICODE_STRING`.
+    pub const fn from_u16_slice(units: &'a [u16]) -> Result<Self, Error> {
+        if let [0] | [0, 0] = units {
+            return Ok(Self { units });
+        }
+
+        let mut start = 0;
+        loop {
+            let mut end = start;
+            while end < units.len() && units[end] != 0 {
+                end += 1;
+            }
+            if end == units.len() {
+                return Err(Error::MissingTerminator);
+            }
+            if end == start {
+                if end + 1 != units.len() {
+                    return Err(Error::TrailingData);
+                }
+                return Ok(Self { units });
+            }
+            if end - start > MAX_LENGTH {
+                return Err(Error::TooLong);
+            }
+            start = end + 1;
+        }
+    }
+
+    /// Parses the data of a `REG_MULTI_SZ` registry value, e.g. the `Data` of
+    /// a `KEY_VALUE_PARTIAL_INFORMATION`.
+    ///
+    /// Fails with [`Error::OddLength`] if `bytes` does not hold whole code
+    /// units and with [`Error::InvalidBuffer`] if it is not aligned for `u16`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+        if !bytes.len().is_multiple_of(2) {
+            return Err(Error::OddLength);
+        }
+        if !bytes.as_ptr().cast::<u16>().is_aligned() {
+            return Err(Error::InvalidBuffer);
+        }
+        // SAFETY: The pointer is aligned and the slice covers exactly the bytes.
+        let units = unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / 2) };
+        Self::from_u16_slice(units)
+    }
+
+    /// The whole block, including all terminators.
+    pub const fn as_u16_slice(&self) -> &'a [u16] {
+        self.units
+    }
+
+    /// The whole block as bytes, e.g. for `ZwSetValueKey`.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        // SAFETY: Any `u16` is also valid as two bytes.
+        unsafe { core::slice::from_raw_parts(self.units.as_ptr().cast(), self.units.len() * 2) }
+    }
+
+    pub fn iter(&self) -> Iter<'a> {
+        Iter {
+            remainder: self.units,
+        }
+    }
+
+    /// Number of strings in the list.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units[0] == 0
+    }
+
+    /// Checks whether the list contains `item`, ignoring case like
+    /// `RtlEqualUnicodeString(.., TRUE)`.
+    pub fn contains_ignore_case(&self, item: impl AsRef<[u16]>) -> bool {
+        let item = item.as_ref();
+        self.iter().any(|candidate| candidate.eq_ignore_case(item))
+    }
+}
+
+impl Default for MultiSz<'_> {
+    fn default() -> Self {
+        Self { units: &[0] }
+    }
+}
+
+impl<'a> TryFrom<&'a [u16]> for MultiSz<'a> {
+    type Error = Error;
+
+    fn try_from(units: &'a [u16]) -> Result<Self, Self::Error> {
+        Self::from_u16_slice(units)
+    }
+}
+
+impl<'a> IntoIterator for MultiSz<'a> {
+    type Item = UnicodeStr<'a>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl AsRef<[u16]> for MultiSz<'_> {
+    fn as_ref(&self) -> &[u16] {
+        self.units
+    }
+}
+
+impl fmt::Debug for MultiSz<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the strings of a [`MultiSz`].
+#[derive(Clone)]
+pub struct Iter<'a> {
+    remainder: &'a [u16],
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = UnicodeStr<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.remainder.iter().position(|&unit| unit == 0)?;
+        if end == 0 {
+            return None;
+        }
+        let (item, rest) = self.remainder.split_at(end);
+        self.remainder = &rest[1..];
+        // The item lengths were checked on construction.
+        Some(UnicodeStr::from_slice(item).unwrap_or_default())
+    }
+}
+
+/// An owned `REG_MULTI_SZ` block whose buffer is allocated from `A`.
+///
+/// The block, including its terminators, is limited to [`MAX_LENGTH`] code
+/// units.
+#[derive(Clone)]
+pub struct MultiSzBuf<A: PoolAllocator = Pool> {
+    inner: UnicodeString<A>,
+}
+
+impl<A: PoolAllocator> MultiSzBuf<A> {
+    /// Builds a list from `items`, which are iterated twice.
+    ///
+    /// Fails with [`Error::EmptyItem`] or [`Error::EmbeddedNul`] for items
+    /// that would end the list early.
+    pub fn from_iter_in<I>(items: I, allocator: A) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: AsRef<[u16]>,
+    {
+        let items = items.into_iter();
+        let mut len = 1;
+        for item in items.clone() {
+            let item = item.as_ref();
+            if item.is_empty() {
+                return Err(Error::EmptyItem);
+            }
+            if item.contains(&0) {
+                return Err(Error::EmbeddedNul);
+            }
+            len += item.len() + 1;
+            if len > MAX_LENGTH {
+                return Err(Error::TooLong);
+            }
+        }
+
+        let mut inner = UnicodeString::with_length_in(len, allocator)?;
+        let buffer = inner.as_mut_slice();
+        let mut pos = 0;
+        for item in items {
+            let item = item.as_ref();
+            buffer[pos..pos + item.len()].copy_from_slice(item);
+            // The buffer is zeroed, so the terminator is already in place.
+            pos += item.len() + 1;
+        }
+        Ok(Self { inner })
+    }
+
+    pub fn as_multi_sz(&self) -> MultiSz<'_> {
+        MultiSz {
+            units: self.inner.as_slice(),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        self.as_multi_sz().iter()
+    }
+
+    /// The whole block as bytes, e.g. for `ZwSetValueKey`.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_multi_sz().as_bytes()
+    }
+
+    pub fn as_u16_slice(&self) -> &[u16] {
+        self.inner.as_slice()
+    }
+}
+
+impl<A: PoolAllocator> PartialEq for MultiSzBuf<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<A: PoolAllocator> Eq for MultiSzBuf<A> {}
+
+impl<A: PoolAllocator> AsRef<[u16]> for MultiSzBuf<A> {
+    fn as_ref(&self) -> &[u16] {
+        self.inner.as_slice()
+    }
+}
+
+impl<A: PoolAllocator> fmt::Debug for MultiSzBuf<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_multi_sz(), f)
+    }
+}
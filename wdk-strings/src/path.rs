@@ -0,0 +1,281 @@
+//! NT object manager paths such as `\Device\Zero` and `\??\Zero`.
+//!
+//! A device is typically known under several names: its NT name in
+//! `\Device`, a symbolic link in the DOS devices directory (`\??`, also
+//! reachable as `\DosDevices` or, for the global namespace, `\GLOBAL??`), and
+//! the Win32 path `\\.\Name` that user mode opens. [`NtPath`] derives the
+//! latter forms from the device name so that only one of them needs to be
+//! spelled out.
+
+use core::fmt;
+
+use crate::pool::{Pool, PoolAllocator};
+use crate::sys::UNICODE_STRING;
+use crate::{Error, Split, UnicodeStr, UnicodeString, ustr};
+
+/// The path separator, `\`.
+pub const SEPARATOR: u16 = b'\\' as u16;
+
+/// The prefixes under which the DOS devices namespace can be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    /// `\??\`, the DOS devices directory of the current session.
+    Dos,
+    /// `\DosDevices\`, a symbolic link to `\??`.
+    DosDevices,
+    /// `\GLOBAL??\`, the global DOS devices directory.
+    Global,
+    /// `\\.\`, the Win32 device namespace, which user mode maps to `\??\`.
+    Win32Device,
+}
+
+impl Prefix {
+    pub const ALL: [Prefix; 4] = [
+        Prefix::Dos,
+        Prefix::DosDevices,
+        Prefix::Global,
+        Prefix::Win32Device,
+    ];
+
+    /// The prefix, including its trailing separator.
+    pub const fn as_unicode_str(self) -> UnicodeStr<'static> {
+        match self {
+            Prefix::Dos => ustr!(r"\??\"),
+            Prefix::DosDevices => ustr!(r"\DosDevices\"),
+            Prefix::Global => ustr!(r"\GLOBAL??\"),
+            Prefix::Win32Device => ustr!(r"\\.\"),
+        }
+    }
+}
+
+/// A borrowed NT object path.
+///
+/// Object names are case-insensitive, so prefixes are matched ignoring case.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NtPath<'a> {
+    inner: UnicodeStr<'a>,
+}
+
+impl<'a> NtPath<'a> {
+    pub const fn new(path: UnicodeStr<'a>) -> Self {
+        Self { inner: path }
+    }
+
+    pub const fn as_unicode_str(&self) -> UnicodeStr<'a> {
+        self.inner
+    }
+
+    /// Whether the path starts at the object manager root.
+    pub fn is_absolute(&self) -> bool {
+        self.inner.as_u16_slice().first() == Some(&SEPARATOR)
+    }
+
+    /// The DOS devices prefix of the path, if any.
+    pub fn prefix(&self) -> Option<Prefix> {
+        Prefix::ALL
+            .into_iter()
+            .find(|prefix| self.inner.starts_with_ignore_case(prefix.as_unicode_str()))
+    }
+
+    /// The name inside the DOS devices namespace, e.g. `Zero` for `\??\Zero`.
+    pub fn dos_name(&self) -> Option<UnicodeStr<'a>> {
+        let prefix = self.prefix()?.as_unicode_str();
+        let units = self.inner.as_u16_slice();
+        UnicodeStr::from_slice(&units[prefix.len()..]).ok()
+    }
+
+    /// The non-empty components of the path.
+    pub fn components(&self) -> Components<'a> {
+        Components {
+            inner: self.inner.split(SEPARATOR),
+        }
+    }
+
+    /// The last component of the path.
+    pub fn file_name(&self) -> Option<UnicodeStr<'a>> {
+        self.components().last()
+    }
+
+    /// The path without its last component, or `None` for the root or an
+    /// empty path. As with `std::path::Path::parent`, the parent of a relative
+    /// path with a single component is the empty path.
+    pub fn parent(&self) -> Option<NtPath<'a>> {
+        let units = trim_trailing_separators(self.inner.as_u16_slice());
+        let last = units.iter().rposition(|&unit| unit != SEPARATOR)?;
+        let parent = match units[..last].iter().rposition(|&unit| unit == SEPARATOR) {
+            Some(pos) => {
+                let parent = trim_trailing_separators(&units[..=pos]);
+                if parent.is_empty() { &units[..1] } else { parent }
+            }
+            None => &units[..0],
+        };
+        UnicodeStr::from_slice(parent).ok().map(NtPath::new)
+    }
+
+    /// Appends `component`, inserting a separator if needed.
+    pub fn join_in<A: PoolAllocator>(
+        &self,
+        component: impl AsRef<[u16]>,
+        allocator: A,
+    ) -> Result<NtPathBuf<A>, Error> {
+        let base = self.inner.as_u16_slice();
+        let component = trim_leading_separators(component.as_ref());
+        let separator: &[u16] = if base.is_empty() || base.ends_with(&[SEPARATOR]) {
+            &[]
+        } else {
+            &[SEPARATOR]
+        };
+        UnicodeString::concat_in(&[base, separator, component], allocator).map(NtPathBuf::from)
+    }
+
+    /// Replaces the DOS devices prefix of the path, e.g. `\??\Zero` to
+    /// `\\.\Zero`. Fails with [`Error::InvalidPath`] if the path has none.
+    pub fn with_prefix_in<A: PoolAllocator>(
+        &self,
+        prefix: Prefix,
+        allocator: A,
+    ) -> Result<NtPathBuf<A>, Error> {
+        let name = self.dos_name().ok_or(Error::InvalidPath)?;
+        NtPathBuf::dos_device_in(name, prefix, allocator)
+    }
+
+    /// The DOS devices path for the last component of this path, e.g.
+    /// `\??\Zero` for the device name `\Device\Zero`.
+    pub fn dos_device_path_in<A: PoolAllocator>(
+        &self,
+        prefix: Prefix,
+        allocator: A,
+    ) -> Result<NtPathBuf<A>, Error> {
+        let name = self.file_name().ok_or(Error::InvalidPath)?;
+        NtPathBuf::dos_device_in(name, prefix, allocator)
+    }
+}
+
+impl<'a> From<UnicodeStr<'a>> for NtPath<'a> {
+    fn from(path: UnicodeStr<'a>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl AsRef<[u16]> for NtPath<'_> {
+    fn as_ref(&self) -> &[u16] {
+        self.inner.as_u16_slice()
+    }
+}
+
+impl fmt::Display for NtPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for NtPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+/// Iterator over the components of an [`NtPath`].
+#[derive(Clone)]
+pub struct Components<'a> {
+    inner: Split<'a>,
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = UnicodeStr<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find(|component| !component.is_empty())
+    }
+}
+
+/// An owned NT object path.
+#[derive(Clone)]
+pub struct NtPathBuf<A: PoolAllocator = Pool> {
+    inner: UnicodeString<A>,
+}
+
+impl<A: PoolAllocator> NtPathBuf<A> {
+    pub fn from_str_in(path: &str, allocator: A) -> Result<Self, Error> {
+        UnicodeString::from_str_in(path, allocator).map(Self::from)
+    }
+
+    /// Builds `prefix` followed by `name`, e.g. `\??\Zero`.
+    pub fn dos_device_in(
+        name: impl AsRef<[u16]>,
+        prefix: Prefix,
+        allocator: A,
+    ) -> Result<Self, Error> {
+        let parts = [prefix.as_unicode_str().as_u16_slice(), name.as_ref()];
+        UnicodeString::concat_in(&parts, allocator).map(Self::from)
+    }
+
+    pub fn as_path(&self) -> NtPath<'_> {
+        NtPath::new(self.inner.as_unicode_str())
+    }
+
+    /// The underlying `UNICODE_STRING`, e.g. for `IoCreateSymbolicLink`.
+    pub fn as_raw(&self) -> &UNICODE_STRING {
+        self.inner.as_raw()
+    }
+
+    pub fn as_unicode_string(&self) -> &UnicodeString<A> {
+        &self.inner
+    }
+
+    pub fn into_unicode_string(self) -> UnicodeString<A> {
+        self.inner
+    }
+}
+
+impl<A: PoolAllocator> From<UnicodeString<A>> for NtPathBuf<A> {
+    fn from(inner: UnicodeString<A>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A: PoolAllocator, B: PoolAllocator> PartialEq<NtPathBuf<B>> for NtPathBuf<A> {
+    fn eq(&self, other: &NtPathBuf<B>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<A: PoolAllocator> Eq for NtPathBuf<A> {}
+
+impl<A: PoolAllocator> core::hash::Hash for NtPathBuf<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<A: PoolAllocator> AsRef<[u16]> for NtPathBuf<A> {
+    fn as_ref(&self) -> &[u16] {
+        self.inner.as_slice()
+    }
+}
+
+impl<A: PoolAllocator> fmt::Display for NtPathBuf<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<A: PoolAllocator> fmt::Debug for NtPathBuf<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+fn trim_trailing_separators(mut units: &[u16]) -> &[u16] {
+    while let [rest @ .., SEPARATOR] = units {
+        units = rest;
+    }
+    units
+}
+
+fn trim_leading_separators(mut units: &[u16]) -> &[u16] {
+    while let [SEPARATOR, rest @ ..] = units {
+        units = rest;
+    }
+    units
+}
@@ -0,0 +1,110 @@
+//! Allocators backing the owned string types.
+
+use core::ptr::NonNull;
+
+use windows_driver_common_util::pool_tag;
+use windows_driver_common_util::pool_tag::PoolTag;
+
+/// Memory pool an allocation is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PoolType {
+    /// Pageable memory; only usable at IRQL < DISPATCH_LEVEL.
+    Paged,
+    /// Non-pageable memory; usable at any IRQL.
+    NonPaged,
+}
+
+/// An allocator for string buffers.
+///
+/// # Safety
+/// `allocate` must return either `None` or a pointer to at least `size` bytes,
+/// aligned for `u16`, that stays valid until it is passed to `deallocate` with
+/// the same `size`.
+pub unsafe trait PoolAllocator {
+    /// Allocates `size` bytes. `size` is never zero.
+    fn allocate(&self, size: usize) -> Option<NonNull<u8>>;
+
+    /// Releases a buffer previously returned by [`allocate`](Self::allocate).
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by `allocate` on this allocator (or a
+    /// clone of it) with the same `size`, and must not be used afterwards.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize);
+}
+
+/// The default allocator: a pool type plus a pool tag.
+///
+/// With the `kernel` feature, allocations go through `ExAllocatePool2`.
+/// Otherwise the host's global allocator is used, so code using the string
+/// types can be unit-tested outside of the kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pool {
+    pool_type: PoolType,
+    tag: PoolTag,
+}
+
+impl Pool {
+    /// Tag used by [`Pool::default`].
+    pub const DEFAULT_TAG: PoolTag = pool_tag!("UStr");
+
+    pub const fn new(pool_type: PoolType, tag: PoolTag) -> Self {
+        Self { pool_type, tag }
+    }
+
+    pub const fn paged(tag: PoolTag) -> Self {
+        Self::new(PoolType::Paged, tag)
+    }
+
+    pub const fn non_paged(tag: PoolTag) -> Self {
+        Self::new(PoolType::NonPaged, tag)
+    }
+
+    pub const fn pool_type(&self) -> PoolType {
+        self.pool_type
+    }
+
+    pub const fn tag(&self) -> PoolTag {
+        self.tag
+    }
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::paged(Self::DEFAULT_TAG)
+    }
+}
+
+#[cfg(feature = "kernel")]
+unsafe impl PoolAllocator for Pool {
+    fn allocate(&self, size: usize) -> Option<NonNull<u8>> {
+        let flags = match self.pool_type {
+            PoolType::Paged => wdk_sys::POOL_FLAG_PAGED,
+            PoolType::NonPaged => wdk_sys::POOL_FLAG_NON_PAGED,
+        };
+        let ptr = unsafe { wdk_sys::ntddk::ExAllocatePool2(flags, size as wdk_sys::SIZE_T, self.tag.as_u32()) };
+        NonNull::new(ptr as *mut u8)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, _size: usize) {
+        unsafe { wdk_sys::ntddk::ExFreePool(ptr.as_ptr() as *mut core::ffi::c_void) }
+    }
+}
+
+#[cfg(not(feature = "kernel"))]
+unsafe impl PoolAllocator for Pool {
+    fn allocate(&self, size: usize) -> Option<NonNull<u8>> {
+        let layout = host_layout(size)?;
+        NonNull::new(unsafe { alloc::alloc::alloc(layout) })
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize) {
+        if let Some(layout) = host_layout(size) {
+            unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+}
+
+#[cfg(not(feature = "kernel"))]
+fn host_layout(size: usize) -> Option<core::alloc::Layout> {
+    core::alloc::Layout::from_size_align(size, core::mem::align_of::<u16>()).ok()
+}
@@ -0,0 +1,71 @@
+//! The Windows string types this crate converts to and from.
+//!
+//! With the `kernel` feature these are the types from `wdk-sys`, with the
+//! `user` feature those from the `windows` crate. Otherwise layout-compatible
+//! stand-ins are used, so the crate can be built and tested on any host.
+
+#[cfg(all(feature = "kernel", feature = "user"))]
+compile_error!("the `kernel` and `user` features of wdk-strings are mutually exclusive");
+
+#[cfg(feature = "kernel")]
+pub use wdk_sys::{PCWSTR, UNICODE_STRING};
+
+#[cfg(all(feature = "user", not(feature = "kernel")))]
+pub use windows::{Win32::Foundation::UNICODE_STRING, core::PCWSTR};
+
+/// Host stand-in for `UNICODE_STRING`, with identical layout.
+#[cfg(not(any(feature = "kernel", feature = "user")))]
+#[allow(non_snake_case, non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct UNICODE_STRING {
+    pub Length: u16,
+    pub MaximumLength: u16,
+    pub Buffer: *mut u16,
+}
+
+/// Host stand-in for `PCWSTR`.
+#[cfg(not(any(feature = "kernel", feature = "user")))]
+#[allow(non_camel_case_types)]
+pub type PCWSTR = *const u16;
+
+pub(crate) const fn new_unicode_string(
+    length: u16,
+    maximum_length: u16,
+    buffer: *mut u16,
+) -> UNICODE_STRING {
+    UNICODE_STRING {
+        Length: length,
+        MaximumLength: maximum_length,
+        #[cfg(not(all(feature = "user", not(feature = "kernel"))))]
+        Buffer: buffer,
+        #[cfg(all(feature = "user", not(feature = "kernel")))]
+        Buffer: windows::core::PWSTR(buffer),
+    }
+}
+
+pub(crate) const fn buffer(string: &UNICODE_STRING) -> *mut u16 {
+    #[cfg(not(all(feature = "user", not(feature = "kernel"))))]
+    return string.Buffer;
+    #[cfg(all(feature = "user", not(feature = "kernel")))]
+    return string.Buffer.0;
+}
+
+/// Builds the `UNICODE_STRING` for a null-terminated literal; used by [`u!`](crate::u).
+#[doc(hidden)]
+pub const fn unicode_string_literal(units: &'static [u16]) -> UNICODE_STRING {
+    new_unicode_string(
+        ((units.len() - 1) * 2) as u16,
+        (units.len() * 2) as u16,
+        units.as_ptr() as *mut u16,
+    )
+}
+
+/// Wraps a pointer to a null-terminated string; used by [`u!`](crate::u).
+#[doc(hidden)]
+pub const fn pcwstr(units: *const u16) -> PCWSTR {
+    #[cfg(not(all(feature = "user", not(feature = "kernel"))))]
+    return units;
+    #[cfg(all(feature = "user", not(feature = "kernel")))]
+    return windows::core::PCWSTR(units);
+}
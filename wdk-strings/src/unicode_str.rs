@@ -0,0 +1,282 @@
+//! A borrowed, validated view of a `UNICODE_STRING`.
+
+use alloc::string::String;
+use core::char::{DecodeUtf16, DecodeUtf16Error};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Copied;
+
+use crate::{case, wildcard};
+use crate::pool::PoolAllocator;
+use crate::sys::{self, UNICODE_STRING};
+use crate::unicode_string::MAX_LENGTH;
+use crate::{Error, UnicodeString};
+
+/// A borrowed UTF-16 string, e.g. the contents of a `UNICODE_STRING`.
+///
+/// The string is not required to be valid UTF-16 nor to be null-terminated.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeStr<'a> {
+    units: &'a [u16],
+}
+
+impl<'a> UnicodeStr<'a> {
+    /// Wraps a slice of UTF-16 code units.
+    pub const fn from_slice(units: &'a [u16]) -> Result<Self, Error> {
+        if units.len() > MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        Ok(Self { units })
+    }
+
+    /// Wraps a null-terminated slice, as produced by [`ustr!`](crate::ustr).
+    ///
+    /// Panics (at compile time in const contexts) if the slice does not end in
+    /// a null terminator or is too long.
+    pub const fn from_nul_terminated(units: &'a [u16]) -> Self {
+        let Some((&0, units)) = units.split_last() else {
+            panic!("string is not null-terminated");
+        };
+        match Self::from_slice(units) {
+            Ok(string) => string,
+            Err(_) => panic!("string exceeds the maximum UNICODE_STRING length"),
+        }
+    }
+
+    /// Borrows the characters described by `raw`, after checking that its
+    /// lengths and buffer are consistent.
+    ///
+    /// # Safety
+    /// If the checks pass, `raw.Buffer` must point to at least `raw.Length`
+    /// bytes that stay valid and unmodified for `'a`.
+    pub unsafe fn from_raw(raw: &'a UNICODE_STRING) -> Result<Self, Error> {
+        let buffer = sys::buffer(raw);
+        if !raw.Length.is_multiple_of(2) || !raw.MaximumLength.is_multiple_of(2) {
+            return Err(Error::OddLength);
+        }
+        if raw.Length > raw.MaximumLength {
+            return Err(Error::LengthExceedsMaximum);
+        }
+        if raw.Length == 0 {
+            return Ok(Self::default());
+        }
+        if buffer.is_null() || !buffer.is_aligned() {
+            return Err(Error::InvalidBuffer);
+        }
+
+        Ok(Self {
+            units: unsafe { core::slice::from_raw_parts(buffer, raw.Length as usize / 2) },
+        })
+    }
+
+    /// Like [`from_raw`](Self::from_raw), for a possibly null pointer such as
+    /// the `PCUNICODE_STRING` passed to `DriverEntry`.
+    ///
+    /// # Safety
+    /// `raw` must be null or valid for reads for `'a`, with the requirements
+    /// of [`from_raw`](Self::from_raw) on the string it points to.
+    pub unsafe fn from_ptr(raw: *const UNICODE_STRING) -> Result<Self, Error> {
+        match unsafe { raw.as_ref() } {
+            Some(raw) => unsafe { Self::from_raw(raw) },
+            None => Err(Error::NullPointer),
+        }
+    }
+
+    /// A `UNICODE_STRING` describing this string, e.g. for passing to kernel
+    /// APIs. The buffer must not be written through the returned value.
+    pub fn to_raw(&self) -> UNICODE_STRING {
+        let length = (self.units.len() * 2) as u16;
+        sys::new_unicode_string(length, length, self.units.as_ptr() as *mut u16)
+    }
+
+    pub const fn as_u16_slice(&self) -> &'a [u16] {
+        self.units
+    }
+
+    /// Number of UTF-16 code units in the string.
+    pub const fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Decodes the string, yielding an error for every unpaired surrogate.
+    pub fn chars(&self) -> Chars<'a> {
+        Chars {
+            inner: char::decode_utf16(self.units.iter().copied()),
+        }
+    }
+
+    /// Converts the string to UTF-8, replacing unpaired surrogates with U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        self.chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    /// Copies the string into a buffer allocated from `allocator`.
+    pub fn to_unicode_string_in<A: PoolAllocator>(
+        &self,
+        allocator: A,
+    ) -> Result<UnicodeString<A>, Error> {
+        UnicodeString::from_utf16_in(self.units, allocator)
+    }
+
+    pub fn starts_with(&self, prefix: impl AsRef<[u16]>) -> bool {
+        self.units.starts_with(prefix.as_ref())
+    }
+
+    pub fn ends_with(&self, suffix: impl AsRef<[u16]>) -> bool {
+        self.units.ends_with(suffix.as_ref())
+    }
+
+    /// Compares with `other` like `RtlEqualUnicodeString(.., TRUE)`.
+    pub fn eq_ignore_case(&self, other: impl AsRef<[u16]>) -> bool {
+        case::equal(self.units, other.as_ref(), true)
+    }
+
+    /// Orders against `other` like `RtlCompareUnicodeString(.., TRUE)`.
+    pub fn cmp_ignore_case(&self, other: impl AsRef<[u16]>) -> Ordering {
+        case::compare(self.units, other.as_ref(), true)
+    }
+
+    /// Like `RtlPrefixUnicodeString(prefix, self, TRUE)`.
+    pub fn starts_with_ignore_case(&self, prefix: impl AsRef<[u16]>) -> bool {
+        case::has_prefix(prefix.as_ref(), self.units, true)
+    }
+
+    pub fn ends_with_ignore_case(&self, suffix: impl AsRef<[u16]>) -> bool {
+        case::has_suffix(suffix.as_ref(), self.units, true)
+    }
+
+    /// Matches the string against a wildcard expression like
+    /// `FsRtlIsNameInExpression`; see [`wildcard`](crate::wildcard).
+    pub fn matches_expression(&self, expression: impl AsRef<[u16]>, ignore_case: bool) -> bool {
+        wildcard::is_name_in_expression(expression.as_ref(), self.units, ignore_case)
+    }
+
+    /// Returns the rest of the string if it starts with `prefix`.
+    pub fn strip_prefix(&self, prefix: impl AsRef<[u16]>) -> Option<UnicodeStr<'a>> {
+        self.units
+            .strip_prefix(prefix.as_ref())
+            .map(|units| Self { units })
+    }
+
+    /// Returns the rest of the string if it ends with `suffix`.
+    pub fn strip_suffix(&self, suffix: impl AsRef<[u16]>) -> Option<UnicodeStr<'a>> {
+        self.units
+            .strip_suffix(suffix.as_ref())
+            .map(|units| Self { units })
+    }
+
+    /// Splits the string at every occurrence of `separator`, e.g.
+    /// `split(u16::from(b'\\'))` for the components of a path.
+    pub fn split(&self, separator: u16) -> Split<'a> {
+        Split {
+            remainder: Some(self.units),
+            separator,
+        }
+    }
+}
+
+impl AsRef<[u16]> for UnicodeStr<'_> {
+    fn as_ref(&self) -> &[u16] {
+        self.units
+    }
+}
+
+impl<'a> TryFrom<&'a [u16]> for UnicodeStr<'a> {
+    type Error = Error;
+
+    fn try_from(units: &'a [u16]) -> Result<Self, Self::Error> {
+        Self::from_slice(units)
+    }
+}
+
+impl PartialEq<str> for UnicodeStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.units.iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for UnicodeStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Writes the string, replacing unpaired surrogates with U+FFFD.
+impl fmt::Display for UnicodeStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        for c in self.chars() {
+            f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UnicodeStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        f.write_char('"')?;
+        for c in self.chars() {
+            match c {
+                Ok(c) => {
+                    for escaped in c.escape_debug() {
+                        f.write_char(escaped)?;
+                    }
+                }
+                Err(e) => write!(f, "\\u{{{:x}}}", e.unpaired_surrogate())?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Iterator over the characters of a [`UnicodeStr`].
+#[derive(Clone)]
+pub struct Chars<'a> {
+    inner: DecodeUtf16<Copied<core::slice::Iter<'a, u16>>>,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = Result<char, DecodeUtf16Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator over the parts of a [`UnicodeStr`] returned by [`UnicodeStr::split`].
+#[derive(Clone)]
+pub struct Split<'a> {
+    remainder: Option<&'a [u16]>,
+    separator: u16,
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = UnicodeStr<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+        match remainder.iter().position(|&unit| unit == self.separator) {
+            Some(pos) => {
+                self.remainder = Some(&remainder[pos + 1..]);
+                Some(UnicodeStr {
+                    units: &remainder[..pos],
+                })
+            }
+            None => {
+                self.remainder = None;
+                Some(UnicodeStr { units: remainder })
+            }
+        }
+    }
+}
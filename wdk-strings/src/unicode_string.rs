@@ -0,0 +1,266 @@
+//! An owned, pool-allocated `UNICODE_STRING`.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ptr::NonNull;
+
+use crate::pool::{Pool, PoolAllocator};
+use crate::sys::{self, UNICODE_STRING};
+use crate::{Error, UnicodeStr};
+
+/// Maximum number of UTF-16 code units a `UNICODE_STRING` can hold.
+pub const MAX_LENGTH: usize = (u16::MAX / 2) as usize;
+
+/// An owned UTF-16 string whose buffer is allocated from `A`.
+///
+/// The buffer is null-terminated whenever there is room for the terminator,
+/// which is the case for every string shorter than [`MAX_LENGTH`]. The
+/// terminator is not included in `Length`.
+pub struct UnicodeString<A: PoolAllocator = Pool> {
+    raw: UNICODE_STRING,
+    allocator: A,
+}
+
+// SAFETY: The buffer is exclusively owned by the string.
+unsafe impl<A: PoolAllocator + Send> Send for UnicodeString<A> {}
+// SAFETY: Shared references only allow reading the buffer.
+unsafe impl<A: PoolAllocator + Sync> Sync for UnicodeString<A> {}
+
+impl<A: PoolAllocator> UnicodeString<A> {
+    /// Creates an empty string. Does not allocate.
+    pub const fn new_in(allocator: A) -> Self {
+        Self {
+            raw: sys::new_unicode_string(0, 0, core::ptr::null_mut()),
+            allocator,
+        }
+    }
+
+    /// Creates a string holding a copy of `units`.
+    pub fn from_utf16_in(units: &[u16], allocator: A) -> Result<Self, Error> {
+        let mut string = Self::with_length_in(units.len(), allocator)?;
+        string.as_mut_slice().copy_from_slice(units);
+        Ok(string)
+    }
+
+    /// Creates a string holding the concatenation of `parts`.
+    pub fn concat_in(parts: &[&[u16]], allocator: A) -> Result<Self, Error> {
+        let len = parts.iter().map(|part| part.len()).sum();
+        let mut string = Self::with_length_in(len, allocator)?;
+        let mut pos = 0;
+        let buffer = string.as_mut_slice();
+        for part in parts {
+            buffer[pos..pos + part.len()].copy_from_slice(part);
+            pos += part.len();
+        }
+        Ok(string)
+    }
+
+    /// Creates a string holding `s` encoded as UTF-16.
+    pub fn from_str_in(s: &str, allocator: A) -> Result<Self, Error> {
+        let mut string = Self::with_length_in(s.encode_utf16().count(), allocator)?;
+        for (dst, src) in string.as_mut_slice().iter_mut().zip(s.encode_utf16()) {
+            *dst = src;
+        }
+        Ok(string)
+    }
+
+    /// Creates a string holding a copy of the characters described by `raw`.
+    ///
+    /// `raw` is validated like [`UnicodeStr::from_raw`] does, and its error
+    /// is returned for odd lengths, a `Length` above `MaximumLength` and null
+    /// or misaligned buffers.
+    ///
+    /// # Safety
+    /// If the checks pass, `raw.Buffer` must point to at least `raw.Length`
+    /// readable bytes.
+    pub unsafe fn copy_from_raw_in(raw: &UNICODE_STRING, allocator: A) -> Result<Self, Error> {
+        let source = unsafe { UnicodeStr::from_raw(raw) }?;
+        Self::from_utf16_in(source.as_u16_slice(), allocator)
+    }
+
+    /// Takes ownership of a `UNICODE_STRING` previously returned by
+    /// [`into_raw`](Self::into_raw).
+    ///
+    /// # Safety
+    /// `raw` and `allocator` must come from the same call to `into_raw`.
+    pub unsafe fn from_raw_parts(raw: UNICODE_STRING, allocator: A) -> Self {
+        Self { raw, allocator }
+    }
+
+    /// Releases ownership of the buffer without freeing it.
+    pub fn into_raw(self) -> (UNICODE_STRING, A) {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the allocator is moved out exactly once.
+        (this.raw, unsafe { core::ptr::read(&this.allocator) })
+    }
+
+    /// Returns a copy of this string, or an error if allocation fails.
+    pub fn try_clone(&self) -> Result<Self, Error>
+    where
+        A: Clone,
+    {
+        Self::from_utf16_in(self.as_slice(), self.allocator.clone())
+    }
+
+    /// The underlying `UNICODE_STRING`, e.g. for passing to kernel APIs.
+    ///
+    /// The referenced buffer must not be modified through the returned value.
+    pub fn as_raw(&self) -> &UNICODE_STRING {
+        &self.raw
+    }
+
+    /// The characters of the string as UTF-16 code units, without terminator.
+    pub fn as_slice(&self) -> &[u16] {
+        let buffer = sys::buffer(&self.raw);
+        if buffer.is_null() {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(buffer, self.len()) }
+        }
+    }
+
+    /// Borrows the string as a [`UnicodeStr`].
+    pub fn as_unicode_str(&self) -> UnicodeStr<'_> {
+        // The length was checked on construction.
+        UnicodeStr::from_slice(self.as_slice()).unwrap_or_default()
+    }
+
+    /// Number of UTF-16 code units in the string.
+    pub fn len(&self) -> usize {
+        self.raw.Length as usize / 2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.Length == 0
+    }
+
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
+    /// Allocates a zeroed string of `len` code units.
+    pub(crate) fn with_length_in(len: usize, allocator: A) -> Result<Self, Error> {
+        if len > MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        if len == 0 {
+            return Ok(Self::new_in(allocator));
+        }
+
+        let capacity = if len < MAX_LENGTH { len + 1 } else { len };
+        let buffer = allocator
+            .allocate(capacity * 2)
+            .ok_or(Error::AllocationFailed)?
+            .cast::<u16>();
+        // SAFETY: The allocation holds `capacity` code units.
+        unsafe {
+            core::ptr::write_bytes(buffer.as_ptr(), 0, capacity);
+        }
+
+        Ok(Self {
+            raw: sys::new_unicode_string((len * 2) as u16, (capacity * 2) as u16, buffer.as_ptr()),
+            allocator,
+        })
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [u16] {
+        let buffer = sys::buffer(&self.raw);
+        if buffer.is_null() {
+            &mut []
+        } else {
+            unsafe { core::slice::from_raw_parts_mut(buffer, self.len()) }
+        }
+    }
+}
+
+impl UnicodeString<Pool> {
+    /// Creates an empty string using the default [`Pool`].
+    pub const fn new() -> Self {
+        Self::new_in(Pool::paged(Pool::DEFAULT_TAG))
+    }
+}
+
+impl Default for UnicodeString<Pool> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: PoolAllocator> Drop for UnicodeString<A> {
+    fn drop(&mut self) {
+        if let Some(buffer) = NonNull::new(sys::buffer(&self.raw)) {
+            unsafe {
+                self.allocator
+                    .deallocate(buffer.cast(), self.raw.MaximumLength as usize)
+            };
+        }
+    }
+}
+
+/// Panics if the allocation fails; use [`UnicodeString::try_clone`] where
+/// that is not acceptable.
+impl<A: PoolAllocator + Clone> Clone for UnicodeString<A> {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("failed to allocate UnicodeString")
+    }
+}
+
+impl TryFrom<&str> for UnicodeString<Pool> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str_in(s, Pool::default())
+    }
+}
+
+impl TryFrom<&[u16]> for UnicodeString<Pool> {
+    type Error = Error;
+
+    fn try_from(units: &[u16]) -> Result<Self, Self::Error> {
+        Self::from_utf16_in(units, Pool::default())
+    }
+}
+
+impl<A: PoolAllocator, B: PoolAllocator> PartialEq<UnicodeString<B>> for UnicodeString<A> {
+    fn eq(&self, other: &UnicodeString<B>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: PoolAllocator> Eq for UnicodeString<A> {}
+
+impl<A: PoolAllocator> PartialEq<str> for UnicodeString<A> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_slice().iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl<A: PoolAllocator> PartialEq<&str> for UnicodeString<A> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<A: PoolAllocator> Hash for UnicodeString<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<A: PoolAllocator> AsRef<[u16]> for UnicodeString<A> {
+    fn as_ref(&self) -> &[u16] {
+        self.as_slice()
+    }
+}
+
+impl<A: PoolAllocator> fmt::Display for UnicodeString<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_unicode_str(), f)
+    }
+}
+
+impl<A: PoolAllocator> fmt::Debug for UnicodeString<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_unicode_str(), f)
+    }
+}
@@ -0,0 +1,176 @@
+//! A fixed-capacity `UNICODE_STRING` that is filled with `core::fmt`.
+
+use core::fmt;
+
+use crate::sys::{self, UNICODE_STRING};
+use crate::unicode_string::MAX_LENGTH;
+use crate::{Error, UnicodeStr};
+
+/// A UTF-16 string stored inline in a buffer of `N` code units.
+///
+/// It implements [`fmt::Write`], so it can be filled with `write!` without
+/// touching the pool, e.g. at `DISPATCH_LEVEL`. Text that does not fit is
+/// dropped and the string is marked as truncated, similar to
+/// `RtlUnicodeStringPrintf` returning `STATUS_BUFFER_OVERFLOW`. A surrogate
+/// pair is never split by truncation.
+#[derive(Clone)]
+pub struct UnicodeStringBuf<const N: usize> {
+    buffer: [u16; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> UnicodeStringBuf<N> {
+    const VALID_CAPACITY: () = assert!(
+        N <= MAX_LENGTH,
+        "capacity exceeds the maximum UNICODE_STRING length"
+    );
+
+    pub const fn new() -> Self {
+        let () = Self::VALID_CAPACITY;
+        Self {
+            buffer: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Creates a string holding the formatted `args`, e.g.
+    /// `UnicodeStringBuf::<32>::from_fmt(format_args!(r"\Device\Zero{}", index))`.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Result<Self, Error> {
+        let mut string = Self::new();
+        string.append_fmt(args)?;
+        Ok(string)
+    }
+
+    /// Appends the formatted `args`, returning [`Error::Truncated`] if they
+    /// did not fit. The part that did fit is kept.
+    pub fn append_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Error> {
+        let _ = fmt::Write::write_fmt(self, args);
+        if self.truncated {
+            Err(Error::Truncated)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Appends `units` if they fit completely, returning [`Error::Truncated`]
+    /// otherwise. Like formatted output, it is rejected once the string is
+    /// truncated.
+    pub fn push_utf16(&mut self, units: &[u16]) -> Result<(), Error> {
+        if self.truncated || units.len() > self.remaining() {
+            self.truncated = true;
+            return Err(Error::Truncated);
+        }
+        self.buffer[self.len..self.len + units.len()].copy_from_slice(units);
+        self.len += units.len();
+        self.terminate();
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+        self.terminate();
+    }
+
+    /// Whether some output was dropped because the buffer was full.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn as_slice(&self) -> &[u16] {
+        &self.buffer[..self.len]
+    }
+
+    pub fn as_unicode_str(&self) -> UnicodeStr<'_> {
+        // `N` was checked against the maximum length at compile time.
+        UnicodeStr::from_slice(self.as_slice()).unwrap_or_default()
+    }
+
+    /// A `UNICODE_STRING` describing the buffer, for passing to kernel APIs.
+    ///
+    /// It borrows the buffer, so the string must not be modified or moved
+    /// while the returned value is in use. The buffer is null-terminated if
+    /// there is room for the terminator.
+    pub fn to_raw(&self) -> UNICODE_STRING {
+        sys::new_unicode_string(
+            (self.len * 2) as u16,
+            (N * 2) as u16,
+            self.buffer.as_ptr() as *mut u16,
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    fn terminate(&mut self) {
+        if self.len < N {
+            self.buffer[self.len] = 0;
+        }
+    }
+}
+
+impl<const N: usize> Default for UnicodeStringBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for UnicodeStringBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Err(fmt::Error);
+        }
+        for c in s.chars() {
+            let mut units = [0; 2];
+            if self.push_utf16(c.encode_utf16(&mut units)).is_err() {
+                return Err(fmt::Error);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> AsRef<[u16]> for UnicodeStringBuf<N> {
+    fn as_ref(&self) -> &[u16] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for UnicodeStringBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_unicode_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for UnicodeStringBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<const N: usize> fmt::Display for UnicodeStringBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_unicode_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for UnicodeStringBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_unicode_str(), f)
+    }
+}
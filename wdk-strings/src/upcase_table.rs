@@ -0,0 +1,162 @@
+// Generated by tools/upcase_table.pl, do not edit.
+
+/// `(first, last, step, offset)`: every `step`th code unit from `first`
+/// through `last` upcases to itself plus `offset`, wrapping around.
+pub(crate) static UPCASE_RUNS: &[(u16, u16, u16, u16)] = &[
+    (0x00b5, 0x00b5, 1, 0x02e7),
+    (0x00e0, 0x00f6, 1, 0xffe0),
+    (0x00f8, 0x00fe, 1, 0xffe0),
+    (0x00ff, 0x00ff, 1, 0x0079),
+    (0x0101, 0x012f, 2, 0xffff),
+    (0x0131, 0x0131, 1, 0xff18),
+    (0x0133, 0x0137, 2, 0xffff),
+    (0x013a, 0x0148, 2, 0xffff),
+    (0x014b, 0x0177, 2, 0xffff),
+    (0x017a, 0x017e, 2, 0xffff),
+    (0x017f, 0x017f, 1, 0xfed4),
+    (0x0180, 0x0180, 1, 0x00c3),
+    (0x0183, 0x0185, 2, 0xffff),
+    (0x0188, 0x0188, 1, 0xffff),
+    (0x018c, 0x018c, 1, 0xffff),
+    (0x0192, 0x0192, 1, 0xffff),
+    (0x0195, 0x0195, 1, 0x0061),
+    (0x0199, 0x0199, 1, 0xffff),
+    (0x019a, 0x019a, 1, 0x00a3),
+    (0x019e, 0x019e, 1, 0x0082),
+    (0x01a1, 0x01a5, 2, 0xffff),
+    (0x01a8, 0x01a8, 1, 0xffff),
+    (0x01ad, 0x01ad, 1, 0xffff),
+    (0x01b0, 0x01b0, 1, 0xffff),
+    (0x01b4, 0x01b6, 2, 0xffff),
+    (0x01b9, 0x01b9, 1, 0xffff),
+    (0x01bd, 0x01bd, 1, 0xffff),
+    (0x01bf, 0x01bf, 1, 0x0038),
+    (0x01c5, 0x01c5, 1, 0xffff),
+    (0x01c6, 0x01c6, 1, 0xfffe),
+    (0x01c8, 0x01c8, 1, 0xffff),
+    (0x01c9, 0x01c9, 1, 0xfffe),
+    (0x01cb, 0x01cb, 1, 0xffff),
+    (0x01cc, 0x01cc, 1, 0xfffe),
+    (0x01ce, 0x01dc, 2, 0xffff),
+    (0x01dd, 0x01dd, 1, 0xffb1),
+    (0x01df, 0x01ef, 2, 0xffff),
+    (0x01f2, 0x01f2, 1, 0xffff),
+    (0x01f3, 0x01f3, 1, 0xfffe),
+    (0x01f5, 0x01f5, 1, 0xffff),
+    (0x01f9, 0x021f, 2, 0xffff),
+    (0x0223, 0x0233, 2, 0xffff),
+    (0x023c, 0x023c, 1, 0xffff),
+    (0x023f, 0x0240, 1, 0x2a3f),
+    (0x0242, 0x0242, 1, 0xffff),
+    (0x0247, 0x024f, 2, 0xffff),
+    (0x0250, 0x0250, 1, 0x2a1f),
+    (0x0251, 0x0251, 1, 0x2a1c),
+    (0x0252, 0x0252, 1, 0x2a1e),
+    (0x0253, 0x0253, 1, 0xff2e),
+    (0x0254, 0x0254, 1, 0xff32),
+    (0x0256, 0x0257, 1, 0xff33),
+    (0x0259, 0x0259, 1, 0xff36),
+    (0x025b, 0x025b, 1, 0xff35),
+    (0x0260, 0x0260, 1, 0xff33),
+    (0x0263, 0x0263, 1, 0xff31),
+    (0x0265, 0x0265, 1, 0xa528),
+    (0x0268, 0x0268, 1, 0xff2f),
+    (0x0269, 0x0269, 1, 0xff2d),
+    (0x026b, 0x026b, 1, 0x29f7),
+    (0x026f, 0x026f, 1, 0xff2d),
+    (0x0271, 0x0271, 1, 0x29fd),
+    (0x0272, 0x0272, 1, 0xff2b),
+    (0x0275, 0x0275, 1, 0xff2a),
+    (0x027d, 0x027d, 1, 0x29e7),
+    (0x0280, 0x0280, 1, 0xff26),
+    (0x0283, 0x0283, 1, 0xff26),
+    (0x0288, 0x0288, 1, 0xff26),
+    (0x0289, 0x0289, 1, 0xffbb),
+    (0x028a, 0x028b, 1, 0xff27),
+    (0x028c, 0x028c, 1, 0xffb9),
+    (0x0292, 0x0292, 1, 0xff25),
+    (0x0345, 0x0345, 1, 0x0054),
+    (0x0371, 0x0373, 2, 0xffff),
+    (0x0377, 0x0377, 1, 0xffff),
+    (0x037b, 0x037d, 1, 0x0082),
+    (0x03ac, 0x03ac, 1, 0xffda),
+    (0x03ad, 0x03af, 1, 0xffdb),
+    (0x03b1, 0x03c1, 1, 0xffe0),
+    (0x03c2, 0x03c2, 1, 0xffe1),
+    (0x03c3, 0x03cb, 1, 0xffe0),
+    (0x03cc, 0x03cc, 1, 0xffc0),
+    (0x03cd, 0x03ce, 1, 0xffc1),
+    (0x03d0, 0x03d0, 1, 0xffc2),
+    (0x03d1, 0x03d1, 1, 0xffc7),
+    (0x03d5, 0x03d5, 1, 0xffd1),
+    (0x03d6, 0x03d6, 1, 0xffca),
+    (0x03d7, 0x03d7, 1, 0xfff8),
+    (0x03d9, 0x03ef, 2, 0xffff),
+    (0x03f0, 0x03f0, 1, 0xffaa),
+    (0x03f1, 0x03f1, 1, 0xffb0),
+    (0x03f2, 0x03f2, 1, 0x0007),
+    (0x03f5, 0x03f5, 1, 0xffa0),
+    (0x03f8, 0x03f8, 1, 0xffff),
+    (0x03fb, 0x03fb, 1, 0xffff),
+    (0x0430, 0x044f, 1, 0xffe0),
+    (0x0450, 0x045f, 1, 0xffb0),
+    (0x0461, 0x0481, 2, 0xffff),
+    (0x048b, 0x04bf, 2, 0xffff),
+    (0x04c2, 0x04ce, 2, 0xffff),
+    (0x04cf, 0x04cf, 1, 0xfff1),
+    (0x04d1, 0x0527, 2, 0xffff),
+    (0x0561, 0x0586, 1, 0xffd0),
+    (0x1d79, 0x1d79, 1, 0x8a04),
+    (0x1d7d, 0x1d7d, 1, 0x0ee6),
+    (0x1e01, 0x1e95, 2, 0xffff),
+    (0x1e9b, 0x1e9b, 1, 0xffc5),
+    (0x1ea1, 0x1eff, 2, 0xffff),
+    (0x1f00, 0x1f07, 1, 0x0008),
+    (0x1f10, 0x1f15, 1, 0x0008),
+    (0x1f20, 0x1f27, 1, 0x0008),
+    (0x1f30, 0x1f37, 1, 0x0008),
+    (0x1f40, 0x1f45, 1, 0x0008),
+    (0x1f51, 0x1f57, 2, 0x0008),
+    (0x1f60, 0x1f67, 1, 0x0008),
+    (0x1f70, 0x1f71, 1, 0x004a),
+    (0x1f72, 0x1f75, 1, 0x0056),
+    (0x1f76, 0x1f77, 1, 0x0064),
+    (0x1f78, 0x1f79, 1, 0x0080),
+    (0x1f7a, 0x1f7b, 1, 0x0070),
+    (0x1f7c, 0x1f7d, 1, 0x007e),
+    (0x1f80, 0x1f87, 1, 0x0008),
+    (0x1f90, 0x1f97, 1, 0x0008),
+    (0x1fa0, 0x1fa7, 1, 0x0008),
+    (0x1fb0, 0x1fb1, 1, 0x0008),
+    (0x1fb3, 0x1fb3, 1, 0x0009),
+    (0x1fbe, 0x1fbe, 1, 0xe3db),
+    (0x1fc3, 0x1fc3, 1, 0x0009),
+    (0x1fd0, 0x1fd1, 1, 0x0008),
+    (0x1fe0, 0x1fe1, 1, 0x0008),
+    (0x1fe5, 0x1fe5, 1, 0x0007),
+    (0x1ff3, 0x1ff3, 1, 0x0009),
+    (0x214e, 0x214e, 1, 0xffe4),
+    (0x2170, 0x217f, 1, 0xfff0),
+    (0x2184, 0x2184, 1, 0xffff),
+    (0x24d0, 0x24e9, 1, 0xffe6),
+    (0x2c30, 0x2c5e, 1, 0xffd0),
+    (0x2c61, 0x2c61, 1, 0xffff),
+    (0x2c65, 0x2c65, 1, 0xd5d5),
+    (0x2c66, 0x2c66, 1, 0xd5d8),
+    (0x2c68, 0x2c6c, 2, 0xffff),
+    (0x2c73, 0x2c73, 1, 0xffff),
+    (0x2c76, 0x2c76, 1, 0xffff),
+    (0x2c81, 0x2ce3, 2, 0xffff),
+    (0x2cec, 0x2cee, 2, 0xffff),
+    (0x2d00, 0x2d25, 1, 0xe3a0),
+    (0xa641, 0xa66d, 2, 0xffff),
+    (0xa681, 0xa697, 2, 0xffff),
+    (0xa723, 0xa72f, 2, 0xffff),
+    (0xa733, 0xa76f, 2, 0xffff),
+    (0xa77a, 0xa77c, 2, 0xffff),
+    (0xa77f, 0xa787, 2, 0xffff),
+    (0xa78c, 0xa78c, 1, 0xffff),
+    (0xa791, 0xa791, 1, 0xffff),
+    (0xa7a1, 0xa7a9, 2, 0xffff),
+    (0xff41, 0xff5a, 1, 0xffe0),
+];
@@ -0,0 +1,169 @@
+//! Wildcard matching with the semantics of `FsRtlIsNameInExpression`.
+//!
+//! Besides `*` (any sequence of characters) and `?` (exactly one character),
+//! expressions may contain the DOS wildcards that the Win32 layer produces
+//! when translating `FindFirstFile` patterns:
+//!
+//! * [`DOS_STAR`] (`<`) matches zero or more characters until encountering
+//!   and matching the final `.` in the name.
+//! * [`DOS_QM`] (`>`) matches any single character or, upon encountering a
+//!   period or end of name string, advances the expression to the end of the
+//!   set of contiguous `DOS_QM`s.
+//! * [`DOS_DOT`] (`"`) matches either a period or zero characters beyond the
+//!   name string.
+
+use alloc::vec::Vec;
+
+use crate::case::upcase;
+
+pub const ANSI_DOS_STAR: u8 = b'<';
+pub const ANSI_DOS_QM: u8 = b'>';
+pub const ANSI_DOS_DOT: u8 = b'"';
+
+pub const DOS_STAR: u16 = ANSI_DOS_STAR as u16;
+pub const DOS_QM: u16 = ANSI_DOS_QM as u16;
+pub const DOS_DOT: u16 = ANSI_DOS_DOT as u16;
+
+const STAR: u16 = b'*' as u16;
+const QM: u16 = b'?' as u16;
+const DOT: u16 = b'.' as u16;
+
+/// Expressions with up to this many characters are matched without
+/// allocating.
+const INLINE_STATES: usize = 512;
+
+/// Checks whether `expression` contains any wildcard, like
+/// `FsRtlDoesNameContainWildCards`.
+pub fn contains_wildcards(expression: &[u16]) -> bool {
+    expression
+        .iter()
+        .any(|&unit| matches!(unit, STAR | QM | DOS_STAR | DOS_QM | DOS_DOT))
+}
+
+/// Checks whether `name` matches `expression`, like
+/// `FsRtlIsNameInExpression`.
+///
+/// Unlike the kernel routine, `expression` does not need to be upcased when
+/// `ignore_case` is set. As in the kernel, an empty name or expression only
+/// matches another empty one, so `*` does not match the empty name.
+pub fn is_name_in_expression(expression: &[u16], name: &[u16], ignore_case: bool) -> bool {
+    if expression.is_empty() || name.is_empty() {
+        return expression.is_empty() && name.is_empty();
+    }
+
+    let words = (expression.len() + 1).div_ceil(64);
+    if words <= INLINE_STATES / 64 {
+        let mut storage = [0u64; 2 * INLINE_STATES / 64];
+        let (current, next) = storage.split_at_mut(INLINE_STATES / 64);
+        Matcher::new(expression, name, ignore_case).run(&mut current[..words], &mut next[..words])
+    } else {
+        let mut current = Vec::new();
+        let mut next = Vec::new();
+        if current.try_reserve_exact(words).is_err() || next.try_reserve_exact(words).is_err() {
+            return false;
+        }
+        current.resize(words, 0);
+        next.resize(words, 0);
+        Matcher::new(expression, name, ignore_case).run(&mut current, &mut next)
+    }
+}
+
+/// Simulates the expression as a nondeterministic automaton whose states are
+/// positions in the expression, with state `expression.len()` accepting.
+struct Matcher<'a> {
+    expression: &'a [u16],
+    name: &'a [u16],
+    ignore_case: bool,
+    last_dot: Option<usize>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(expression: &'a [u16], name: &'a [u16], ignore_case: bool) -> Self {
+        Self {
+            expression,
+            name,
+            ignore_case,
+            last_dot: name.iter().rposition(|&unit| unit == DOT),
+        }
+    }
+
+    fn run<'s>(&self, mut current: &'s mut [u64], mut next: &'s mut [u64]) -> bool {
+        set(current, 0);
+        self.close(current, self.name.first().copied());
+
+        for (pos, &unit) in self.name.iter().enumerate() {
+            next.fill(0);
+            for state in 0..self.expression.len() {
+                if !contains(current, state) {
+                    continue;
+                }
+                match self.expression[state] {
+                    STAR => set(next, state),
+                    DOS_STAR => {
+                        // DOS_STAR must leave the final period for the rest of
+                        // the expression.
+                        if unit != DOT || self.last_dot != Some(pos) {
+                            set(next, state);
+                        }
+                    }
+                    QM => set(next, state + 1),
+                    DOS_QM => {
+                        if unit != DOT {
+                            set(next, state + 1);
+                        }
+                    }
+                    DOS_DOT => {
+                        if unit == DOT {
+                            set(next, state + 1);
+                        }
+                    }
+                    literal => {
+                        if self.fold(literal) == self.fold(unit) {
+                            set(next, state + 1);
+                        }
+                    }
+                }
+            }
+
+            self.close(next, self.name.get(pos + 1).copied());
+            if next.iter().all(|&word| word == 0) {
+                return false;
+            }
+            core::mem::swap(&mut current, &mut next);
+        }
+
+        contains(current, self.expression.len())
+    }
+
+    /// Adds the states reachable without consuming a character, given the
+    /// next character of the name (`None` at the end of the name).
+    fn close(&self, states: &mut [u64], lookahead: Option<u16>) {
+        // All such transitions move forward, so one ascending pass suffices.
+        for state in 0..self.expression.len() {
+            if !contains(states, state) {
+                continue;
+            }
+            let skip = match self.expression[state] {
+                STAR | DOS_STAR => true,
+                DOS_QM => matches!(lookahead, None | Some(DOT)),
+                DOS_DOT => lookahead.is_none(),
+                _ => false,
+            };
+            if skip {
+                set(states, state + 1);
+            }
+        }
+    }
+
+    fn fold(&self, unit: u16) -> u16 {
+        if self.ignore_case { upcase(unit) } else { unit }
+    }
+}
+
+fn set(states: &mut [u64], state: usize) {
+    states[state / 64] |= 1 << (state % 64);
+}
+
+fn contains(states: &[u64], state: usize) -> bool {
+    states[state / 64] & (1 << (state % 64)) != 0
+}
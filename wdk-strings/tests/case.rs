@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use wdk_strings::case::{compare, equal, has_prefix, hash_x65599, upcase};
+use wdk_strings::{CaseInsensitive, ustr};
+
+fn utf16(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+#[test]
+fn upcase_matches_the_kernel_table() {
+    let cases = [
+        ('a', 'A'),
+        ('z', 'Z'),
+        ('A', 'A'),
+        ('0', '0'),
+        ('\\', '\\'),
+        ('é', 'É'),
+        ('ÿ', 'Ÿ'),
+        ('µ', 'Μ'),
+        ('ß', 'ß'),
+        ('ǆ', 'Ǆ'),
+        ('ǅ', 'Ǆ'),
+        ('ж', 'Ж'),
+        ('ω', 'Ω'),
+        ('ａ', 'Ａ'),
+        // The simple mapping, not the titlecase-like full one.
+        ('ᾀ', 'ᾈ'),
+        // Added to Unicode after the kernel table was frozen.
+        ('ა', 'ა'),
+        ('ᲀ', 'ᲀ'),
+        ('ꭰ', 'ꭰ'),
+        ('ʞ', 'ʞ'),
+        ('\u{0529}', '\u{0529}'),
+        ('\u{a797}', '\u{a797}'),
+        ('\u{2d27}', '\u{2d27}'),
+    ];
+    for (lower, upper) in cases {
+        assert_eq!(upcase(lower as u16), upper as u16, "upcase({lower:?})");
+    }
+    assert_eq!(upcase(0xd800), 0xd800);
+    assert_eq!(upcase(0xdfff), 0xdfff);
+}
+
+#[test]
+fn compare_orders_like_rtl_compare_unicode_string() {
+    let a = utf16(r"\Device\Zero");
+    let b = utf16(r"\DEVICE\ZERO");
+    assert_eq!(compare(&a, &b, true), Ordering::Equal);
+    assert_eq!(compare(&a, &b, false), Ordering::Greater);
+    assert_eq!(compare(&utf16("abc"), &utf16("ABCD"), true), Ordering::Less);
+    assert_eq!(compare(&utf16("abd"), &utf16("ABC"), true), Ordering::Greater);
+    // '_' (0x5f) sorts after upcased letters but before lowercase ones.
+    assert_eq!(compare(&utf16("_"), &utf16("a"), true), Ordering::Greater);
+    assert_eq!(compare(&utf16("_"), &utf16("a"), false), Ordering::Less);
+}
+
+#[test]
+fn equal_and_prefix() {
+    assert!(equal(&utf16("Notepad.EXE"), &utf16("notepad.exe"), true));
+    assert!(!equal(&utf16("Notepad.EXE"), &utf16("notepad.exe"), false));
+    assert!(!equal(&utf16("notepad"), &utf16("notepad.exe"), true));
+
+    let path = ustr!(r"\REGISTRY\MACHINE\System\CurrentControlSet\Services\Zero");
+    assert!(has_prefix(&utf16(r"\Registry\Machine\"), path.as_u16_slice(), true));
+    assert!(!has_prefix(&utf16(r"\Registry\Machine\"), path.as_u16_slice(), false));
+    assert!(path.starts_with_ignore_case(ustr!(r"\registry\machine")));
+    assert!(path.ends_with_ignore_case(ustr!(r"services\zero")));
+    assert!(path.eq_ignore_case(utf16(r"\registry\machine\system\currentcontrolset\services\zero")));
+    assert_eq!(path.cmp_ignore_case(ustr!(r"\Registry\Machine\Z")), Ordering::Less);
+}
+
+#[test]
+fn hash_matches_x65599() {
+    assert_eq!(hash_x65599(&[], true), 0);
+    assert_eq!(hash_x65599(&utf16("A"), false), 0x41);
+    assert_eq!(hash_x65599(&utf16("AB"), false), 0x41 * 65599 + 0x42);
+    assert_eq!(hash_x65599(&utf16("ab"), true), hash_x65599(&utf16("AB"), false));
+    assert_ne!(hash_x65599(&utf16("ab"), false), hash_x65599(&utf16("AB"), false));
+}
+
+#[test]
+fn case_insensitive_wrapper_works_as_set_key() {
+    let mut names = HashSet::new();
+    assert!(names.insert(CaseInsensitive(ustr!("notepad.exe"))));
+    assert!(!names.insert(CaseInsensitive(ustr!("NOTEPAD.EXE"))));
+    assert!(names.contains(&CaseInsensitive(ustr!("Notepad.Exe"))));
+    assert_eq!(names.len(), 1);
+}
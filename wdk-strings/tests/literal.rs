@@ -0,0 +1,60 @@
+use wdk_strings::sys::{PCWSTR, UNICODE_STRING};
+use wdk_strings::{UnicodeStr, u, ustr};
+
+const DEVICE: &str = "Zero";
+
+const DEVICE_NAME: UNICODE_STRING = u!(r"\Device\", DEVICE);
+const DEVICE_PATH: PCWSTR = u!(pcwstr: r"\\.\", DEVICE);
+const CONTENTS: &[u16] = u!(slice: "h\u{e9}llo \u{1f600}");
+
+#[test]
+fn unicode_string_concatenates_parts() {
+    assert_eq!(DEVICE_NAME.Length, 24);
+    assert_eq!(DEVICE_NAME.MaximumLength, 26);
+    let s = unsafe { UnicodeStr::from_raw(&DEVICE_NAME) }.unwrap();
+    assert_eq!(s, r"\Device\Zero");
+    assert_eq!(unsafe { *DEVICE_NAME.Buffer.add(12) }, 0);
+}
+
+#[test]
+fn pcwstr_is_null_terminated() {
+    let expected: Vec<u16> = r"\\.\Zero".encode_utf16().chain([0]).collect();
+    let units = unsafe { core::slice::from_raw_parts(DEVICE_PATH, expected.len()) };
+    assert_eq!(units, expected);
+}
+
+#[test]
+fn slice_excludes_terminator() {
+    let expected: Vec<u16> = "h\u{e9}llo \u{1f600}".encode_utf16().collect();
+    assert_eq!(CONTENTS, expected);
+    assert_eq!(unsafe { *CONTENTS.as_ptr().add(CONTENTS.len()) }, 0);
+}
+
+#[test]
+fn empty_parts() {
+    assert!(u!(slice: "", "").is_empty());
+    assert!(ustr!("", DEVICE, "").eq(&DEVICE));
+    assert_eq!(u!("").Length, 0);
+    assert_eq!(u!("").MaximumLength, 2);
+}
+
+#[test]
+fn ascii_only_accepts_ascii() {
+    assert_eq!(u!(ascii_only: DEVICE).Length, 8);
+    assert_eq!(u!(ascii_only, slice: DEVICE).len(), 4);
+    assert_eq!(ustr!(ascii_only: r"\??\", DEVICE), r"\??\Zero");
+}
+
+#[test]
+fn longest_literals() {
+    static BYTES: [u8; 32767] = [b'x'; 32767];
+    const LONGEST: &str = match core::str::from_utf8(&BYTES) {
+        Ok(s) => s,
+        Err(_) => panic!(),
+    };
+    const NAME: UNICODE_STRING = u!(LONGEST.split_at(32766).0);
+    assert_eq!(NAME.Length, 65532);
+    assert_eq!(NAME.MaximumLength, 65534);
+    assert_eq!(ustr!(LONGEST).len(), 32767);
+    assert_eq!(u!(slice: LONGEST).len(), 32767);
+}
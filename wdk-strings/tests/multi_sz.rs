@@ -0,0 +1,95 @@
+use wdk_strings::{Error, MAX_LENGTH, MultiSz, MultiSzBuf, Pool, multi_sz, ustr};
+
+fn units(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+fn items(list: MultiSz<'_>) -> Vec<String> {
+    list.iter().map(|item| item.to_string_lossy()).collect()
+}
+
+#[test]
+fn parses_items() {
+    let block = units("notepad.exe\0calc.exe\0\0");
+    let list = MultiSz::from_u16_slice(&block).unwrap();
+    assert_eq!(items(list), ["notepad.exe", "calc.exe"]);
+    assert_eq!(list.len(), 2);
+    assert!(!list.is_empty());
+    assert!(list.contains_ignore_case(ustr!("CALC.EXE")));
+    assert!(!list.contains_ignore_case(ustr!("calc")));
+    assert_eq!(format!("{list:?}"), r#"["notepad.exe", "calc.exe"]"#);
+
+    for empty in [&[0][..], &[0, 0]] {
+        let list = MultiSz::from_u16_slice(empty).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(list.iter().count(), 0);
+    }
+}
+
+#[test]
+fn rejects_malformed_blocks() {
+    let cases: &[(&str, Error)] = &[
+        ("", Error::MissingTerminator),
+        ("a", Error::MissingTerminator),
+        ("a\0", Error::MissingTerminator),
+        ("a\0b", Error::MissingTerminator),
+        ("a\0\0b\0\0", Error::TrailingData),
+        ("a\0\0\0", Error::TrailingData),
+        ("\0\0\0", Error::TrailingData),
+        ("\0a\0\0", Error::TrailingData),
+    ];
+    for (block, error) in cases {
+        assert_eq!(MultiSz::from_u16_slice(&units(block)), Err(*error), "{block:?}");
+    }
+
+    let long: Vec<u16> = vec![b'x' as u16; MAX_LENGTH + 1].into_iter().chain([0, 0]).collect();
+    assert_eq!(MultiSz::from_u16_slice(&long), Err(Error::TooLong));
+}
+
+#[test]
+fn from_bytes_checks_size_and_alignment() {
+    let block = units("a\0b\0\0");
+    let bytes: Vec<u8> = block.iter().flat_map(|unit| unit.to_le_bytes()).collect();
+    // Copy into u16-aligned storage with room for a misaligned view.
+    let mut storage = vec![0u16; block.len() + 1];
+    let raw = unsafe {
+        core::slice::from_raw_parts_mut(storage.as_mut_ptr().cast::<u8>(), storage.len() * 2)
+    };
+    raw[..bytes.len()].copy_from_slice(&bytes);
+
+    let list = MultiSz::from_bytes(&raw[..bytes.len()]).unwrap();
+    assert_eq!(items(list), ["a", "b"]);
+    assert_eq!(list.as_bytes(), &bytes[..]);
+    assert_eq!(MultiSz::from_bytes(&raw[..bytes.len() - 1]), Err(Error::OddLength));
+    assert_eq!(MultiSz::from_bytes(&raw[1..bytes.len() + 1]), Err(Error::InvalidBuffer));
+}
+
+#[test]
+fn builds_from_iterator() {
+    let names = [ustr!("notepad.exe"), ustr!("calc.exe")];
+    let list = MultiSzBuf::from_iter_in(names, Pool::default()).unwrap();
+    assert_eq!(list.as_u16_slice(), units("notepad.exe\0calc.exe\0\0"));
+    assert_eq!(items(list.as_multi_sz()), ["notepad.exe", "calc.exe"]);
+    assert_eq!(list.clone(), list);
+
+    let empty = MultiSzBuf::from_iter_in(core::iter::empty::<&[u16]>(), Pool::default()).unwrap();
+    assert_eq!(empty.as_u16_slice(), [0]);
+
+    let with_empty = [units("a"), units("")];
+    assert_eq!(
+        MultiSzBuf::from_iter_in(&with_empty, Pool::default()).unwrap_err(),
+        Error::EmptyItem
+    );
+    let with_nul = [units("a\0b")];
+    assert_eq!(
+        MultiSzBuf::from_iter_in(&with_nul, Pool::default()).unwrap_err(),
+        Error::EmbeddedNul
+    );
+}
+
+#[test]
+fn literal() {
+    const NAMES: MultiSz<'static> = multi_sz!("notepad.exe", "\u{1f600}.exe");
+    assert_eq!(NAMES.as_u16_slice(), units("notepad.exe\0\u{1f600}.exe\0\0"));
+    assert_eq!(multi_sz!().as_u16_slice(), [0]);
+}
@@ -0,0 +1,78 @@
+use wdk_strings::{Error, NtPath, NtPathBuf, Pool, Prefix, ustr};
+
+const DEVICE_NAME: NtPath<'static> = NtPath::new(ustr!(r"\Device\Zero"));
+
+fn path(s: &'static str) -> NtPathBuf {
+    NtPathBuf::from_str_in(s, Pool::default()).unwrap()
+}
+
+#[test]
+fn symlink_and_win32_names_derive_from_device_name() {
+    let symlink = DEVICE_NAME.dos_device_path_in(Prefix::Dos, Pool::default()).unwrap();
+    assert_eq!(symlink.to_string(), r"\??\Zero");
+
+    let win32 = symlink.as_path().with_prefix_in(Prefix::Win32Device, Pool::default()).unwrap();
+    assert_eq!(win32.to_string(), r"\\.\Zero");
+
+    let global = win32.as_path().with_prefix_in(Prefix::Global, Pool::default()).unwrap();
+    assert_eq!(global.to_string(), r"\GLOBAL??\Zero");
+
+    let dos_devices = global.as_path().with_prefix_in(Prefix::DosDevices, Pool::default()).unwrap();
+    assert_eq!(dos_devices.to_string(), r"\DosDevices\Zero");
+}
+
+#[test]
+fn prefixes_are_recognized_case_insensitively() {
+    let cases = [
+        (r"\??\C:\Windows", Some(Prefix::Dos), Some(r"C:\Windows")),
+        (r"\DosDevices\Zero", Some(Prefix::DosDevices), Some("Zero")),
+        (r"\dosdevices\Zero", Some(Prefix::DosDevices), Some("Zero")),
+        (r"\Global??\Booster", Some(Prefix::Global), Some("Booster")),
+        (r"\\.\Booster", Some(Prefix::Win32Device), Some("Booster")),
+        (r"\Device\Zero", None, None),
+        (r"\Registry\Machine\System", None, None),
+    ];
+    for (input, prefix, name) in cases {
+        let p = path(input);
+        assert_eq!(p.as_path().prefix(), prefix, "{input}");
+        assert_eq!(p.as_path().dos_name().map(|n| n.to_string_lossy()).as_deref(), name, "{input}");
+    }
+
+    assert_eq!(
+        DEVICE_NAME.with_prefix_in(Prefix::Dos, Pool::default()).err(),
+        Some(Error::InvalidPath)
+    );
+}
+
+#[test]
+fn components_file_name_and_parent() {
+    let registry = path(r"\Registry\Machine\System\CurrentControlSet\Services\Zero\");
+    let components: Vec<_> = registry.as_path().components().map(|c| c.to_string_lossy()).collect();
+    assert_eq!(components, ["Registry", "Machine", "System", "CurrentControlSet", "Services", "Zero"]);
+    assert_eq!(registry.as_path().file_name().unwrap(), "Zero");
+
+    let parent = registry.as_path().parent().unwrap();
+    assert_eq!(parent.as_unicode_str(), r"\Registry\Machine\System\CurrentControlSet\Services");
+
+    assert_eq!(DEVICE_NAME.parent().unwrap().as_unicode_str(), r"\Device");
+    assert_eq!(DEVICE_NAME.parent().unwrap().parent().unwrap().as_unicode_str(), r"\");
+    assert!(NtPath::new(ustr!(r"\")).parent().is_none());
+    assert!(NtPath::new(ustr!("")).parent().is_none());
+    assert_eq!(NtPath::new(ustr!("Zero")).parent().unwrap().as_unicode_str(), "");
+    assert!(NtPath::new(ustr!(r"\")).file_name().is_none());
+}
+
+#[test]
+fn join_inserts_exactly_one_separator() {
+    let device = NtPath::new(ustr!(r"\Device"));
+    assert_eq!(device.join_in(ustr!("Zero"), Pool::default()).unwrap().as_path(), DEVICE_NAME);
+    assert_eq!(device.join_in(ustr!(r"\Zero"), Pool::default()).unwrap().as_path(), DEVICE_NAME);
+
+    let root = NtPath::new(ustr!(r"\"));
+    assert_eq!(root.join_in(ustr!("Device"), Pool::default()).unwrap().to_string(), r"\Device");
+    assert!(NtPath::new(ustr!(r"\"))
+        .join_in(ustr!("Device"), Pool::default())
+        .unwrap()
+        .as_path()
+        .is_absolute());
+}
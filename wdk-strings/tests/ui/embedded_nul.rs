@@ -0,0 +1,8 @@
+use wdk_strings::sys::UNICODE_STRING;
+use wdk_strings::u;
+
+const NAME: UNICODE_STRING = u!(r"\Device\Zero", "\0");
+
+fn main() {
+    let _ = NAME;
+}
@@ -0,0 +1,7 @@
+use wdk_strings::{MultiSz, multi_sz};
+
+const NAMES: MultiSz<'static> = multi_sz!("notepad.exe", "");
+
+fn main() {
+    let _ = NAMES;
+}
@@ -0,0 +1,8 @@
+use wdk_strings::sys::PCWSTR;
+use wdk_strings::u;
+
+const PATH: PCWSTR = u!(ascii_only, pcwstr: r"\\.\Zéro");
+
+fn main() {
+    let _ = PATH;
+}
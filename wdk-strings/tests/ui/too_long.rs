@@ -0,0 +1,16 @@
+use wdk_strings::sys::UNICODE_STRING;
+use wdk_strings::u;
+
+const BYTES: [u8; 32767] = [b'x'; 32767];
+const LONGEST: &str = match core::str::from_utf8(&BYTES) {
+    Ok(s) => s,
+    Err(_) => panic!(),
+};
+
+// 32767 code units fit `Length`, but leave no room for the terminator in
+// `MaximumLength`.
+const NAME: UNICODE_STRING = u!(LONGEST);
+
+fn main() {
+    let _ = NAME;
+}
@@ -0,0 +1,84 @@
+use wdk_strings::sys::UNICODE_STRING;
+use wdk_strings::{Error, UnicodeStr, UnicodeString, ustr};
+
+fn raw(length: u16, maximum_length: u16, buffer: *mut u16) -> UNICODE_STRING {
+    UNICODE_STRING {
+        Length: length,
+        MaximumLength: maximum_length,
+        Buffer: buffer,
+    }
+}
+
+#[test]
+fn literal_is_null_terminated_but_excludes_terminator() {
+    let s = ustr!(r"\Device\Zero");
+    assert_eq!(s.len(), 12);
+    assert_eq!(s, r"\Device\Zero");
+    assert_eq!(unsafe { *s.as_u16_slice().as_ptr().add(12) }, 0);
+}
+
+/// Validates a `UNICODE_STRING` with the given fields and returns its contents.
+fn validate(length: u16, maximum_length: u16, buffer: *mut u16) -> Result<String, Error> {
+    let raw = raw(length, maximum_length, buffer);
+    unsafe { UnicodeStr::from_raw(&raw) }.map(|s| s.to_string_lossy())
+}
+
+#[test]
+fn from_raw_validates_lengths_and_buffer() {
+    let mut units = [0x41u16, 0x42, 0x43, 0];
+    let buffer = units.as_mut_ptr();
+    let misaligned = unsafe { (buffer as *mut u8).add(1) } as *mut u16;
+
+    assert_eq!(validate(6, 8, buffer).as_deref(), Ok("ABC"));
+    assert_eq!(validate(5, 8, buffer), Err(Error::OddLength));
+    assert_eq!(validate(6, 7, buffer), Err(Error::OddLength));
+    assert_eq!(validate(8, 6, buffer), Err(Error::LengthExceedsMaximum));
+    assert_eq!(validate(2, 2, core::ptr::null_mut()), Err(Error::InvalidBuffer));
+    assert_eq!(validate(2, 2, misaligned), Err(Error::InvalidBuffer));
+    assert_eq!(validate(0, 0, core::ptr::null_mut()).as_deref(), Ok(""));
+    assert_eq!(unsafe { UnicodeStr::from_ptr(core::ptr::null()) }, Err(Error::NullPointer));
+}
+
+#[test]
+fn to_raw_round_trips() {
+    let s = ustr!("Booster");
+    let raw = s.to_raw();
+    assert_eq!(raw.Length, 14);
+    assert_eq!(unsafe { UnicodeStr::from_raw(&raw) }.unwrap(), s);
+}
+
+#[test]
+fn chars_decodes_surrogate_pairs_and_reports_lone_surrogates() {
+    let units = [0x61, 0xd83d, 0xde00, 0xdc00, 0x62, 0xd800];
+    let s = UnicodeStr::from_slice(&units).unwrap();
+    let chars: Vec<_> = s.chars().map(|c| c.map_err(|e| e.unpaired_surrogate())).collect();
+    assert_eq!(
+        chars,
+        [Ok('a'), Ok('\u{1F600}'), Err(0xdc00), Ok('b'), Err(0xd800)]
+    );
+    assert_eq!(s.to_string_lossy(), "a\u{1F600}\u{FFFD}b\u{FFFD}");
+}
+
+#[test]
+fn prefix_suffix_and_split() {
+    let path = ustr!(r"\Registry\Machine\System\CurrentControlSet");
+    assert!(path.starts_with(ustr!(r"\Registry\")));
+    assert!(path.ends_with(ustr!("ControlSet")));
+    assert!(!path.starts_with(ustr!(r"\registry")));
+    assert_eq!(path.strip_prefix(ustr!(r"\Registry\")).unwrap(), r"Machine\System\CurrentControlSet");
+
+    let parts: Vec<_> = path.split(u16::from(b'\\')).map(|p| p.to_string_lossy()).collect();
+    assert_eq!(parts, ["", "Registry", "Machine", "System", "CurrentControlSet"]);
+
+    let parts: Vec<_> = ustr!("").split(u16::from(b'\\')).collect();
+    assert_eq!(parts, [ustr!("")]);
+}
+
+#[test]
+fn owned_and_borrowed_strings_convert() {
+    let owned = UnicodeString::try_from("Zero").unwrap();
+    let borrowed = owned.as_unicode_str();
+    assert_eq!(borrowed, ustr!("Zero"));
+    assert_eq!(borrowed.to_unicode_string_in(*owned.allocator()).unwrap(), owned);
+    assert_eq!(format!("{borrowed:?}"), format!("{owned:?}"));
+}
@@ -0,0 +1,145 @@
+use core::ptr::NonNull;
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use wdk_strings::sys::UNICODE_STRING;
+use wdk_strings::{Error, MAX_LENGTH, Pool, PoolAllocator, PoolType, UnicodeString};
+use windows_driver_common_util::pool_tag;
+
+/// Allocator that counts outstanding allocations and can be told to fail.
+#[derive(Clone)]
+struct CountingPool<'a> {
+    live: &'a Cell<usize>,
+    fail: bool,
+}
+
+unsafe impl PoolAllocator for CountingPool<'_> {
+    fn allocate(&self, size: usize) -> Option<NonNull<u8>> {
+        if self.fail {
+            return None;
+        }
+        let ptr = Pool::default().allocate(size)?;
+        self.live.set(self.live.get() + 1);
+        Some(ptr)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, size: usize) {
+        self.live.set(self.live.get() - 1);
+        unsafe { Pool::default().deallocate(ptr, size) }
+    }
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn from_str_sets_lengths_and_terminator() {
+    let s = UnicodeString::try_from(r"\Device\Zero").unwrap();
+    assert_eq!(s.len(), 12);
+    assert_eq!(s.as_raw().Length, 24);
+    assert_eq!(s.as_raw().MaximumLength, 26);
+    assert_eq!(unsafe { *s.as_raw().Buffer.add(12) }, 0);
+    assert_eq!(s, r"\Device\Zero");
+}
+
+#[test]
+fn non_bmp_characters_use_surrogate_pairs() {
+    let s = UnicodeString::try_from("a\u{1F600}").unwrap();
+    assert_eq!(s.as_slice(), &[0x61, 0xd83d, 0xde00]);
+    assert_eq!(s.to_string(), "a\u{1F600}");
+}
+
+#[test]
+fn empty_string_does_not_allocate() {
+    let live = Cell::new(0);
+    let s = UnicodeString::from_str_in("", CountingPool { live: &live, fail: false }).unwrap();
+    assert!(s.is_empty());
+    assert!(s.as_raw().Buffer.is_null());
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn drop_and_clone_release_their_buffers() {
+    let live = Cell::new(0);
+    {
+        let s = UnicodeString::from_str_in("abc", CountingPool { live: &live, fail: false }).unwrap();
+        let t = s.clone();
+        assert_eq!(live.get(), 2);
+        assert_eq!(s, t);
+        assert_ne!(s.as_raw().Buffer, t.as_raw().Buffer);
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn allocation_failure_is_reported() {
+    let live = Cell::new(0);
+    let result = UnicodeString::from_str_in("abc", CountingPool { live: &live, fail: true });
+    assert_eq!(result.err(), Some(Error::AllocationFailed));
+}
+
+#[test]
+fn length_limit_is_enforced() {
+    let units = vec![b'x' as u16; MAX_LENGTH];
+    let s = UnicodeString::try_from(&units[..]).unwrap();
+    assert_eq!(s.as_raw().Length, 0xfffe);
+    assert_eq!(s.as_raw().MaximumLength, 0xfffe);
+
+    let units = vec![b'x' as u16; MAX_LENGTH + 1];
+    assert_eq!(UnicodeString::try_from(&units[..]).err(), Some(Error::TooLong));
+}
+
+#[test]
+fn copy_from_raw_and_into_raw_round_trip() {
+    let original = UnicodeString::try_from(r"\Registry\Machine\System").unwrap();
+    let copy = unsafe { UnicodeString::copy_from_raw_in(original.as_raw(), Pool::paged(pool_tag!("Test"))) }.unwrap();
+    assert_eq!(copy, original);
+    assert_eq!(copy.allocator().tag(), pool_tag!("Test"));
+    assert_eq!(copy.allocator().pool_type(), PoolType::Paged);
+
+    let (raw, pool) = copy.into_raw();
+    let restored = unsafe { UnicodeString::from_raw_parts(raw, pool) };
+    assert_eq!(restored, original);
+}
+
+#[test]
+fn copy_from_raw_rejects_malformed_strings() {
+    let mut units = [0x41u16, 0x42, 0x43, 0];
+    let buffer = units.as_mut_ptr();
+    let misaligned = unsafe { (buffer as *mut u8).add(1) } as *mut u16;
+    let copy = |length, maximum_length, buffer| {
+        let raw = UNICODE_STRING {
+            Length: length,
+            MaximumLength: maximum_length,
+            Buffer: buffer,
+        };
+        unsafe { UnicodeString::copy_from_raw_in(&raw, Pool::default()) }
+    };
+
+    assert_eq!(copy(6, 8, buffer).unwrap(), "ABC");
+    assert_eq!(copy(5, 8, buffer).err(), Some(Error::OddLength));
+    assert_eq!(copy(8, 6, buffer).err(), Some(Error::LengthExceedsMaximum));
+    assert_eq!(copy(2, 2, misaligned).err(), Some(Error::InvalidBuffer));
+    assert_eq!(copy(2, 2, core::ptr::null_mut()).err(), Some(Error::InvalidBuffer));
+    assert!(copy(0, 0, core::ptr::null_mut()).unwrap().is_empty());
+}
+
+#[test]
+fn equal_strings_hash_equally_across_allocators() {
+    let live = Cell::new(0);
+    let a = UnicodeString::try_from("Zero").unwrap();
+    let b = UnicodeString::from_str_in("Zero", CountingPool { live: &live, fail: false }).unwrap();
+    assert!(a == b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn debug_escapes_and_display_replaces_lone_surrogates() {
+    let s = UnicodeString::try_from(&[0x61, 0xd800, 0x22][..]).unwrap();
+    assert_eq!(format!("{s:?}"), r#""a\u{d800}\"""#);
+    assert_eq!(s.to_string(), "a\u{FFFD}\"");
+}
@@ -0,0 +1,57 @@
+use core::fmt::Write;
+
+use wdk_strings::{Error, UnicodeStr, UnicodeStringBuf};
+
+#[test]
+fn formats_device_names_without_allocating() {
+    let name = UnicodeStringBuf::<32>::from_fmt(format_args!(r"\Device\Zero{}", 3)).unwrap();
+    assert_eq!(name, r"\Device\Zero3");
+    assert!(!name.is_truncated());
+
+    let raw = name.to_raw();
+    assert_eq!(raw.Length, 26);
+    assert_eq!(raw.MaximumLength, 64);
+    assert_eq!(unsafe { *raw.Buffer.add(13) }, 0);
+    assert_eq!(unsafe { UnicodeStr::from_raw(&raw) }.unwrap(), r"\Device\Zero3");
+}
+
+#[test]
+fn truncation_keeps_what_fits_and_is_reported() {
+    let mut name = UnicodeStringBuf::<8>::new();
+    assert_eq!(name.append_fmt(format_args!("{}-{}", "Booster", 42)), Err(Error::Truncated));
+    assert!(name.is_truncated());
+    assert_eq!(name, "Booster-");
+    assert_eq!(name.to_raw().Length, 16);
+    assert_eq!(name.to_raw().MaximumLength, 16);
+
+    // Once truncated, further output is rejected so the result never has gaps.
+    assert!(write!(name, "x").is_err());
+    name.clear();
+    assert!(!name.is_truncated());
+    assert!(write!(name, "x").is_ok());
+    assert_eq!(name, "x");
+}
+
+#[test]
+fn surrogate_pairs_are_not_split() {
+    let mut s = UnicodeStringBuf::<2>::new();
+    assert!(write!(s, "a\u{1F600}").is_err());
+    assert_eq!(s.as_slice(), &[0x61]);
+
+    let mut s = UnicodeStringBuf::<3>::new();
+    assert!(write!(s, "a\u{1F600}").is_ok());
+    assert_eq!(s.as_slice(), &[0x61, 0xd83d, 0xde00]);
+}
+
+#[test]
+fn push_utf16_is_all_or_nothing() {
+    let mut s = UnicodeStringBuf::<4>::new();
+    s.push_utf16(&[1, 2, 3]).unwrap();
+    assert_eq!(s.push_utf16(&[4, 5]), Err(Error::Truncated));
+    assert_eq!(s.as_slice(), &[1, 2, 3]);
+    assert_eq!(s.capacity(), 4);
+
+    // A shorter push after the failed one would leave a gap.
+    assert_eq!(s.push_utf16(&[6]), Err(Error::Truncated));
+    assert_eq!(s.as_slice(), &[1, 2, 3]);
+}
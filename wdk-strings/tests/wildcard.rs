@@ -0,0 +1,144 @@
+use wdk_strings::ustr;
+use wdk_strings::wildcard::{contains_wildcards, is_name_in_expression};
+
+fn utf16(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+/// (expression, name, ignore_case, expected)
+///
+/// `<`, `>` and `"` are DOS_STAR, DOS_QM and DOS_DOT.
+const CASES: &[(&str, &str, bool, bool)] = &[
+    // Empty operands only match each other.
+    ("", "", false, true),
+    ("", "a", false, false),
+    ("*", "", false, false),
+    ("**", "", false, false),
+    ("?", "", false, false),
+    // Literals.
+    ("ntdll.dll", "ntdll.dll", false, true),
+    ("ntdll.dll", ".", false, false),
+    ("ntdll.dll", "..", false, false),
+    ("ntdll.dll", "~1", false, false),
+    ("ntdll.dll", "ntdll.dl", false, false),
+    ("ntdll.dll", "ntdll.dlll", false, false),
+    ("smss.exe", "ntdll.dll", false, false),
+    (".", "NTFS", false, false),
+    // Case folding.
+    ("NTDLL.DLL", "ntdll.dll", false, false),
+    ("NTDLL.DLL", "ntdll.dll", true, true),
+    ("ntdll.dll", "NTDLL.DLL", true, true),
+    ("ÄÖÜ.TXT", "äöü.txt", true, true),
+    // `*`
+    ("*", "a", false, true),
+    ("*", ".", false, true),
+    ("**", "a", false, true),
+    ("he*o", "hello", false, true),
+    ("he*o", "helo", false, true),
+    ("he*o", "heo", false, true),
+    ("he*o", "hella", false, false),
+    ("he*", "hella", false, true),
+    ("*.cpl", "kdcom.dll", false, false),
+    ("*.cpl", "main.cpl", false, true),
+    ("*.exe", "notepad.exe.exe", false, true),
+    ("*.*", "abc", false, false),
+    ("*.*", "abc.", false, true),
+    ("*.*", ".abc", false, true),
+    ("*~*", "NTOSKRN~1.EXE", false, true),
+    ("*~*", "NTOSKRNL.EXE", false, false),
+    ("*\\*", "\\Device\\Zero", false, true),
+    ("*.EXE", "notepad.exe", true, true),
+    ("*.EXE", "notepad.exe", false, false),
+    // `?`
+    ("?", "a", false, true),
+    ("?", ".", false, true),
+    ("?", "ab", false, false),
+    ("??", "a", false, false),
+    ("*?", "a", false, true),
+    ("*?", "aa", false, true),
+    ("*??", "a", false, false),
+    ("*???", "abc", false, true),
+    ("nt??krnl.???", "ntoskrnl.exe", false, true),
+    ("nt??krnl.???", "ntoskrnl.ex", false, false),
+    // DOS_STAR stops before the final period.
+    ("<", "abc", false, true),
+    ("<", "a.b", false, false),
+    ("<", "a.b.c", false, false),
+    ("<.b", "a.b", false, true),
+    ("<.c", "a.b.c", false, true),
+    ("<.b", "a.b.c", false, false),
+    ("<.EXE", "NTOSKRNL.EXE", false, true),
+    ("<.EXE", "NTOSKRNL.EXE.EXE", false, true),
+    ("<*", "a.b", false, true),
+    ("<\"*", "abc", false, true),
+    ("<\"*", "abc.def", false, true),
+    ("<\"*", "a.b.c", false, true),
+    ("<\"<", "a.b", false, true),
+    ("<\"<", "a.b.c", false, true),
+    ("<\"<", "abc", false, true),
+    // DOS_QM matches one character, or nothing at a period or the end.
+    (">", "a", false, true),
+    (">", "ab", false, false),
+    (">", ".", false, false),
+    ("abc>>>", "abc", false, true),
+    ("abc>>>", "abcd", false, true),
+    ("abc>>>", "abcdef", false, true),
+    ("abc>>>", "abcdefg", false, false),
+    ("a>>.txt", "a.txt", false, true),
+    ("a>>.txt", "ab.txt", false, true),
+    ("a>>.txt", "abc.txt", false, true),
+    ("a>>.txt", "abcd.txt", false, false),
+    ("a>b", "ab", false, false),
+    ("a>b", "acb", false, true),
+    ("a>b", "a.b", false, false),
+    (">>>>>>>>\">>>", "ntoskrnl.exe", false, true),
+    (">>>>>>>>\">>>", "ntdll.dll", false, true),
+    (">>>>>>>>\">>>", "autoexec", false, true),
+    (">>>>>>>>\">>>", "toolongname.exe", false, false),
+    (">>>>>>>>\">>>", "name.long", false, false),
+    // DOS_DOT matches a period, or nothing at the end of the name.
+    ("a\"", "a", false, true),
+    ("a\"", "a.", false, true),
+    ("a\"", "ab", false, false),
+    ("a\"b", "a.b", false, true),
+    ("a\"b", "ab", false, false),
+    ("a\"\"", "a", false, true),
+    ("a\"\"", "a.", false, true),
+    ("a\"\"", "a..", false, true),
+    ("a\"\"", "a...", false, false),
+    ("\"", ".", false, true),
+];
+
+#[test]
+fn documented_fsrtl_behaviour() {
+    for &(expression, name, ignore_case, expected) in CASES {
+        assert_eq!(
+            is_name_in_expression(&utf16(expression), &utf16(name), ignore_case),
+            expected,
+            "expression {expression:?}, name {name:?}, ignore_case {ignore_case}"
+        );
+    }
+}
+
+#[test]
+fn long_expressions_fall_back_to_heap_states() {
+    let expression = "*a".repeat(600);
+    let name = "ba".repeat(600);
+    assert!(is_name_in_expression(&utf16(&expression), &utf16(&name), false));
+    assert!(!is_name_in_expression(&utf16(&expression), &utf16(&name[..name.len() - 1]), false));
+}
+
+#[test]
+fn wildcard_detection() {
+    assert!(!contains_wildcards(&utf16("ntoskrnl.exe")));
+    for wildcard in ["*", "?", "<", ">", "\""] {
+        assert!(contains_wildcards(&utf16(&format!("a{wildcard}b"))), "{wildcard}");
+    }
+}
+
+#[test]
+fn unicode_str_convenience() {
+    let image = ustr!(r"\Device\HarddiskVolume3\Windows\System32\notepad.exe");
+    assert!(image.matches_expression(ustr!(r"*\NOTEPAD.EXE"), true));
+    assert!(!image.matches_expression(ustr!(r"*\NOTEPAD.EXE"), false));
+}
@@ -0,0 +1,62 @@
+#!/usr/bin/env perl
+# Generates src/upcase_table.rs, the Unicode 6.0 simple uppercase mapping of
+# the BMP that the kernel's upcase table was frozen at:
+#
+#     perl tools/upcase_table.pl > src/upcase_table.rs
+#
+# A mapping is kept if both of its characters were assigned in Unicode 6.0,
+# so the output only depends on the age data of the installed Perl, not on
+# its Unicode version.
+
+use strict;
+use warnings;
+use Unicode::UCD qw(charprop prop_invmap);
+
+sub in_6_0 {
+    my ($cp) = @_;
+    my $age = charprop($cp, 'Age');
+    return 0 unless $age =~ /^V(\d+)_(\d+)$/;
+    return $1 < 6 || ($1 == 6 && $2 == 0);
+}
+
+my ($ranges, $map, $format) = prop_invmap('Simple_Uppercase_Mapping');
+die "unexpected format $format" unless $format =~ /^a/;
+
+my @pairs;
+for my $i (0 .. $#$ranges - 1) {
+    my $value = $map->[$i];
+    next if ref $value || $value eq '0';
+    for my $cp ($ranges->[$i] .. $ranges->[$i + 1] - 1) {
+        last if $cp > 0xffff;
+        next if $cp < 0x80;
+        my $upper = $format eq 'a' ? $value + $cp - $ranges->[$i] : $value;
+        next if $upper > 0xffff || $upper == $cp;
+        next unless in_6_0($cp) && in_6_0($upper);
+        push @pairs, [$cp, $upper - $cp];
+    }
+}
+@pairs = sort { $a->[0] <=> $b->[0] } @pairs;
+
+# Runs of characters with the same offset to their uppercase form, either
+# contiguous or every other code point.
+my @runs;
+for my $pair (@pairs) {
+    my ($cp, $delta) = @$pair;
+    my $run = $runs[-1];
+    if ($run && $run->{delta} == $delta) {
+        my $step = $cp - $run->{last};
+        if ($run->{first} == $run->{last} ? ($step == 1 || $step == 2) : $step == $run->{step}) {
+            $run->{step} = $step;
+            $run->{last} = $cp;
+            next;
+        }
+    }
+    push @runs, { first => $cp, last => $cp, step => 1, delta => $delta };
+}
+
+print "// Generated by tools/upcase_table.pl, do not edit.\n\n";
+print "/// `(first, last, step, offset)`: every `step`th code unit from `first`\n";
+print "/// through `last` upcases to itself plus `offset`, wrapping around.\n";
+print "pub(crate) static UPCASE_RUNS: &[(u16, u16, u16, u16)] = &[\n";
+printf "    (0x%04x, 0x%04x, %d, 0x%04x),\n", $_->{first}, $_->{last}, $_->{step}, $_->{delta} & 0xffff for @runs;
+print "];\n";
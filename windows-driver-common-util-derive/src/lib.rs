@@ -0,0 +1,146 @@
+//! Derive macros for `windows-driver-common-util`. Use them through its
+//! `derive` feature, which re-exports them next to the traits they implement.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::{Data, DeriveInput, Error, Member, Type, parse_macro_input};
+
+/// Verifies that a `#[repr(C)]` struct has the same layout for 32-bit and
+/// 64-bit processes and implements `WireStruct`, `WireType` and `Pod` for it.
+/// See `windows_driver_common_util::wire`.
+#[proc_macro_derive(WireStruct)]
+pub fn derive_wire_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    wire_struct(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn wire_struct(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "WireStruct can only be derived for structs",
+            ));
+        }
+    };
+    if !is_repr_c(input)? {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "WireStruct requires #[repr(C)]",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "WireStruct cannot be derived for generic structs",
+        ));
+    }
+    if fields.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "WireStruct requires at least one field",
+        ));
+    }
+    for field in fields {
+        check_type(&field.ty)?;
+    }
+
+    let name = &input.ident;
+    let krate = quote!(::windows_driver_common_util);
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let members: Vec<_> = fields.members().collect();
+    let field_names = members.iter().map(|member| match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+    let type_names = types
+        .iter()
+        .map(|ty| ty.to_token_stream().to_string().replace(' ', ""));
+
+    Ok(quote! {
+        const _: () = #krate::wire::assert_layout(
+            [#(::core::mem::offset_of!(#name, #members)),*],
+            ::core::mem::size_of::<#name>(),
+            ::core::mem::align_of::<#name>(),
+            [#((
+                ::core::mem::size_of::<#types>(),
+                <#types as #krate::wire::WireType>::WIRE_ALIGN,
+            )),*],
+        );
+
+        // SAFETY: `repr(C)` without padding, checked above, and every field
+        // is a `WireType`, hence `Pod`.
+        unsafe impl #krate::pod::Pod for #name {}
+
+        // SAFETY: The layout was checked above to be the wire layout.
+        unsafe impl #krate::wire::WireType for #name {
+            const WIRE_ALIGN: usize = ::core::mem::align_of::<Self>();
+            const WIRE_FINGERPRINT: u64 = #krate::wire::fingerprint(
+                [#((
+                    <#types as #krate::wire::WireType>::WIRE_FINGERPRINT,
+                    ::core::mem::offset_of!(Self, #members),
+                )),*],
+                ::core::mem::size_of::<Self>(),
+                ::core::mem::align_of::<Self>(),
+            );
+        }
+
+        impl #krate::wire::WireStruct for #name {
+            const FIELDS: &'static [#krate::wire::WireField] = &[#(
+                #krate::wire::WireField {
+                    name: #field_names,
+                    ty: #type_names,
+                    offset: ::core::mem::offset_of!(Self, #members),
+                    size: ::core::mem::size_of::<#types>(),
+                }
+            ),*];
+        }
+    })
+}
+
+fn is_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+    let mut repr_c = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of `align(..)` and `packed(..)`.
+                let _ = meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr_c)
+}
+
+/// Rejects field types whose size depends on the pointer width, with a more
+/// helpful message than the missing `WireType` implementation would give.
+fn check_type(ty: &Type) -> syn::Result<()> {
+    let message = match ty {
+        Type::Ptr(_) | Type::Reference(_) | Type::BareFn(_) => {
+            "WireStruct fields must not be pointers, whose size differs between 32-bit and 64-bit processes"
+        }
+        Type::Path(path)
+            if path.qself.is_none()
+                && path.path.segments.last().is_some_and(|segment| {
+                    segment.ident == "usize" || segment.ident == "isize"
+                }) =>
+        {
+            "WireStruct fields must not be usize or isize, whose size differs between 32-bit and 64-bit processes; use u32 or u64"
+        }
+        Type::Array(array) => return check_type(&array.elem),
+        Type::Group(group) => return check_type(&group.elem),
+        Type::Paren(paren) => return check_type(&paren.elem),
+        _ => return Ok(()),
+    };
+    Err(Error::new_spanned(ty, message))
+}
@@ -0,0 +1,205 @@
+//! GUIDs, e.g. for tracelogging providers and device interfaces, that can be
+//! written as literals and shared between a driver and its clients.
+//!
+//! [`Guid`] is this crate's own type, so it can be parsed and formatted on any
+//! host. [`GUID`] is the type the Windows APIs expect: from `wdk-sys` with the
+//! `kernel` feature, from the `windows` crate with the `user` feature, and a
+//! layout-compatible stand-in otherwise.
+
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(all(feature = "kernel", feature = "user"))]
+compile_error!("the `kernel` and `user` features of windows-driver-common-util are mutually exclusive");
+
+#[cfg(feature = "kernel")]
+pub use wdk_sys::GUID;
+
+#[cfg(all(feature = "user", not(feature = "kernel")))]
+pub use windows::core::GUID;
+
+/// Host stand-in for `GUID`, with identical layout.
+#[cfg(not(any(feature = "kernel", feature = "user")))]
+#[allow(non_snake_case)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GUID {
+    pub Data1: u32,
+    pub Data2: u16,
+    pub Data3: u16,
+    pub Data4: [u8; 8],
+}
+
+/// A GUID such as `{B2723AD5-1678-446D-A577-8599D3E85ECB}`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+/// Errors returned when parsing a [`Guid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseGuidError {
+    /// The string does not have 36 characters, or 38 with braces.
+    InvalidLength,
+    /// An opening brace without a closing one, or the other way round.
+    UnmatchedBrace,
+    /// A hyphen is missing or in the wrong place.
+    MisplacedHyphen,
+    /// A character other than a hexadecimal digit where one is expected.
+    InvalidDigit,
+}
+
+impl ParseGuidError {
+    const fn message(self) -> &'static str {
+        match self {
+            ParseGuidError::InvalidLength => "GUID must have the form xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+            ParseGuidError::UnmatchedBrace => "GUID has unmatched braces",
+            ParseGuidError::MisplacedHyphen => "GUID has a missing or misplaced hyphen",
+            ParseGuidError::InvalidDigit => "GUID contains a character that is not a hexadecimal digit",
+        }
+    }
+}
+
+impl fmt::Display for ParseGuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ParseGuidError {}
+
+/// Positions of the hyphens in the unbraced form.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+impl Guid {
+    pub const fn from_values(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self { data1, data2, data3, data4 }
+    }
+
+    /// The GUID whose canonical form is the hexadecimal representation of
+    /// `value`, e.g. `0xb2723ad5_1678_446d_a577_8599d3e85ecb`.
+    pub const fn from_u128(value: u128) -> Self {
+        Self {
+            data1: (value >> 96) as u32,
+            data2: (value >> 80) as u16,
+            data3: (value >> 64) as u16,
+            data4: (value as u64).to_be_bytes(),
+        }
+    }
+
+    pub const fn to_u128(&self) -> u128 {
+        ((self.data1 as u128) << 96)
+            | ((self.data2 as u128) << 80)
+            | ((self.data3 as u128) << 64)
+            | u64::from_be_bytes(self.data4) as u128
+    }
+
+    /// Parses `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`, optionally enclosed in
+    /// braces as in the registry. Hexadecimal digits may have either case.
+    pub const fn parse(s: &str) -> Result<Self, ParseGuidError> {
+        let mut bytes = s.as_bytes();
+        match bytes {
+            [b'{', inner @ .., b'}'] => bytes = inner,
+            [b'{', ..] | [.., b'}'] => return Err(ParseGuidError::UnmatchedBrace),
+            _ => {}
+        }
+        if bytes.len() != 36 {
+            return Err(ParseGuidError::InvalidLength);
+        }
+
+        let mut value: u128 = 0;
+        let mut pos = 0;
+        let mut hyphen = 0;
+        while pos < bytes.len() {
+            let byte = bytes[pos];
+            if hyphen < HYPHENS.len() && pos == HYPHENS[hyphen] {
+                if byte != b'-' {
+                    return Err(ParseGuidError::MisplacedHyphen);
+                }
+                hyphen += 1;
+            } else {
+                let digit = match byte {
+                    b'0'..=b'9' => byte - b'0',
+                    b'a'..=b'f' => byte - b'a' + 10,
+                    b'A'..=b'F' => byte - b'A' + 10,
+                    b'-' => return Err(ParseGuidError::MisplacedHyphen),
+                    _ => return Err(ParseGuidError::InvalidDigit),
+                };
+                value = (value << 4) | digit as u128;
+            }
+            pos += 1;
+        }
+        Ok(Self::from_u128(value))
+    }
+
+    /// Like [`parse`](Self::parse), but panics on malformed input, which fails
+    /// compilation in const contexts. Used by [`guid!`](crate::guid).
+    pub const fn parse_or_panic(s: &str) -> Self {
+        match Self::parse(s) {
+            Ok(guid) => guid,
+            Err(error) => panic!("{}", error.message()),
+        }
+    }
+
+    /// Converts to the `GUID` type of the Windows APIs.
+    pub const fn to_sys(self) -> GUID {
+        #[cfg(not(all(feature = "user", not(feature = "kernel"))))]
+        return GUID {
+            Data1: self.data1,
+            Data2: self.data2,
+            Data3: self.data3,
+            Data4: self.data4,
+        };
+        #[cfg(all(feature = "user", not(feature = "kernel")))]
+        return GUID::from_values(self.data1, self.data2, self.data3, self.data4);
+    }
+
+    pub const fn from_sys(guid: &GUID) -> Self {
+        #[cfg(not(all(feature = "user", not(feature = "kernel"))))]
+        return Self::from_values(guid.Data1, guid.Data2, guid.Data3, guid.Data4);
+        #[cfg(all(feature = "user", not(feature = "kernel")))]
+        return Self::from_values(guid.data1, guid.data2, guid.data3, guid.data4);
+    }
+}
+
+impl From<Guid> for GUID {
+    fn from(guid: Guid) -> Self {
+        guid.to_sys()
+    }
+}
+
+impl From<GUID> for Guid {
+    fn from(guid: GUID) -> Self {
+        Guid::from_sys(&guid)
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Writes the braced, uppercase registry form, like `StringFromGUID2`.
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g, h, i] = self.data4;
+        write!(
+            f,
+            "{{{:08X}-{:04X}-{:04X}-{a:02X}{b:02X}-{c:02X}{d:02X}{e:02X}{g:02X}{h:02X}{i:02X}}}",
+            self.data1, self.data2, self.data3,
+        )
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,421 @@
+//! I/O control codes, as built by the `CTL_CODE` macro of the Windows headers:
+//!
+//! ```text
+//!  31          16 15  14 13          2 1      0
+//! +--------------+------+-------------+--------+
+//! | device type  |access|  function   | method |
+//! +--------------+------+-------------+--------+
+//! ```
+
+use core::ffi::c_void;
+use core::fmt;
+use core::mem::size_of;
+
+use crate::pod::Pod;
+use crate::status::{NTSTATUS, STATUS_BUFFER_TOO_SMALL, STATUS_INVALID_PARAMETER};
+
+// Device types, the `DEVICE_TYPE` (a `ULONG`) passed to `IoCreateDevice`.
+// Types below 0x8000 are reserved for Microsoft.
+pub const FILE_DEVICE_BEEP: u32 = 0x01;
+pub const FILE_DEVICE_CD_ROM: u32 = 0x02;
+pub const FILE_DEVICE_CD_ROM_FILE_SYSTEM: u32 = 0x03;
+pub const FILE_DEVICE_CONTROLLER: u32 = 0x04;
+pub const FILE_DEVICE_DATALINK: u32 = 0x05;
+pub const FILE_DEVICE_DFS: u32 = 0x06;
+pub const FILE_DEVICE_DISK: u32 = 0x07;
+pub const FILE_DEVICE_DISK_FILE_SYSTEM: u32 = 0x08;
+pub const FILE_DEVICE_FILE_SYSTEM: u32 = 0x09;
+pub const FILE_DEVICE_INPORT_PORT: u32 = 0x0a;
+pub const FILE_DEVICE_KEYBOARD: u32 = 0x0b;
+pub const FILE_DEVICE_MAILSLOT: u32 = 0x0c;
+pub const FILE_DEVICE_MIDI_IN: u32 = 0x0d;
+pub const FILE_DEVICE_MIDI_OUT: u32 = 0x0e;
+pub const FILE_DEVICE_MOUSE: u32 = 0x0f;
+pub const FILE_DEVICE_MULTI_UNC_PROVIDER: u32 = 0x10;
+pub const FILE_DEVICE_NAMED_PIPE: u32 = 0x11;
+pub const FILE_DEVICE_NETWORK: u32 = 0x12;
+pub const FILE_DEVICE_NETWORK_BROWSER: u32 = 0x13;
+pub const FILE_DEVICE_NETWORK_FILE_SYSTEM: u32 = 0x14;
+pub const FILE_DEVICE_NULL: u32 = 0x15;
+pub const FILE_DEVICE_PARALLEL_PORT: u32 = 0x16;
+pub const FILE_DEVICE_PHYSICAL_NETCARD: u32 = 0x17;
+pub const FILE_DEVICE_PRINTER: u32 = 0x18;
+pub const FILE_DEVICE_SCANNER: u32 = 0x19;
+pub const FILE_DEVICE_SERIAL_MOUSE_PORT: u32 = 0x1a;
+pub const FILE_DEVICE_SERIAL_PORT: u32 = 0x1b;
+pub const FILE_DEVICE_SCREEN: u32 = 0x1c;
+pub const FILE_DEVICE_SOUND: u32 = 0x1d;
+pub const FILE_DEVICE_STREAMS: u32 = 0x1e;
+pub const FILE_DEVICE_TAPE: u32 = 0x1f;
+pub const FILE_DEVICE_TAPE_FILE_SYSTEM: u32 = 0x20;
+pub const FILE_DEVICE_TRANSPORT: u32 = 0x21;
+pub const FILE_DEVICE_UNKNOWN: u32 = 0x22;
+pub const FILE_DEVICE_VIDEO: u32 = 0x23;
+pub const FILE_DEVICE_VIRTUAL_DISK: u32 = 0x24;
+pub const FILE_DEVICE_WAVE_IN: u32 = 0x25;
+pub const FILE_DEVICE_WAVE_OUT: u32 = 0x26;
+pub const FILE_DEVICE_8042_PORT: u32 = 0x27;
+pub const FILE_DEVICE_NETWORK_REDIRECTOR: u32 = 0x28;
+pub const FILE_DEVICE_BATTERY: u32 = 0x29;
+pub const FILE_DEVICE_BUS_EXTENDER: u32 = 0x2a;
+pub const FILE_DEVICE_MODEM: u32 = 0x2b;
+pub const FILE_DEVICE_VDM: u32 = 0x2c;
+pub const FILE_DEVICE_MASS_STORAGE: u32 = 0x2d;
+pub const FILE_DEVICE_SMB: u32 = 0x2e;
+pub const FILE_DEVICE_KS: u32 = 0x2f;
+pub const FILE_DEVICE_CHANGER: u32 = 0x30;
+pub const FILE_DEVICE_SMARTCARD: u32 = 0x31;
+pub const FILE_DEVICE_ACPI: u32 = 0x32;
+pub const FILE_DEVICE_DVD: u32 = 0x33;
+pub const FILE_DEVICE_FULLSCREEN_VIDEO: u32 = 0x34;
+pub const FILE_DEVICE_DFS_FILE_SYSTEM: u32 = 0x35;
+pub const FILE_DEVICE_DFS_VOLUME: u32 = 0x36;
+pub const FILE_DEVICE_SERENUM: u32 = 0x37;
+pub const FILE_DEVICE_TERMSRV: u32 = 0x38;
+pub const FILE_DEVICE_KSEC: u32 = 0x39;
+pub const FILE_DEVICE_FIPS: u32 = 0x3a;
+pub const FILE_DEVICE_INFINIBAND: u32 = 0x3b;
+
+// Transfer methods, the `Method` argument of `CTL_CODE`.
+pub const METHOD_BUFFERED: u32 = 0;
+pub const METHOD_IN_DIRECT: u32 = 1;
+pub const METHOD_OUT_DIRECT: u32 = 2;
+pub const METHOD_NEITHER: u32 = 3;
+pub const METHOD_DIRECT_TO_HARDWARE: u32 = METHOD_IN_DIRECT;
+pub const METHOD_DIRECT_FROM_HARDWARE: u32 = METHOD_OUT_DIRECT;
+
+// Required access, the `Access` argument of `CTL_CODE`.
+pub const FILE_ANY_ACCESS: u32 = 0;
+pub const FILE_SPECIAL_ACCESS: u32 = FILE_ANY_ACCESS;
+pub const FILE_READ_ACCESS: u32 = 1;
+pub const FILE_WRITE_ACCESS: u32 = 2;
+
+/// How the I/O manager passes the buffers of a device control request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferMethod {
+    Buffered = 0,
+    InDirect = 1,
+    OutDirect = 2,
+    Neither = 3,
+}
+
+impl TransferMethod {
+    /// The method encoded in the low two bits of `bits`.
+    pub const fn from_bits(bits: u32) -> Self {
+        match bits & 0x3 {
+            0 => TransferMethod::Buffered,
+            1 => TransferMethod::InDirect,
+            2 => TransferMethod::OutDirect,
+            _ => TransferMethod::Neither,
+        }
+    }
+
+    /// The name of the `METHOD_*` constant.
+    pub const fn name(self) -> &'static str {
+        match self {
+            TransferMethod::Buffered => "METHOD_BUFFERED",
+            TransferMethod::InDirect => "METHOD_IN_DIRECT",
+            TransferMethod::OutDirect => "METHOD_OUT_DIRECT",
+            TransferMethod::Neither => "METHOD_NEITHER",
+        }
+    }
+}
+
+/// The access to the device handle a device control request requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RequiredAccess {
+    Any = 0,
+    Read = 1,
+    Write = 2,
+    ReadWrite = 3,
+}
+
+impl RequiredAccess {
+    /// The access encoded in the low two bits of `bits`.
+    pub const fn from_bits(bits: u32) -> Self {
+        match bits & 0x3 {
+            0 => RequiredAccess::Any,
+            1 => RequiredAccess::Read,
+            2 => RequiredAccess::Write,
+            _ => RequiredAccess::ReadWrite,
+        }
+    }
+
+    /// The `FILE_*_ACCESS` expression.
+    pub const fn name(self) -> &'static str {
+        match self {
+            RequiredAccess::Any => "FILE_ANY_ACCESS",
+            RequiredAccess::Read => "FILE_READ_ACCESS",
+            RequiredAccess::Write => "FILE_WRITE_ACCESS",
+            RequiredAccess::ReadWrite => "FILE_READ_ACCESS | FILE_WRITE_ACCESS",
+        }
+    }
+}
+
+/// An I/O control code, e.g. the `IoControlCode` of a device control request.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IoctlCode(u32);
+
+impl IoctlCode {
+    /// Builds a code like `CTL_CODE`.
+    ///
+    /// Panics, which fails compilation in const contexts, if a field does not
+    /// fit: device types are 16 bits, functions 12 bits, and methods and
+    /// access 2 bits each.
+    pub const fn new(device_type: u32, function: u32, method: u32, access: u32) -> Self {
+        if device_type > 0xffff {
+            panic!("IOCTL device type must fit into 16 bits");
+        }
+        if function > 0xfff {
+            panic!("IOCTL function must fit into 12 bits");
+        }
+        if method > 0x3 {
+            panic!("IOCTL method must be one of the METHOD_* constants");
+        }
+        if access > 0x3 {
+            panic!("IOCTL access must be a combination of the FILE_*_ACCESS constants");
+        }
+        Self((device_type << 16) | (access << 14) | (function << 2) | method)
+    }
+
+    /// Wraps a code received in a request. Every value is a valid code.
+    pub const fn from_raw(code: u32) -> Self {
+        Self(code)
+    }
+
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    pub const fn device_type(self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    pub const fn function(self) -> u16 {
+        ((self.0 >> 2) & 0xfff) as u16
+    }
+
+    pub const fn method(self) -> TransferMethod {
+        TransferMethod::from_bits(self.0)
+    }
+
+    pub const fn access(self) -> RequiredAccess {
+        RequiredAccess::from_bits(self.0 >> 14)
+    }
+}
+
+impl From<IoctlCode> for u32 {
+    fn from(code: IoctlCode) -> Self {
+        code.as_u32()
+    }
+}
+
+impl From<u32> for IoctlCode {
+    fn from(code: u32) -> Self {
+        Self::from_raw(code)
+    }
+}
+
+/// Writes the code as the `CTL_CODE` invocation that produces it, e.g.
+/// `CTL_CODE(0x8022, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)`.
+impl fmt::Display for IoctlCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CTL_CODE({:#x}, {:#x}, {}, {})",
+            self.device_type(),
+            self.function(),
+            self.method().name(),
+            self.access().name()
+        )
+    }
+}
+
+impl fmt::Debug for IoctlCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IoctlCode({:#010x} = {self})", self.0)
+    }
+}
+
+/// A device control request with typed buffers, e.g. as declared by
+/// [`define_ioctls!`](crate::define_ioctls).
+///
+/// The generic helpers check at compile time that METHOD_NEITHER requests,
+/// and only those, declare [`USER_POINTERS`](Self::USER_POINTERS).
+pub trait Ioctl {
+    const CODE: IoctlCode;
+
+    /// What the caller passes in, `()` for nothing.
+    type Input: Pod;
+
+    /// What the driver returns, `()` for nothing.
+    type Output: Pod;
+
+    /// For METHOD_NEITHER requests, whether the buffers are user-mode
+    /// addresses the driver accesses directly. Requests without them must
+    /// have neither input nor output.
+    const USER_POINTERS: Option<bool> = None;
+}
+
+/// Metadata of an [`Ioctl`], for tools that inspect the requests a crate
+/// declares without sending them. `define_ioctls!` lists them in the
+/// `IOCTLS` constant of the request enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoctlInfo {
+    /// The name of the code constant, e.g. `IOCTL_ZERO_GET_STATS`.
+    pub name: &'static str,
+    pub code: IoctlCode,
+    pub input_size: usize,
+    pub output_size: usize,
+    pub user_pointers: Option<bool>,
+}
+
+impl IoctlInfo {
+    pub const fn of<I: Ioctl>(name: &'static str) -> Self {
+        Self {
+            name,
+            code: I::CODE,
+            input_size: size_of::<I::Input>(),
+            output_size: size_of::<I::Output>(),
+            user_pointers: I::USER_POINTERS,
+        }
+    }
+}
+
+/// Fails compilation for inconsistent [`Ioctl`] declarations when used in a
+/// const context.
+///
+/// METHOD_NEITHER requests may only carry input: their output would be a
+/// user-mode address, which Rust code cannot write to without the exception
+/// guard of `__try`, so `windows-drivers-util` refuses it.
+pub const fn check<I: Ioctl>() {
+    let neither = matches!(I::CODE.method(), TransferMethod::Neither);
+    match I::USER_POINTERS {
+        None if neither => {
+            panic!("METHOD_NEITHER ioctls must declare whether they carry user pointers")
+        }
+        Some(_) if !neither => panic!("only METHOD_NEITHER ioctls can carry user pointers"),
+        Some(false) if size_of::<I::Input>() != 0 || size_of::<I::Output>() != 0 => {
+            panic!("METHOD_NEITHER ioctls without user pointers cannot have input or output")
+        }
+        Some(true) if size_of::<I::Output>() != 0 => {
+            panic!("METHOD_NEITHER ioctls with user pointers cannot have output")
+        }
+        _ => {}
+    }
+}
+
+/// Where the input of a request is read from, e.g. the input buffer of an
+/// IRP. Only the bytes of the requested type are read, so a source can copy
+/// them from user mode on demand.
+pub trait InputSource {
+    /// Reads a `T` from the start of the input, failing with
+    /// `STATUS_BUFFER_TOO_SMALL` if it is shorter.
+    fn read<T: Pod>(&self) -> Result<T, NTSTATUS>;
+}
+
+impl InputSource for [u8] {
+    fn read<T: Pod>(&self) -> Result<T, NTSTATUS> {
+        if self.len() < size_of::<T>() {
+            return Err(STATUS_BUFFER_TOO_SMALL);
+        }
+        // SAFETY: The slice holds a `T`, and any bit pattern is a valid
+        // `Pod`. Buffers from user mode need not be aligned.
+        Ok(unsafe { self.as_ptr().cast::<T>().read_unaligned() })
+    }
+}
+
+/// An input buffer given as pointer and length.
+#[derive(Debug, Clone, Copy)]
+pub struct RawInput {
+    input: *const c_void,
+    len: u32,
+}
+
+impl RawInput {
+    /// # Safety
+    ///
+    /// `input` must be null or valid for reads of `len` bytes while the
+    /// value is used. For METHOD_NEITHER requests with user pointers, it
+    /// must have been probed.
+    pub const unsafe fn new(input: *const c_void, len: u32) -> Self {
+        Self { input, len }
+    }
+}
+
+impl InputSource for RawInput {
+    fn read<T: Pod>(&self) -> Result<T, NTSTATUS> {
+        if (self.len as usize) < size_of::<T>() {
+            return Err(STATUS_BUFFER_TOO_SMALL);
+        }
+        if self.input.is_null() {
+            return Err(STATUS_INVALID_PARAMETER);
+        }
+        // SAFETY: `new` guarantees `len` readable bytes, which cover a `T`.
+        // Buffers from user mode need not be aligned.
+        Ok(unsafe { self.input.cast::<T>().read_unaligned() })
+    }
+}
+
+/// Reads the input of `I` from `source`, failing with
+/// `STATUS_BUFFER_TOO_SMALL` if it is shorter than `I::Input`. Requests
+/// without input read nothing.
+pub fn read_input_from<I: Ioctl>(
+    source: &(impl InputSource + ?Sized),
+) -> Result<I::Input, NTSTATUS> {
+    const { check::<I>() };
+    if size_of::<I::Input>() == 0 {
+        // SAFETY: Any bit pattern is a valid `Pod`, including no bits at all.
+        return Ok(unsafe { core::mem::zeroed() });
+    }
+    source.read::<I::Input>()
+}
+
+/// Reads the input of `I` from the input buffer of a request, like
+/// [`read_input_from`].
+///
+/// # Safety
+///
+/// `input` must be null or valid for reads of `input_len` bytes. For
+/// METHOD_NEITHER requests with user pointers, it must have been probed.
+pub unsafe fn read_input<I: Ioctl>(
+    input: *const c_void,
+    input_len: u32,
+) -> Result<I::Input, NTSTATUS> {
+    // SAFETY: Guaranteed by the caller.
+    read_input_from::<I>(&unsafe { RawInput::new(input, input_len) })
+}
+
+/// Checks that an output buffer of `output_len` bytes can hold an
+/// `I::Output`, failing with `STATUS_BUFFER_TOO_SMALL` otherwise.
+pub const fn check_output<I: Ioctl>(output_len: u32) -> Result<(), NTSTATUS> {
+    const { check::<I>() };
+    if (output_len as usize) < size_of::<I::Output>() {
+        return Err(STATUS_BUFFER_TOO_SMALL);
+    }
+    Ok(())
+}
+
+/// Writes the output of `I` to the output buffer of a request and returns
+/// the `Information` to complete the request with.
+///
+/// # Safety
+///
+/// `output` must be null or valid for writes of `output_len` bytes.
+pub unsafe fn write_output<I: Ioctl>(
+    output: *mut c_void,
+    output_len: u32,
+    value: I::Output,
+) -> Result<usize, NTSTATUS> {
+    check_output::<I>(output_len)?;
+    if size_of::<I::Output>() == 0 {
+        return Ok(0);
+    }
+    if output.is_null() {
+        return Err(STATUS_INVALID_PARAMETER);
+    }
+    // SAFETY: The caller guarantees `output_len` writable bytes, which cover
+    // an `I::Output`.
+    unsafe { output.cast::<I::Output>().write_unaligned(value) };
+    Ok(size_of::<I::Output>())
+}
@@ -1,3 +1,14 @@
 #![no_std]
 
-pub mod macros;
\ No newline at end of file
+pub mod guid;
+pub mod ioctl;
+pub mod irp;
+pub mod macros;
+pub mod pod;
+pub mod pool_tag;
+pub mod sddl;
+pub mod status;
+mod status_table;
+#[cfg(feature = "user")]
+pub mod user;
+pub mod wire;
\ No newline at end of file
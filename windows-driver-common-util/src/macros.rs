@@ -1,9 +1,267 @@
+/// The `u32` value of `CTL_CODE(device_type, function, method, access)`.
+///
+/// Fields out of range fail to compile in const contexts; see
+/// [`IoctlCode::new`](crate::ioctl::IoctlCode::new).
 #[macro_export]
 macro_rules! ctl_code {
     ($device_type:expr, $function:expr, $method:expr, $access:expr) => {
-        ((($device_type & 0xffff) as u32) << 16)
-            | ((($access & 0x3) as u32) << 14)
-            | ((($function & 0xfff) as u32) << 2)
-            | (($method & 0x3) as u32)
+        $crate::ioctl::IoctlCode::new(
+            $device_type as u32,
+            $function as u32,
+            $method as u32,
+            $access as u32,
+        )
+        .as_u32()
     };
-}
\ No newline at end of file
+}
+
+/// A [`GUID`](crate::guid::GUID) literal, e.g.
+/// `guid!("b2723ad5-1678-446d-a577-8599d3e85ecb")`, optionally enclosed in
+/// braces. Malformed input fails to compile.
+///
+/// For this crate's [`Guid`](crate::guid::Guid), use
+/// `Guid::parse_or_panic` in a constant instead.
+#[macro_export]
+macro_rules! guid {
+    ($s:expr) => {{
+        const GUID: $crate::guid::GUID = $crate::guid::Guid::parse_or_panic($s).to_sys();
+        GUID
+    }};
+}
+
+/// A [`SecurityDescriptor`](crate::sddl::SecurityDescriptor) compiled from
+/// SDDL at compile time, e.g. `sddl!("D:P(A;;GA;;;SY)(A;;GA;;;BA)")`.
+/// Malformed SDDL fails to compile.
+///
+/// An optional second argument gives the
+/// [`GenericMapping`](crate::sddl::GenericMapping) for generic rights, e.g.
+/// `sddl!("D:P(A;;GA;;;SY)", GenericMapping::FILE)`.
+#[macro_export]
+macro_rules! sddl {
+    ($s:expr $(,)?) => {
+        $crate::__sddl!($s, None)
+    };
+    ($s:expr, $mapping:expr $(,)?) => {
+        $crate::__sddl!($s, Some(&$mapping))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sddl {
+    ($s:expr, $mapping:expr) => {{
+        const LEN: usize = $crate::sddl::compiled_len($s, $mapping);
+        const DESCRIPTOR: &$crate::sddl::AlignedDescriptor<LEN> =
+            &$crate::sddl::compile($s, $mapping);
+        DESCRIPTOR.descriptor()
+    }};
+}
+
+/// A [`PoolTag`](crate::pool_tag::PoolTag) from its four characters in
+/// display order, e.g. `pool_tag!("Samp")`. Anything but four printable
+/// ASCII characters fails to compile.
+#[macro_export]
+macro_rules! pool_tag {
+    ($tag:expr) => {{
+        const TAG: $crate::pool_tag::PoolTag = $crate::pool_tag::PoolTag::new($tag);
+        TAG
+    }};
+}
+
+/// Declares the device control requests of a driver protocol, e.g.
+///
+/// ```
+/// # use windows_driver_common_util::define_ioctls;
+/// # use windows_driver_common_util::ioctl::{FILE_ANY_ACCESS, METHOD_BUFFERED, METHOD_NEITHER};
+/// # use windows_driver_common_util::wire::WireStruct;
+/// # #[derive(WireStruct, Default, Clone, Copy)] #[repr(C)] pub struct ZeroStats { pub total_read: u64 }
+/// define_ioctls! {
+///     device_type = 0x8022;
+///     /// Requests understood by the Zero device.
+///     pub enum ZeroRequest {
+///         /// Returns the read and write counters.
+///         IOCTL_ZERO_GET_STATS = (0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)
+///             => GetStats -> ZeroStats, fn get_stats;
+///         /// Resets the counters.
+///         IOCTL_ZERO_CLEAR_STATS = (0x801, METHOD_NEITHER, FILE_ANY_ACCESS)
+///             => ClearStats, fn clear_stats, user_pointers = false;
+///     }
+/// }
+/// ```
+///
+/// Each request gets a `u32` code constant, a unit struct implementing
+/// [`Ioctl`](crate::ioctl::Ioctl), e.g. `GetStats`, and a variant of the
+/// request enum, which carries the input if the request takes one, e.g.
+/// `SetPriority(ThreadData)`. Requests without `-> Output` return nothing.
+/// METHOD_NEITHER requests must declare `user_pointers`.
+///
+/// The driver decodes requests with `ZeroRequest::decode_from`, which checks
+/// the buffer sizes against the declared types, and `ZeroRequest::IOCTLS`
+/// describes them to tools such as `ioctl-check`. With the `user` feature, `fn`
+/// names a helper that sends the request, e.g.
+/// `get_stats(device: HANDLE) -> windows::core::Result<ZeroStats>`.
+#[macro_export]
+macro_rules! define_ioctls {
+    (
+        device_type = $device_type:expr;
+        $(#[$enum_meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$meta:meta])*
+                $code:ident = ($function:expr, $method:expr, $access:expr)
+                    => $variant:ident $(($input:ty))? $(-> $output:ty)?, fn $helper:ident
+                    $(, user_pointers = $user_pointers:expr)?;
+            )*
+        }
+    ) => {
+        $(
+            $(#[$meta])*
+            $vis const $code: u32 = $crate::ctl_code!($device_type, $function, $method, $access);
+
+            $(#[$meta])*
+            $vis struct $variant;
+
+            impl $crate::ioctl::Ioctl for $variant {
+                const CODE: $crate::ioctl::IoctlCode = $crate::ioctl::IoctlCode::from_raw($code);
+                type Input = $crate::__ioctl_type!($($input)?);
+                type Output = $crate::__ioctl_type!($($output)?);
+                const USER_POINTERS: ::core::option::Option<bool> = $crate::__ioctl_option!($($user_pointers)?);
+            }
+
+            const _: () = $crate::ioctl::check::<$variant>();
+        )*
+
+        $(#[$enum_meta])*
+        $vis enum $name {
+            $(
+                $(#[$meta])*
+                $variant $(($input))?,
+            )*
+        }
+
+        impl $name {
+            /// Metadata of the requests, in declaration order.
+            $vis const IOCTLS: &'static [$crate::ioctl::IoctlInfo] = &[
+                $($crate::ioctl::IoctlInfo::of::<$variant>(::core::stringify!($code)),)*
+            ];
+
+            /// Decodes a device control request, checking that the input and
+            /// output buffers are large enough for the declared types. Only
+            /// the declared input is read from `input`, after the code is
+            /// known, and nothing for requests without input.
+            ///
+            /// Fails with `STATUS_INVALID_DEVICE_REQUEST` for unknown codes
+            /// and `STATUS_BUFFER_TOO_SMALL` for short buffers.
+            $vis fn decode_from(
+                code: u32,
+                input: &(impl $crate::ioctl::InputSource + ?::core::marker::Sized),
+                output_len: u32,
+            ) -> ::core::result::Result<Self, $crate::status::NTSTATUS> {
+                match code {
+                    $(
+                        $code => {
+                            $crate::ioctl::check_output::<$variant>(output_len)?;
+                            let input = $crate::ioctl::read_input_from::<$variant>(input)?;
+                            let _ = input;
+                            ::core::result::Result::Ok(Self::$variant $(({
+                                let input: $input = input;
+                                input
+                            }))?)
+                        }
+                    )*
+                    _ => ::core::result::Result::Err($crate::status::STATUS_INVALID_DEVICE_REQUEST),
+                }
+            }
+
+            /// Decodes a device control request like `decode_from`, with the
+            /// input buffer given as pointer and length.
+            ///
+            /// # Safety
+            ///
+            /// `input` must be null or valid for reads of `input_len` bytes.
+            /// For METHOD_NEITHER requests with user pointers, it must have
+            /// been probed.
+            $vis unsafe fn decode(
+                code: u32,
+                input: *const ::core::ffi::c_void,
+                input_len: u32,
+                output_len: u32,
+            ) -> ::core::result::Result<Self, $crate::status::NTSTATUS> {
+                // SAFETY: Guaranteed by the caller.
+                let input = unsafe { $crate::ioctl::RawInput::new(input, input_len) };
+                Self::decode_from(code, &input, output_len)
+            }
+
+            /// The code of the request.
+            $vis const fn code(&self) -> u32 {
+                match self {
+                    $(Self::$variant { .. } => $code,)*
+                }
+            }
+
+            /// Size of the request's output, the `Information` to complete a
+            /// successful request with.
+            $vis const fn output_size(&self) -> usize {
+                match self {
+                    $(Self::$variant { .. } => {
+                        ::core::mem::size_of::<<$variant as $crate::ioctl::Ioctl>::Output>()
+                    })*
+                }
+            }
+        }
+
+        $(
+            $crate::__ioctl_user_helper!(
+                $(#[$meta])*
+                $vis fn $helper($variant, $($input)?) -> $crate::__ioctl_type!($($output)?)
+            );
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ioctl_type {
+    () => {
+        ()
+    };
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ioctl_option {
+    () => {
+        ::core::option::Option::None
+    };
+    ($value:expr) => {
+        ::core::option::Option::Some($value)
+    };
+}
+
+#[cfg(feature = "user")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ioctl_user_helper {
+    ($(#[$meta:meta])* $vis:vis fn $helper:ident($ioctl:ident, ) -> $output:ty) => {
+        $(#[$meta])*
+        $vis fn $helper(device: $crate::user::HANDLE) -> $crate::user::Result<$output> {
+            $crate::user::device_io_control::<$ioctl>(device, &())
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis fn $helper:ident($ioctl:ident, $input:ty) -> $output:ty) => {
+        $(#[$meta])*
+        $vis fn $helper(device: $crate::user::HANDLE, input: &$input) -> $crate::user::Result<$output> {
+            $crate::user::device_io_control::<$ioctl>(device, input)
+        }
+    };
+}
+
+#[cfg(not(feature = "user"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ioctl_user_helper {
+    ($($tokens:tt)*) => {};
+}
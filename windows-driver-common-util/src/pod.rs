@@ -0,0 +1,44 @@
+//! Plain old data: types that can be copied to and from the buffers of a
+//! request byte by byte.
+
+/// A type whose bytes can be exchanged with another address space as is.
+///
+/// Use `#[derive(WireStruct)]` from [`wire`](crate::wire) to declare
+/// structs, which checks the requirements at compile time.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` or a primitive, must not have padding,
+/// and every bit pattern, including all zeros, must be a valid value. In
+/// particular, it must not contain pointers, references, `bool`, `char` or
+/// fields whose size differs between 32-bit and 64-bit processes, such as
+/// `usize`.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: Primitives without padding or invalid bit patterns.
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(
+    (),
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    f32,
+    f64
+);
+
+// SAFETY: Arrays have the layout of their elements repeated, without padding.
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
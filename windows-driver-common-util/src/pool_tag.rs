@@ -0,0 +1,81 @@
+//! Pool tags, the four characters identifying a driver's allocations in
+//! PoolMon, WinDbg's `!poolused` and Driver Verifier.
+//!
+//! The tag is stored as a `u32` whose little-endian bytes are the characters
+//! in display order, so `pool_tag!("Samp")` shows up as `Samp`. C code writes
+//! the same tag as the multi-character constant `'pmaS'`.
+//!
+//! Crates list the tags they allocate with, and what for, in a
+//! `pub const POOL_TAGS: &[(PoolTag, &str)]`, from which `pooltag-export`
+//! writes the `pooltag.txt` entries.
+
+use core::fmt;
+
+/// A pool tag, e.g. `pool_tag!("Samp")`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PoolTag(u32);
+
+impl PoolTag {
+    /// Creates a tag from its four characters in display order.
+    ///
+    /// Panics, which fails compilation in const contexts, unless `tag`
+    /// consists of exactly four printable ASCII characters.
+    pub const fn new(tag: &str) -> Self {
+        let bytes = tag.as_bytes();
+        if bytes.len() != 4 {
+            panic!("pool tag must have exactly four characters");
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            if !matches!(bytes[i], b' '..=b'~') {
+                panic!("pool tag must consist of printable ASCII characters");
+            }
+            i += 1;
+        }
+        Self(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Wraps the value passed to or returned by the pool APIs.
+    pub const fn from_u32(tag: u32) -> Self {
+        Self(tag)
+    }
+
+    /// The value to pass to `ExAllocatePool2` and friends.
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// The characters in display order.
+    pub const fn to_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl From<PoolTag> for u32 {
+    fn from(tag: PoolTag) -> Self {
+        tag.as_u32()
+    }
+}
+
+/// Writes the characters in PoolMon order, with bytes that are not printable
+/// ASCII (possible for tags from [`PoolTag::from_u32`]) shown as `.`.
+impl fmt::Display for PoolTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        for byte in self.to_bytes() {
+            let c = if matches!(byte, b' '..=b'~') {
+                byte as char
+            } else {
+                '.'
+            };
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PoolTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PoolTag(\"{self}\")")
+    }
+}
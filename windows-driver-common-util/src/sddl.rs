@@ -0,0 +1,1006 @@
+//! A compiler from SDDL strings, such as the `D:P(A;;GA;;;SY)(A;;GA;;;BA)` of
+//! an INF `Security` entry, to binary self-relative security descriptors, and
+//! a decoder back to SDDL.
+//!
+//! The compiler is a `const fn`, so [`sddl!`](crate::sddl) embeds the
+//! descriptor in the driver image and malformed strings fail to compile. It
+//! supports the subset of SDDL that is meaningful for device objects:
+//!
+//! * owner (`O:`), group (`G:`), DACL (`D:`) and SACL (`S:`) components, in
+//!   any order;
+//! * the ACL flags `P`, `AI`, `AR` and, for DACLs, `NO_ACCESS_CONTROL`;
+//! * access allowed (`A`), access denied (`D`), audit (`AU`) and mandatory
+//!   label (`ML`) ACEs, without object GUIDs;
+//! * the ACE flags `OI`, `CI`, `NP`, `IO`, `ID`, `SA` and `FA`;
+//! * rights as two-letter codes (`GA`, `FR`, `RC`, ...) or as a number;
+//! * SIDs as `S-1-...` strings or machine-independent aliases (`SY`, `BA`,
+//!   `WD`, ...). Domain-relative aliases such as `DA` cannot be resolved
+//!   without a domain and are rejected.
+//!
+//! Generic rights are kept in the descriptor, as by
+//! `ConvertStringSecurityDescriptorToSecurityDescriptor`, unless a
+//! [`GenericMapping`] is given, as `IoCreateDeviceSecure` does with
+//! [`GenericMapping::FILE`].
+
+use core::ffi::c_void;
+use core::fmt;
+
+/// Errors returned when compiling SDDL or decoding a security descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SddlError {
+    /// A component does not start with `O:`, `G:`, `D:` or `S:`.
+    InvalidComponent,
+    /// A component appears twice.
+    DuplicateComponent,
+    /// An unknown ACL flag.
+    InvalidAclFlags,
+    /// An ACE is not of the form `(type;flags;rights;;;sid)`, or parentheses
+    /// are unbalanced.
+    InvalidAce,
+    /// An ACE type that is unknown, not allowed in its ACL, or has object
+    /// GUIDs.
+    UnsupportedAceType,
+    /// An unknown ACE flag.
+    InvalidAceFlags,
+    /// An unknown right or malformed number.
+    InvalidRights,
+    /// A malformed SID or unknown alias.
+    InvalidSid,
+    /// A domain-relative SID alias.
+    UnsupportedSid,
+    /// `NO_ACCESS_CONTROL` combined with ACEs.
+    NullAclWithAces,
+    /// An ACL exceeds the 64 KiB limit of its size field.
+    TooLarge,
+    /// The output buffer is too small for the descriptor.
+    BufferTooSmall,
+    /// Binary data is not a valid self-relative security descriptor.
+    InvalidDescriptor,
+}
+
+impl SddlError {
+    const fn message(self) -> &'static str {
+        match self {
+            SddlError::InvalidComponent => "SDDL component must start with O:, G:, D: or S:",
+            SddlError::DuplicateComponent => "SDDL component appears more than once",
+            SddlError::InvalidAclFlags => "SDDL ACL has an unknown flag",
+            SddlError::InvalidAce => "SDDL ACE must have the form (type;flags;rights;;;sid)",
+            SddlError::UnsupportedAceType => "SDDL ACE type is not supported in this ACL",
+            SddlError::InvalidAceFlags => "SDDL ACE has an unknown flag",
+            SddlError::InvalidRights => "SDDL ACE has unknown or malformed rights",
+            SddlError::InvalidSid => "SDDL SID is malformed or an unknown alias",
+            SddlError::UnsupportedSid => "SDDL SID alias is domain-relative",
+            SddlError::NullAclWithAces => "SDDL NO_ACCESS_CONTROL cannot have ACEs",
+            SddlError::TooLarge => "SDDL ACL exceeds 64 KiB",
+            SddlError::BufferTooSmall => "buffer is too small for the security descriptor",
+            SddlError::InvalidDescriptor => "invalid self-relative security descriptor",
+        }
+    }
+}
+
+impl fmt::Display for SddlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for SddlError {}
+
+/// The specific rights that generic rights stand for, like `GENERIC_MAPPING`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenericMapping {
+    pub read: u32,
+    pub write: u32,
+    pub execute: u32,
+    pub all: u32,
+}
+
+impl GenericMapping {
+    /// The mapping of file and device objects.
+    pub const FILE: GenericMapping = GenericMapping {
+        read: FILE_GENERIC_READ,
+        write: FILE_GENERIC_WRITE,
+        execute: FILE_GENERIC_EXECUTE,
+        all: FILE_ALL_ACCESS,
+    };
+
+    /// Replaces the generic rights in `mask` with the specific rights they
+    /// stand for, like `RtlMapGenericMask`.
+    pub const fn map(&self, mask: u32) -> u32 {
+        let mut mapped = mask & !(GENERIC_READ | GENERIC_WRITE | GENERIC_EXECUTE | GENERIC_ALL);
+        if mask & GENERIC_READ != 0 {
+            mapped |= self.read;
+        }
+        if mask & GENERIC_WRITE != 0 {
+            mapped |= self.write;
+        }
+        if mask & GENERIC_EXECUTE != 0 {
+            mapped |= self.execute;
+        }
+        if mask & GENERIC_ALL != 0 {
+            mapped |= self.all;
+        }
+        mapped
+    }
+}
+
+pub const GENERIC_READ: u32 = 0x8000_0000;
+pub const GENERIC_WRITE: u32 = 0x4000_0000;
+pub const GENERIC_EXECUTE: u32 = 0x2000_0000;
+pub const GENERIC_ALL: u32 = 0x1000_0000;
+pub const FILE_GENERIC_READ: u32 = 0x0012_0089;
+pub const FILE_GENERIC_WRITE: u32 = 0x0012_0116;
+pub const FILE_GENERIC_EXECUTE: u32 = 0x0012_00a0;
+pub const FILE_ALL_ACCESS: u32 = 0x001f_01ff;
+
+const SECURITY_DESCRIPTOR_REVISION: u8 = 1;
+const ACL_REVISION: u8 = 2;
+const HEADER_LEN: usize = 20;
+const ACL_HEADER_LEN: usize = 8;
+const ACE_HEADER_LEN: usize = 8;
+const SID_MAX_SUB_AUTHORITIES: usize = 15;
+
+const SE_DACL_PRESENT: u16 = 0x0004;
+const SE_SACL_PRESENT: u16 = 0x0010;
+const SE_DACL_AUTO_INHERIT_REQ: u16 = 0x0100;
+const SE_SACL_AUTO_INHERIT_REQ: u16 = 0x0200;
+const SE_DACL_AUTO_INHERITED: u16 = 0x0400;
+const SE_SACL_AUTO_INHERITED: u16 = 0x0800;
+const SE_DACL_PROTECTED: u16 = 0x1000;
+const SE_SACL_PROTECTED: u16 = 0x2000;
+const SE_SELF_RELATIVE: u16 = 0x8000;
+
+const ACCESS_ALLOWED_ACE_TYPE: u8 = 0x0;
+const ACCESS_DENIED_ACE_TYPE: u8 = 0x1;
+const SYSTEM_AUDIT_ACE_TYPE: u8 = 0x2;
+const SYSTEM_MANDATORY_LABEL_ACE_TYPE: u8 = 0x11;
+
+/// ACE types by SDDL code, and whether they belong in a SACL.
+const ACE_TYPES: [(&str, u8, bool); 4] = [
+    ("A", ACCESS_ALLOWED_ACE_TYPE, false),
+    ("D", ACCESS_DENIED_ACE_TYPE, false),
+    ("AU", SYSTEM_AUDIT_ACE_TYPE, true),
+    ("ML", SYSTEM_MANDATORY_LABEL_ACE_TYPE, true),
+];
+
+const ACE_FLAGS: [(&str, u8); 7] = [
+    ("OI", 0x01),
+    ("CI", 0x02),
+    ("NP", 0x04),
+    ("IO", 0x08),
+    ("ID", 0x10),
+    ("SA", 0x40),
+    ("FA", 0x80),
+];
+
+/// Rights that stand for several bits, preferred when decoding an exact match.
+const COMPOSITE_RIGHTS: [(&str, u32); 8] = [
+    ("FA", FILE_ALL_ACCESS),
+    ("FR", FILE_GENERIC_READ),
+    ("FW", FILE_GENERIC_WRITE),
+    ("FX", FILE_GENERIC_EXECUTE),
+    ("KA", 0x000f_003f),
+    ("KR", 0x0002_0019),
+    ("KW", 0x0002_0006),
+    ("KX", 0x0002_0019),
+];
+
+/// Rights that stand for a single bit, in the order they are decoded.
+const BIT_RIGHTS: [(&str, u32); 17] = [
+    ("GA", GENERIC_ALL),
+    ("GR", GENERIC_READ),
+    ("GW", GENERIC_WRITE),
+    ("GX", GENERIC_EXECUTE),
+    ("RC", 0x0002_0000),
+    ("SD", 0x0001_0000),
+    ("WD", 0x0004_0000),
+    ("WO", 0x0008_0000),
+    ("CC", 0x0000_0001),
+    ("DC", 0x0000_0002),
+    ("LC", 0x0000_0004),
+    ("SW", 0x0000_0008),
+    ("RP", 0x0000_0010),
+    ("WP", 0x0000_0020),
+    ("DT", 0x0000_0040),
+    ("LO", 0x0000_0080),
+    ("CR", 0x0000_0100),
+];
+
+/// Mandatory label rights, used for `ML` ACEs instead of [`BIT_RIGHTS`].
+const LABEL_RIGHTS: [(&str, u32); 3] = [("NR", 0x1), ("NW", 0x2), ("NX", 0x4)];
+
+/// SID aliases whose SIDs are the same on every machine.
+const SID_ALIASES: [(&str, u64, &[u32]); 24] = [
+    ("WD", 1, &[0]),
+    ("CO", 3, &[0]),
+    ("CG", 3, &[1]),
+    ("NU", 5, &[2]),
+    ("IU", 5, &[4]),
+    ("SU", 5, &[6]),
+    ("AN", 5, &[7]),
+    ("AU", 5, &[11]),
+    ("RC", 5, &[12]),
+    ("SY", 5, &[18]),
+    ("LS", 5, &[19]),
+    ("NS", 5, &[20]),
+    ("BA", 5, &[32, 544]),
+    ("BU", 5, &[32, 545]),
+    ("BG", 5, &[32, 546]),
+    ("PU", 5, &[32, 547]),
+    ("AO", 5, &[32, 548]),
+    ("SO", 5, &[32, 549]),
+    ("BO", 5, &[32, 551]),
+    ("AC", 15, &[2, 1]),
+    ("LW", 16, &[4096]),
+    ("ME", 16, &[8192]),
+    ("HI", 16, &[12288]),
+    ("SI", 16, &[16384]),
+];
+
+/// Aliases that resolve relative to the machine's domain.
+const DOMAIN_ALIASES: [&str; 11] = [
+    "DA", "DG", "DU", "DC", "DD", "CA", "SA", "EA", "PA", "RS", "LA",
+];
+
+macro_rules! tri {
+    ($e:expr) => {
+        match $e {
+            Ok(value) => value,
+            Err(error) => return Err(error),
+        }
+    };
+}
+
+/// Compiles `sddl` into a self-relative security descriptor in `buffer`,
+/// returning its length. Generic rights are mapped if `mapping` is given.
+///
+/// The components are laid out as SACL, DACL, owner and group, like
+/// `RtlMakeSelfRelativeSD`. `buffer` should be 4-byte aligned before passing
+/// the result to the kernel.
+pub const fn compile_into(
+    sddl: &str,
+    mapping: Option<&GenericMapping>,
+    buffer: &mut [u8],
+) -> Result<usize, SddlError> {
+    let components = tri!(split_components(sddl.as_bytes()));
+    let mut writer = Writer { buffer, pos: 0 };
+    tri!(writer.zeroes(HEADER_LEN));
+
+    let mut control = SE_SELF_RELATIVE;
+    let mut offsets = [0u32; 4];
+    if let Some(sacl) = components[SACL] {
+        let (flags, offset) = tri!(writer.acl(sacl, true, mapping));
+        control |= SE_SACL_PRESENT | flags;
+        offsets[SACL] = offset;
+    }
+    if let Some(dacl) = components[DACL] {
+        let (flags, offset) = tri!(writer.acl(dacl, false, mapping));
+        control |= SE_DACL_PRESENT | flags;
+        offsets[DACL] = offset;
+    }
+    if let Some(owner) = components[OWNER] {
+        offsets[OWNER] = writer.pos as u32;
+        tri!(writer.sid(owner));
+    }
+    if let Some(group) = components[GROUP] {
+        offsets[GROUP] = writer.pos as u32;
+        tri!(writer.sid(group));
+    }
+
+    let len = writer.pos;
+    writer.pos = 0;
+    tri!(writer.bytes(&[SECURITY_DESCRIPTOR_REVISION, 0]));
+    tri!(writer.u16(control));
+    tri!(writer.u32(offsets[OWNER]));
+    tri!(writer.u32(offsets[GROUP]));
+    tri!(writer.u32(offsets[SACL]));
+    tri!(writer.u32(offsets[DACL]));
+    Ok(len)
+}
+
+/// Scratch space for [`compiled_len`]; descriptors given as literals are far
+/// smaller.
+const MAX_LITERAL_LEN: usize = 4096;
+
+/// The length of the descriptor compiled from `sddl`. Panics on malformed
+/// input, which fails compilation in const contexts. Used by
+/// [`sddl!`](crate::sddl).
+#[doc(hidden)]
+pub const fn compiled_len(sddl: &str, mapping: Option<&GenericMapping>) -> usize {
+    match compile_into(sddl, mapping, &mut [0; MAX_LITERAL_LEN]) {
+        Ok(len) => len,
+        Err(error) => panic!("{}", error.message()),
+    }
+}
+
+/// Compiles `sddl` into `N` bytes, panicking on malformed input. Used by
+/// [`sddl!`](crate::sddl).
+#[doc(hidden)]
+pub const fn compile<const N: usize>(
+    sddl: &str,
+    mapping: Option<&GenericMapping>,
+) -> AlignedDescriptor<N> {
+    let mut descriptor = AlignedDescriptor([0; N]);
+    match compile_into(sddl, mapping, &mut descriptor.0) {
+        Ok(_) => descriptor,
+        Err(error) => panic!("{}", error.message()),
+    }
+}
+
+/// Storage for a compiled descriptor with the alignment the kernel expects.
+#[doc(hidden)]
+#[repr(C, align(4))]
+pub struct AlignedDescriptor<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> AlignedDescriptor<N> {
+    pub const fn descriptor(&'static self) -> SecurityDescriptor<'static> {
+        SecurityDescriptor { bytes: &self.0 }
+    }
+}
+
+const OWNER: usize = 0;
+const GROUP: usize = 1;
+const DACL: usize = 2;
+const SACL: usize = 3;
+
+/// Splits SDDL into the values of its owner, group, DACL and SACL components.
+const fn split_components(s: &[u8]) -> Result<[Option<&[u8]>; 4], SddlError> {
+    let mut components: [Option<&[u8]>; 4] = [None; 4];
+    let mut pos = 0;
+    while pos < s.len() {
+        let kind = match component_at(s, pos) {
+            Some(kind) => kind,
+            None => return Err(SddlError::InvalidComponent),
+        };
+        if components[kind].is_some() {
+            return Err(SddlError::DuplicateComponent);
+        }
+
+        let start = pos + 2;
+        let mut end = start;
+        let mut depth = 0;
+        while end < s.len() {
+            match s[end] {
+                b'(' => depth += 1,
+                b')' => {
+                    if depth == 0 {
+                        return Err(SddlError::InvalidAce);
+                    }
+                    depth -= 1;
+                }
+                _ => {
+                    if depth == 0 && component_at(s, end).is_some() {
+                        break;
+                    }
+                }
+            }
+            end += 1;
+        }
+        if depth != 0 {
+            return Err(SddlError::InvalidAce);
+        }
+        components[kind] = Some(slice(s, start, end));
+        pos = end;
+    }
+    Ok(components)
+}
+
+const fn component_at(s: &[u8], pos: usize) -> Option<usize> {
+    if pos + 1 >= s.len() || s[pos + 1] != b':' {
+        return None;
+    }
+    match s[pos] {
+        b'O' => Some(OWNER),
+        b'G' => Some(GROUP),
+        b'D' => Some(DACL),
+        b'S' => Some(SACL),
+        _ => None,
+    }
+}
+
+struct Writer<'b> {
+    buffer: &'b mut [u8],
+    pos: usize,
+}
+
+impl Writer<'_> {
+    const fn bytes(&mut self, bytes: &[u8]) -> Result<(), SddlError> {
+        if self.buffer.len() - self.pos < bytes.len() {
+            return Err(SddlError::BufferTooSmall);
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buffer[self.pos] = bytes[i];
+            self.pos += 1;
+            i += 1;
+        }
+        Ok(())
+    }
+
+    const fn zeroes(&mut self, len: usize) -> Result<(), SddlError> {
+        if self.buffer.len() - self.pos < len {
+            return Err(SddlError::BufferTooSmall);
+        }
+        let mut i = 0;
+        while i < len {
+            self.buffer[self.pos] = 0;
+            self.pos += 1;
+            i += 1;
+        }
+        Ok(())
+    }
+
+    const fn u16(&mut self, value: u16) -> Result<(), SddlError> {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    const fn u32(&mut self, value: u32) -> Result<(), SddlError> {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    const fn u16_at(&mut self, pos: usize, value: u16) {
+        let bytes = value.to_le_bytes();
+        self.buffer[pos] = bytes[0];
+        self.buffer[pos + 1] = bytes[1];
+    }
+
+    /// Writes an ACL, returning its control flags and offset (zero for a null
+    /// ACL).
+    const fn acl(
+        &mut self,
+        s: &[u8],
+        is_sacl: bool,
+        mapping: Option<&GenericMapping>,
+    ) -> Result<(u16, u32), SddlError> {
+        let mut flags = 0;
+        let mut null_acl = false;
+        let mut pos = 0;
+        while pos < s.len() && s[pos] != b'(' {
+            let rest = slice(s, pos, s.len());
+            if starts_with(rest, b"NO_ACCESS_CONTROL") && !is_sacl {
+                null_acl = true;
+                pos += b"NO_ACCESS_CONTROL".len();
+            } else if starts_with(rest, b"P") {
+                flags |= if is_sacl { SE_SACL_PROTECTED } else { SE_DACL_PROTECTED };
+                pos += 1;
+            } else if starts_with(rest, b"AI") {
+                flags |= if is_sacl { SE_SACL_AUTO_INHERITED } else { SE_DACL_AUTO_INHERITED };
+                pos += 2;
+            } else if starts_with(rest, b"AR") {
+                flags |= if is_sacl { SE_SACL_AUTO_INHERIT_REQ } else { SE_DACL_AUTO_INHERIT_REQ };
+                pos += 2;
+            } else {
+                return Err(SddlError::InvalidAclFlags);
+            }
+        }
+        if null_acl {
+            if pos != s.len() {
+                return Err(SddlError::NullAclWithAces);
+            }
+            return Ok((flags, 0));
+        }
+
+        let start = self.pos;
+        tri!(self.zeroes(ACL_HEADER_LEN));
+        let mut count: u16 = 0;
+        while pos < s.len() {
+            if s[pos] != b'(' {
+                return Err(SddlError::InvalidAce);
+            }
+            let mut end = pos + 1;
+            while end < s.len() && s[end] != b')' {
+                end += 1;
+            }
+            if end == s.len() {
+                return Err(SddlError::InvalidAce);
+            }
+            tri!(self.ace(slice(s, pos + 1, end), is_sacl, mapping));
+            count += 1;
+            pos = end + 1;
+        }
+
+        let size = self.pos - start;
+        if size > u16::MAX as usize {
+            return Err(SddlError::TooLarge);
+        }
+        self.buffer[start] = ACL_REVISION;
+        self.u16_at(start + 2, size as u16);
+        self.u16_at(start + 4, count);
+        Ok((flags, start as u32))
+    }
+
+    const fn ace(
+        &mut self,
+        s: &[u8],
+        is_sacl: bool,
+        mapping: Option<&GenericMapping>,
+    ) -> Result<(), SddlError> {
+        let mut fields: [&[u8]; 6] = [&[]; 6];
+        let mut field = 0;
+        let mut start = 0;
+        let mut pos = 0;
+        while pos <= s.len() {
+            if pos == s.len() || s[pos] == b';' {
+                if field == fields.len() {
+                    return Err(SddlError::InvalidAce);
+                }
+                fields[field] = slice(s, start, pos);
+                field += 1;
+                start = pos + 1;
+            }
+            pos += 1;
+        }
+        if field != fields.len() {
+            return Err(SddlError::InvalidAce);
+        }
+        if !fields[3].is_empty() || !fields[4].is_empty() {
+            return Err(SddlError::UnsupportedAceType);
+        }
+
+        let ace_type = tri!(parse_ace_type(fields[0], is_sacl));
+        let ace_flags = tri!(parse_ace_flags(fields[1]));
+        let label = ace_type == SYSTEM_MANDATORY_LABEL_ACE_TYPE;
+        let mut mask = tri!(parse_rights(fields[2], label));
+        if let Some(mapping) = mapping
+            && !label
+        {
+            mask = mapping.map(mask);
+        }
+
+        let start = self.pos;
+        tri!(self.bytes(&[ace_type, ace_flags, 0, 0]));
+        tri!(self.u32(mask));
+        tri!(self.sid(fields[5]));
+        let size = self.pos - start;
+        if size > u16::MAX as usize {
+            return Err(SddlError::TooLarge);
+        }
+        self.u16_at(start + 2, size as u16);
+        Ok(())
+    }
+
+    const fn sid(&mut self, s: &[u8]) -> Result<(), SddlError> {
+        let mut alias = 0;
+        while alias < SID_ALIASES.len() {
+            let (name, authority, sub_authorities) = SID_ALIASES[alias];
+            if eq(s, name.as_bytes()) {
+                return self.sid_parts(authority, sub_authorities);
+            }
+            alias += 1;
+        }
+        let mut alias = 0;
+        while alias < DOMAIN_ALIASES.len() {
+            if eq(s, DOMAIN_ALIASES[alias].as_bytes()) {
+                return Err(SddlError::UnsupportedSid);
+            }
+            alias += 1;
+        }
+
+        // S-1-<authority>-<sub authority>...
+        let mut parts = [0u64; SID_MAX_SUB_AUTHORITIES + 2];
+        let mut count = 0;
+        if !starts_with(s, b"S-") {
+            return Err(SddlError::InvalidSid);
+        }
+        let mut pos = 2;
+        loop {
+            let mut end = pos;
+            while end < s.len() && s[end] != b'-' {
+                end += 1;
+            }
+            if count == parts.len() {
+                return Err(SddlError::InvalidSid);
+            }
+            parts[count] = match parse_number(slice(s, pos, end)) {
+                Some(value) => value,
+                None => return Err(SddlError::InvalidSid),
+            };
+            count += 1;
+            if end == s.len() {
+                break;
+            }
+            pos = end + 1;
+        }
+        if count < 3 || parts[0] != 1 || parts[1] >= 1 << 48 {
+            return Err(SddlError::InvalidSid);
+        }
+
+        let mut sub_authorities = [0u32; SID_MAX_SUB_AUTHORITIES];
+        let mut i = 2;
+        while i < count {
+            if parts[i] > u32::MAX as u64 {
+                return Err(SddlError::InvalidSid);
+            }
+            sub_authorities[i - 2] = parts[i] as u32;
+            i += 1;
+        }
+        let (sub_authorities, _) = sub_authorities.split_at(count - 2);
+        self.sid_parts(parts[1], sub_authorities)
+    }
+
+    const fn sid_parts(&mut self, authority: u64, sub_authorities: &[u32]) -> Result<(), SddlError> {
+        let authority = authority.to_be_bytes();
+        tri!(self.bytes(&[1, sub_authorities.len() as u8]));
+        tri!(self.bytes(slice(&authority, 2, 8)));
+        let mut i = 0;
+        while i < sub_authorities.len() {
+            tri!(self.u32(sub_authorities[i]));
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+const fn parse_ace_type(s: &[u8], is_sacl: bool) -> Result<u8, SddlError> {
+    let mut i = 0;
+    while i < ACE_TYPES.len() {
+        let (name, ace_type, in_sacl) = ACE_TYPES[i];
+        if eq(s, name.as_bytes()) {
+            if in_sacl != is_sacl {
+                return Err(SddlError::UnsupportedAceType);
+            }
+            return Ok(ace_type);
+        }
+        i += 1;
+    }
+    Err(SddlError::UnsupportedAceType)
+}
+
+const fn parse_ace_flags(s: &[u8]) -> Result<u8, SddlError> {
+    let mut flags = 0;
+    let mut pos = 0;
+    'codes: while pos < s.len() {
+        let rest = slice(s, pos, s.len());
+        let mut i = 0;
+        while i < ACE_FLAGS.len() {
+            let (name, flag) = ACE_FLAGS[i];
+            if starts_with(rest, name.as_bytes()) {
+                flags |= flag;
+                pos += name.len();
+                continue 'codes;
+            }
+            i += 1;
+        }
+        return Err(SddlError::InvalidAceFlags);
+    }
+    Ok(flags)
+}
+
+/// Parses the rights of an ACE; `label` selects the mandatory label rights.
+const fn parse_rights(s: &[u8], label: bool) -> Result<u32, SddlError> {
+    if !s.is_empty() && s[0].is_ascii_digit() {
+        return match parse_number(s) {
+            Some(value) if value <= u32::MAX as u64 => Ok(value as u32),
+            _ => Err(SddlError::InvalidRights),
+        };
+    }
+
+    let mut mask = 0;
+    let mut pos = 0;
+    while pos < s.len() {
+        if s.len() - pos < 2 {
+            return Err(SddlError::InvalidRights);
+        }
+        let code = slice(s, pos, pos + 2);
+        let table: &[(&str, u32)] = if label { &LABEL_RIGHTS } else { &BIT_RIGHTS };
+        mask |= match find_right(code, table) {
+            Some(bits) => bits,
+            None if !label => match find_right(code, &COMPOSITE_RIGHTS) {
+                Some(bits) => bits,
+                None => return Err(SddlError::InvalidRights),
+            },
+            None => return Err(SddlError::InvalidRights),
+        };
+        pos += 2;
+    }
+    Ok(mask)
+}
+
+const fn find_right(code: &[u8], table: &[(&str, u32)]) -> Option<u32> {
+    let mut i = 0;
+    while i < table.len() {
+        if eq(code, table[i].0.as_bytes()) {
+            return Some(table[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal number.
+const fn parse_number(s: &[u8]) -> Option<u64> {
+    let (digits, radix) = match s {
+        [b'0', b'x' | b'X', digits @ ..] => (digits, 16),
+        digits => (digits, 10),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: u64 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b @ b'0'..=b'9' => b - b'0',
+            b @ b'a'..=b'f' if radix == 16 => b - b'a' + 10,
+            b @ b'A'..=b'F' if radix == 16 => b - b'A' + 10,
+            _ => return None,
+        };
+        value = match value.checked_mul(radix) {
+            Some(value) => match value.checked_add(digit as u64) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(value)
+}
+
+const fn slice(s: &[u8], start: usize, end: usize) -> &[u8] {
+    let (head, _) = s.split_at(end);
+    let (_, tail) = head.split_at(start);
+    tail
+}
+
+const fn starts_with(s: &[u8], prefix: &[u8]) -> bool {
+    s.len() >= prefix.len() && eq(slice(s, 0, prefix.len()), prefix)
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A validated self-relative security descriptor, displayed as SDDL.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SecurityDescriptor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SecurityDescriptor<'a> {
+    /// Validates a self-relative security descriptor, e.g. one returned by
+    /// [`compile_into`] or read from the registry.
+    ///
+    /// Descriptors with ACE types this module cannot express in SDDL are
+    /// rejected with [`SddlError::UnsupportedAceType`].
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, SddlError> {
+        let descriptor = Self { bytes };
+        if bytes.len() < HEADER_LEN
+            || bytes[0] != SECURITY_DESCRIPTOR_REVISION
+            || descriptor.control() & SE_SELF_RELATIVE == 0
+        {
+            return Err(SddlError::InvalidDescriptor);
+        }
+        for offset in [descriptor.offset(OWNER), descriptor.offset(GROUP)]
+            .into_iter()
+            .flatten()
+        {
+            descriptor.sid_len(offset)?;
+        }
+        for acl in [descriptor.acl(DACL), descriptor.acl(SACL)].into_iter().flatten() {
+            for ace in descriptor.aces(acl?) {
+                let (ace_type, _, _, sid) = ace?;
+                if !ACE_TYPES.iter().any(|&(_, known, _)| known == ace_type) {
+                    return Err(SddlError::UnsupportedAceType);
+                }
+                descriptor.sid_len(sid)?;
+            }
+        }
+        Ok(descriptor)
+    }
+
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The descriptor as a `PSECURITY_DESCRIPTOR`.
+    pub const fn as_ptr(&self) -> *const c_void {
+        self.bytes.as_ptr().cast()
+    }
+
+    /// The `SECURITY_DESCRIPTOR_CONTROL` flags.
+    pub fn control(&self) -> u16 {
+        self.read_u16(2)
+    }
+
+    fn read_u16(&self, pos: usize) -> u16 {
+        u16::from_le_bytes([self.bytes[pos], self.bytes[pos + 1]])
+    }
+
+    fn read_u32(&self, pos: usize) -> u32 {
+        let bytes = &self.bytes[pos..pos + 4];
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    fn offset(&self, component: usize) -> Option<usize> {
+        let field = match component {
+            OWNER => 4,
+            GROUP => 8,
+            SACL => 12,
+            _ => 16,
+        };
+        let offset = self.read_u32(field) as usize;
+        (offset != 0).then_some(offset)
+    }
+
+    /// The ACL of `component`: `None` if not present, `Some(Ok(None))` for a
+    /// null ACL.
+    fn acl(&self, component: usize) -> Option<Result<Option<(usize, usize)>, SddlError>> {
+        let present = if component == DACL { SE_DACL_PRESENT } else { SE_SACL_PRESENT };
+        if self.control() & present == 0 {
+            return None;
+        }
+        let Some(offset) = self.offset(component) else {
+            return Some(Ok(None));
+        };
+        if offset + ACL_HEADER_LEN > self.bytes.len() {
+            return Some(Err(SddlError::InvalidDescriptor));
+        }
+        let size = self.read_u16(offset + 2) as usize;
+        if size < ACL_HEADER_LEN || offset + size > self.bytes.len() {
+            return Some(Err(SddlError::InvalidDescriptor));
+        }
+        Some(Ok(Some((offset, size))))
+    }
+
+    /// Iterates the ACEs of an ACL as `(type, flags, mask, sid offset)`.
+    fn aces(
+        &self,
+        acl: Option<(usize, usize)>,
+    ) -> impl Iterator<Item = Result<(u8, u8, u32, usize), SddlError>> + '_ {
+        let (offset, size, count) = match acl {
+            Some((offset, size)) => (offset, size, self.read_u16(offset + 4)),
+            None => (0, 0, 0),
+        };
+        let end = offset + size;
+        let mut pos = offset + ACL_HEADER_LEN;
+        (0..count).map(move |_| {
+            if pos + ACE_HEADER_LEN > end {
+                return Err(SddlError::InvalidDescriptor);
+            }
+            let ace_size = self.read_u16(pos + 2) as usize;
+            if ace_size < ACE_HEADER_LEN || pos + ace_size > end {
+                return Err(SddlError::InvalidDescriptor);
+            }
+            let ace = (self.bytes[pos], self.bytes[pos + 1], self.read_u32(pos + 4), pos + 8);
+            pos += ace_size;
+            Ok(ace)
+        })
+    }
+
+    fn sid_len(&self, offset: usize) -> Result<usize, SddlError> {
+        if offset + 8 > self.bytes.len() || self.bytes[offset] != 1 {
+            return Err(SddlError::InvalidDescriptor);
+        }
+        let count = self.bytes[offset + 1] as usize;
+        let len = 8 + 4 * count;
+        if count > SID_MAX_SUB_AUTHORITIES || offset + len > self.bytes.len() {
+            return Err(SddlError::InvalidDescriptor);
+        }
+        Ok(len)
+    }
+
+    fn write_sid(&self, f: &mut fmt::Formatter<'_>, offset: usize) -> fmt::Result {
+        let count = self.bytes[offset + 1] as usize;
+        let mut authority = [0; 8];
+        authority[2..].copy_from_slice(&self.bytes[offset + 2..offset + 8]);
+        let authority = u64::from_be_bytes(authority);
+        let sub_authority = |i: usize| self.read_u32(offset + 8 + 4 * i);
+
+        let alias = SID_ALIASES.iter().find(|(_, known_authority, known_subs)| {
+            *known_authority == authority
+                && known_subs.len() == count
+                && known_subs.iter().enumerate().all(|(i, &sub)| sub == sub_authority(i))
+        });
+        if let Some((name, _, _)) = alias {
+            return f.write_str(name);
+        }
+
+        if authority >> 32 == 0 {
+            write!(f, "S-1-{authority}")?;
+        } else {
+            write!(f, "S-1-{authority:#x}")?;
+        }
+        for i in 0..count {
+            write!(f, "-{}", sub_authority(i))?;
+        }
+        Ok(())
+    }
+
+    fn write_acl(&self, f: &mut fmt::Formatter<'_>, component: usize) -> fmt::Result {
+        let Some(Ok(acl)) = self.acl(component) else {
+            return Ok(());
+        };
+        let control = self.control();
+        let (protected, auto_inherited, auto_inherit_req) = if component == DACL {
+            f.write_str("D:")?;
+            (SE_DACL_PROTECTED, SE_DACL_AUTO_INHERITED, SE_DACL_AUTO_INHERIT_REQ)
+        } else {
+            f.write_str("S:")?;
+            (SE_SACL_PROTECTED, SE_SACL_AUTO_INHERITED, SE_SACL_AUTO_INHERIT_REQ)
+        };
+        for (flag, code) in [(protected, "P"), (auto_inherit_req, "AR"), (auto_inherited, "AI")] {
+            if control & flag != 0 {
+                f.write_str(code)?;
+            }
+        }
+        if acl.is_none() {
+            return f.write_str("NO_ACCESS_CONTROL");
+        }
+
+        for ace in self.aces(acl) {
+            let Ok((ace_type, ace_flags, mask, sid)) = ace else {
+                return Err(fmt::Error);
+            };
+            let name = ACE_TYPES
+                .iter()
+                .find(|&&(_, known, _)| known == ace_type)
+                .map_or("", |(name, _, _)| name);
+            write!(f, "({name};")?;
+            for (code, flag) in ACE_FLAGS {
+                if ace_flags & flag != 0 {
+                    f.write_str(code)?;
+                }
+            }
+            f.write_str(";")?;
+            write_rights(f, mask, ace_type == SYSTEM_MANDATORY_LABEL_ACE_TYPE)?;
+            f.write_str(";;;")?;
+            self.write_sid(f, sid)?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_rights(f: &mut fmt::Formatter<'_>, mask: u32, label: bool) -> fmt::Result {
+    let bit_rights: &[(&str, u32)] = if label { &LABEL_RIGHTS } else { &BIT_RIGHTS };
+    if !label
+        && let Some((code, _)) = COMPOSITE_RIGHTS.iter().find(|&&(_, bits)| bits == mask)
+    {
+        return f.write_str(code);
+    }
+    let known = bit_rights.iter().fold(0, |known, (_, bits)| known | bits);
+    if mask == 0 || mask & !known != 0 {
+        return write!(f, "{mask:#x}");
+    }
+    for (code, bits) in bit_rights {
+        if mask & bits != 0 {
+            f.write_str(code)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the descriptor as SDDL, with components in the order owner, group,
+/// DACL, SACL.
+impl fmt::Display for SecurityDescriptor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(owner) = self.offset(OWNER) {
+            f.write_str("O:")?;
+            self.write_sid(f, owner)?;
+        }
+        if let Some(group) = self.offset(GROUP) {
+            f.write_str("G:")?;
+            self.write_sid(f, group)?;
+        }
+        self.write_acl(f, DACL)?;
+        self.write_acl(f, SACL)
+    }
+}
+
+impl fmt::Debug for SecurityDescriptor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecurityDescriptor({self})")
+    }
+}
@@ -0,0 +1,217 @@
+//! `NTSTATUS` values, as returned by kernel routines and dispatch functions.
+//!
+//! The constants have the type of `wdk_sys::NTSTATUS`, a plain `i32`, so they
+//! can be used with the kernel APIs directly. The `windows` crate wraps the
+//! value instead: `windows::Win32::Foundation::NTSTATUS(STATUS_SUCCESS)`.
+//!
+//! [`NtStatus`] decodes a value, names it and maps it to a Win32 error code.
+//!
+//! The names of all statuses in `ntstatus.h` are generated into
+//! `status_table.rs` by `tools/status_table.pl`; the table is only linked
+//! into drivers that display statuses. Constants and Win32 mappings exist
+//! for the statuses drivers commonly return.
+
+use core::fmt;
+
+use crate::status_table::STATUS_NAMES;
+
+#[allow(clippy::upper_case_acronyms)]
+pub type NTSTATUS = i32;
+
+/// Equivalent of the `NT_SUCCESS` macro: success and informational values.
+pub const fn nt_success(status: NTSTATUS) -> bool {
+    status >= 0
+}
+
+/// Declares the `STATUS_*` constants together with the table behind
+/// [`NtStatus::to_win32_error`], so they cannot drift apart.
+macro_rules! statuses {
+    ($($name:ident = $value:literal => $win32_error:literal,)*) => {
+        $(pub const $name: NTSTATUS = $value as u32 as i32;)*
+
+        /// Value and Win32 error code of each constant.
+        const WIN32_ERRORS: &[(NTSTATUS, u32)] = &[$(($name, $win32_error)),*];
+    };
+}
+
+statuses! {
+    STATUS_SUCCESS = 0x0000_0000 => 0,
+    STATUS_TIMEOUT = 0x0000_0102 => 258,
+    STATUS_PENDING = 0x0000_0103 => 997,
+
+    STATUS_BUFFER_OVERFLOW = 0x8000_0005 => 234,
+    STATUS_DEVICE_BUSY = 0x8000_0011 => 170,
+    STATUS_NO_MORE_ENTRIES = 0x8000_001a => 259,
+
+    STATUS_UNSUCCESSFUL = 0xc000_0001 => 31,
+    STATUS_NOT_IMPLEMENTED = 0xc000_0002 => 1,
+    STATUS_INFO_LENGTH_MISMATCH = 0xc000_0004 => 24,
+    STATUS_ACCESS_VIOLATION = 0xc000_0005 => 998,
+    STATUS_INVALID_HANDLE = 0xc000_0008 => 6,
+    STATUS_INVALID_PARAMETER = 0xc000_000d => 87,
+    STATUS_NO_SUCH_DEVICE = 0xc000_000e => 2,
+    STATUS_INVALID_DEVICE_REQUEST = 0xc000_0010 => 1,
+    STATUS_END_OF_FILE = 0xc000_0011 => 38,
+    STATUS_NO_MEMORY = 0xc000_0017 => 8,
+    STATUS_ACCESS_DENIED = 0xc000_0022 => 5,
+    STATUS_BUFFER_TOO_SMALL = 0xc000_0023 => 122,
+    STATUS_OBJECT_TYPE_MISMATCH = 0xc000_0024 => 6,
+    STATUS_OBJECT_NAME_INVALID = 0xc000_0033 => 123,
+    STATUS_OBJECT_NAME_NOT_FOUND = 0xc000_0034 => 2,
+    STATUS_OBJECT_NAME_COLLISION = 0xc000_0035 => 183,
+    STATUS_INSUFFICIENT_RESOURCES = 0xc000_009a => 1450,
+    STATUS_DEVICE_NOT_READY = 0xc000_00a3 => 21,
+    STATUS_NOT_SUPPORTED = 0xc000_00bb => 50,
+    STATUS_INVALID_USER_BUFFER = 0xc000_00e8 => 1784,
+    STATUS_CANCELLED = 0xc000_0120 => 995,
+    STATUS_INVALID_DEVICE_STATE = 0xc000_0184 => 22,
+    STATUS_INVALID_BUFFER_SIZE = 0xc000_0206 => 1784,
+    STATUS_NOT_FOUND = 0xc000_0225 => 1168,
+}
+
+/// The facility of Win32 error codes wrapped in an `NTSTATUS`.
+pub const FACILITY_NTWIN32: u16 = 0x7;
+
+/// `ERROR_MR_MID_NOT_FOUND`, what `RtlNtStatusToDosError` returns for values
+/// it does not know.
+pub const ERROR_MR_MID_NOT_FOUND: u32 = 317;
+
+/// The two severity bits of an `NTSTATUS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Success = 0,
+    Informational = 1,
+    Warning = 2,
+    Error = 3,
+}
+
+/// An `NTSTATUS` value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct NtStatus(NTSTATUS);
+
+impl NtStatus {
+    pub const fn from_raw(status: NTSTATUS) -> Self {
+        Self(status)
+    }
+
+    pub const fn as_raw(self) -> NTSTATUS {
+        self.0
+    }
+
+    pub const fn severity(self) -> Severity {
+        match (self.0 as u32) >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Whether the customer bit is set, marking values defined outside
+    /// Microsoft.
+    pub const fn is_customer(self) -> bool {
+        (self.0 as u32) & 0x2000_0000 != 0
+    }
+
+    pub const fn facility(self) -> u16 {
+        (((self.0 as u32) >> 16) & 0xfff) as u16
+    }
+
+    pub const fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Like `NT_SUCCESS`: true for success and informational values.
+    pub const fn is_success(self) -> bool {
+        nt_success(self.0)
+    }
+
+    pub const fn is_warning(self) -> bool {
+        matches!(self.severity(), Severity::Warning)
+    }
+
+    pub const fn is_error(self) -> bool {
+        matches!(self.severity(), Severity::Error)
+    }
+
+    /// The symbolic name from `ntstatus.h`, e.g. `STATUS_ACCESS_DENIED`.
+    pub const fn name(self) -> Option<&'static str> {
+        let (mut low, mut high) = (0, STATUS_NAMES.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (value, name) = STATUS_NAMES[mid];
+            if value == self.0 as u32 {
+                return Some(name);
+            } else if value < self.0 as u32 {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        None
+    }
+
+    /// The Win32 error code, like `RtlNtStatusToDosError`: customer values
+    /// are returned unchanged, the statuses this module defines are mapped,
+    /// Win32 errors wrapped in an `NTSTATUS` are unwrapped, and anything else
+    /// becomes `ERROR_MR_MID_NOT_FOUND`.
+    pub const fn to_win32_error(self) -> u32 {
+        if self.is_customer() {
+            return self.0 as u32;
+        }
+        let mut i = 0;
+        while i < WIN32_ERRORS.len() {
+            if WIN32_ERRORS[i].0 == self.0 {
+                return WIN32_ERRORS[i].1;
+            }
+            i += 1;
+        }
+        if self.facility() == FACILITY_NTWIN32 && !self.is_success() {
+            return self.code() as u32;
+        }
+        ERROR_MR_MID_NOT_FOUND
+    }
+}
+
+impl From<NTSTATUS> for NtStatus {
+    fn from(status: NTSTATUS) -> Self {
+        Self(status)
+    }
+}
+
+impl From<NtStatus> for NTSTATUS {
+    fn from(status: NtStatus) -> Self {
+        status.0
+    }
+}
+
+#[cfg(all(feature = "user", not(feature = "kernel")))]
+impl From<windows::Win32::Foundation::NTSTATUS> for NtStatus {
+    fn from(status: windows::Win32::Foundation::NTSTATUS) -> Self {
+        Self(status.0)
+    }
+}
+
+#[cfg(all(feature = "user", not(feature = "kernel")))]
+impl From<NtStatus> for windows::Win32::Foundation::NTSTATUS {
+    fn from(status: NtStatus) -> Self {
+        Self(status.0)
+    }
+}
+
+/// Writes the symbolic name, or the value as `0xC0000001` for unknown ones.
+impl fmt::Display for NtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:#010X}", self.0 as u32),
+        }
+    }
+}
+
+impl fmt::Debug for NtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NtStatus({self})")
+    }
+}
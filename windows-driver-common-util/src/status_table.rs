@@ -0,0 +1,2837 @@
+// Generated by tools/status_table.pl from the windows-0.62.2 crate, do not edit.
+
+/// Value and name of each status, sorted by value.
+#[rustfmt::skip]
+pub(crate) const STATUS_NAMES: &[(u32, &str)] = &[
+    (0x0000_0000, "STATUS_SUCCESS"),
+    (0x0000_0001, "STATUS_WAIT_1"),
+    (0x0000_0002, "STATUS_WAIT_2"),
+    (0x0000_0003, "STATUS_WAIT_3"),
+    (0x0000_003f, "STATUS_WAIT_63"),
+    (0x0000_0080, "STATUS_ABANDONED"),
+    (0x0000_00bf, "STATUS_ABANDONED_WAIT_63"),
+    (0x0000_00c0, "STATUS_USER_APC"),
+    (0x0000_00ff, "STATUS_ALREADY_COMPLETE"),
+    (0x0000_0100, "STATUS_KERNEL_APC"),
+    (0x0000_0101, "STATUS_ALERTED"),
+    (0x0000_0102, "STATUS_TIMEOUT"),
+    (0x0000_0103, "STATUS_PENDING"),
+    (0x0000_0104, "STATUS_REPARSE"),
+    (0x0000_0105, "STATUS_MORE_ENTRIES"),
+    (0x0000_0106, "STATUS_NOT_ALL_ASSIGNED"),
+    (0x0000_0107, "STATUS_SOME_NOT_MAPPED"),
+    (0x0000_0108, "STATUS_OPLOCK_BREAK_IN_PROGRESS"),
+    (0x0000_0109, "STATUS_VOLUME_MOUNTED"),
+    (0x0000_010a, "STATUS_RXACT_COMMITTED"),
+    (0x0000_010b, "STATUS_NOTIFY_CLEANUP"),
+    (0x0000_010c, "STATUS_NOTIFY_ENUM_DIR"),
+    (0x0000_010d, "STATUS_NO_QUOTAS_FOR_ACCOUNT"),
+    (0x0000_010e, "STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED"),
+    (0x0000_0110, "STATUS_PAGE_FAULT_TRANSITION"),
+    (0x0000_0111, "STATUS_PAGE_FAULT_DEMAND_ZERO"),
+    (0x0000_0112, "STATUS_PAGE_FAULT_COPY_ON_WRITE"),
+    (0x0000_0113, "STATUS_PAGE_FAULT_GUARD_PAGE"),
+    (0x0000_0114, "STATUS_PAGE_FAULT_PAGING_FILE"),
+    (0x0000_0115, "STATUS_CACHE_PAGE_LOCKED"),
+    (0x0000_0116, "STATUS_CRASH_DUMP"),
+    (0x0000_0117, "STATUS_BUFFER_ALL_ZEROS"),
+    (0x0000_0118, "STATUS_REPARSE_OBJECT"),
+    (0x0000_0119, "STATUS_RESOURCE_REQUIREMENTS_CHANGED"),
+    (0x0000_0120, "STATUS_TRANSLATION_COMPLETE"),
+    (0x0000_0121, "STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY"),
+    (0x0000_0122, "STATUS_NOTHING_TO_TERMINATE"),
+    (0x0000_0123, "STATUS_PROCESS_NOT_IN_JOB"),
+    (0x0000_0124, "STATUS_PROCESS_IN_JOB"),
+    (0x0000_0125, "STATUS_VOLSNAP_HIBERNATE_READY"),
+    (0x0000_0126, "STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY"),
+    (0x0000_0127, "STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED"),
+    (0x0000_0128, "STATUS_INTERRUPT_STILL_CONNECTED"),
+    (0x0000_0129, "STATUS_PROCESS_CLONED"),
+    (0x0000_012a, "STATUS_FILE_LOCKED_WITH_ONLY_READERS"),
+    (0x0000_012b, "STATUS_FILE_LOCKED_WITH_WRITERS"),
+    (0x0000_012c, "STATUS_VALID_IMAGE_HASH"),
+    (0x0000_012d, "STATUS_VALID_CATALOG_HASH"),
+    (0x0000_012e, "STATUS_VALID_STRONG_CODE_HASH"),
+    (0x0000_012f, "STATUS_GHOSTED"),
+    (0x0000_0130, "STATUS_DATA_OVERWRITTEN"),
+    (0x0000_0202, "STATUS_RESOURCEMANAGER_READ_ONLY"),
+    (0x0000_0210, "STATUS_RING_PREVIOUSLY_EMPTY"),
+    (0x0000_0211, "STATUS_RING_PREVIOUSLY_FULL"),
+    (0x0000_0212, "STATUS_RING_PREVIOUSLY_ABOVE_QUOTA"),
+    (0x0000_0213, "STATUS_RING_NEWLY_EMPTY"),
+    (0x0000_0214, "STATUS_RING_SIGNAL_OPPOSITE_ENDPOINT"),
+    (0x0000_0215, "STATUS_OPLOCK_SWITCHED_TO_NEW_HANDLE"),
+    (0x0000_0216, "STATUS_OPLOCK_HANDLE_CLOSED"),
+    (0x0000_0367, "STATUS_WAIT_FOR_OPLOCK"),
+    (0x0000_0368, "STATUS_REPARSE_GLOBAL"),
+    (0x0000_0369, "STATUS_PAGE_FAULT_RETRY"),
+    (0x0001_0001, "DBG_EXCEPTION_HANDLED"),
+    (0x0001_0002, "DBG_CONTINUE"),
+    (0x001c_0001, "STATUS_FLT_IO_COMPLETE"),
+    (0x0029_3000, "STATUS_RTPM_CONTEXT_CONTINUE"),
+    (0x0029_3001, "STATUS_RTPM_CONTEXT_COMPLETE"),
+    (0x0035_0059, "STATUS_HV_PENDING_PAGE_REQUESTS"),
+    (0x00e7_0000, "STATUS_SPACES_REPAIRED"),
+    (0x00e7_0001, "STATUS_SPACES_PAUSE"),
+    (0x00e7_0002, "STATUS_SPACES_COMPLETE"),
+    (0x00e7_0003, "STATUS_SPACES_REDIRECT"),
+    (0x4000_0000, "STATUS_OBJECT_NAME_EXISTS"),
+    (0x4000_0001, "STATUS_THREAD_WAS_SUSPENDED"),
+    (0x4000_0002, "STATUS_WORKING_SET_LIMIT_RANGE"),
+    (0x4000_0003, "STATUS_IMAGE_NOT_AT_BASE"),
+    (0x4000_0004, "STATUS_RXACT_STATE_CREATED"),
+    (0x4000_0005, "STATUS_SEGMENT_NOTIFICATION"),
+    (0x4000_0006, "STATUS_LOCAL_USER_SESSION_KEY"),
+    (0x4000_0007, "STATUS_BAD_CURRENT_DIRECTORY"),
+    (0x4000_0008, "STATUS_SERIAL_MORE_WRITES"),
+    (0x4000_0009, "STATUS_REGISTRY_RECOVERED"),
+    (0x4000_000a, "STATUS_FT_READ_RECOVERY_FROM_BACKUP"),
+    (0x4000_000b, "STATUS_FT_WRITE_RECOVERY"),
+    (0x4000_000c, "STATUS_SERIAL_COUNTER_TIMEOUT"),
+    (0x4000_000d, "STATUS_NULL_LM_PASSWORD"),
+    (0x4000_000e, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH"),
+    (0x4000_000f, "STATUS_RECEIVE_PARTIAL"),
+    (0x4000_0010, "STATUS_RECEIVE_EXPEDITED"),
+    (0x4000_0011, "STATUS_RECEIVE_PARTIAL_EXPEDITED"),
+    (0x4000_0012, "STATUS_EVENT_DONE"),
+    (0x4000_0013, "STATUS_EVENT_PENDING"),
+    (0x4000_0014, "STATUS_CHECKING_FILE_SYSTEM"),
+    (0x4000_0015, "STATUS_FATAL_APP_EXIT"),
+    (0x4000_0016, "STATUS_PREDEFINED_HANDLE"),
+    (0x4000_0017, "STATUS_WAS_UNLOCKED"),
+    (0x4000_0018, "STATUS_SERVICE_NOTIFICATION"),
+    (0x4000_0019, "STATUS_WAS_LOCKED"),
+    (0x4000_001a, "STATUS_LOG_HARD_ERROR"),
+    (0x4000_001b, "STATUS_ALREADY_WIN32"),
+    (0x4000_001c, "STATUS_WX86_UNSIMULATE"),
+    (0x4000_001d, "STATUS_WX86_CONTINUE"),
+    (0x4000_001e, "STATUS_WX86_SINGLE_STEP"),
+    (0x4000_001f, "STATUS_WX86_BREAKPOINT"),
+    (0x4000_0020, "STATUS_WX86_EXCEPTION_CONTINUE"),
+    (0x4000_0021, "STATUS_WX86_EXCEPTION_LASTCHANCE"),
+    (0x4000_0022, "STATUS_WX86_EXCEPTION_CHAIN"),
+    (0x4000_0023, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE"),
+    (0x4000_0024, "STATUS_NO_YIELD_PERFORMED"),
+    (0x4000_0025, "STATUS_TIMER_RESUME_IGNORED"),
+    (0x4000_0026, "STATUS_ARBITRATION_UNHANDLED"),
+    (0x4000_0027, "STATUS_CARDBUS_NOT_SUPPORTED"),
+    (0x4000_0028, "STATUS_WX86_CREATEWX86TIB"),
+    (0x4000_0029, "STATUS_MP_PROCESSOR_MISMATCH"),
+    (0x4000_002a, "STATUS_HIBERNATED"),
+    (0x4000_002b, "STATUS_RESUME_HIBERNATION"),
+    (0x4000_002c, "STATUS_FIRMWARE_UPDATED"),
+    (0x4000_002d, "STATUS_DRIVERS_LEAKING_LOCKED_PAGES"),
+    (0x4000_002e, "STATUS_MESSAGE_RETRIEVED"),
+    (0x4000_002f, "STATUS_SYSTEM_POWERSTATE_TRANSITION"),
+    (0x4000_0030, "STATUS_ALPC_CHECK_COMPLETION_LIST"),
+    (0x4000_0031, "STATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION"),
+    (0x4000_0032, "STATUS_ACCESS_AUDIT_BY_POLICY"),
+    (0x4000_0033, "STATUS_ABANDON_HIBERFILE"),
+    (0x4000_0034, "STATUS_BIZRULES_NOT_ENABLED"),
+    (0x4000_0035, "STATUS_FT_READ_FROM_COPY"),
+    (0x4000_0036, "STATUS_IMAGE_AT_DIFFERENT_BASE"),
+    (0x4000_0037, "STATUS_PATCH_DEFERRED"),
+    (0x4000_0038, "STATUS_EMULATION_BREAKPOINT"),
+    (0x4000_0039, "STATUS_EMULATION_SYSCALL"),
+    (0x4000_0294, "STATUS_WAKE_SYSTEM"),
+    (0x4000_0370, "STATUS_DS_SHUTTING_DOWN"),
+    (0x4000_0807, "STATUS_DISK_REPAIR_REDIRECTED"),
+    (0x4000_a144, "STATUS_SERVICES_FAILED_AUTOSTART"),
+    (0x4001_0001, "DBG_REPLY_LATER"),
+    (0x4001_0002, "DBG_UNABLE_TO_PROVIDE_HANDLE"),
+    (0x4001_0003, "DBG_TERMINATE_THREAD"),
+    (0x4001_0004, "DBG_TERMINATE_PROCESS"),
+    (0x4001_0005, "DBG_CONTROL_C"),
+    (0x4001_0006, "DBG_PRINTEXCEPTION_C"),
+    (0x4001_0007, "DBG_RIPEXCEPTION"),
+    (0x4001_0008, "DBG_CONTROL_BREAK"),
+    (0x4001_0009, "DBG_COMMAND_EXCEPTION"),
+    (0x4001_000a, "DBG_PRINTEXCEPTION_WIDE_C"),
+    (0x4002_0056, "RPC_NT_UUID_LOCAL_ONLY"),
+    (0x4002_00af, "RPC_NT_SEND_INCOMPLETE"),
+    (0x400a_0004, "STATUS_CTX_CDM_CONNECT"),
+    (0x400a_0005, "STATUS_CTX_CDM_DISCONNECT"),
+    (0x4015_000d, "STATUS_SXS_RELEASE_ACTIVATION_CONTEXT"),
+    (0x4019_0001, "STATUS_HEURISTIC_DAMAGE_POSSIBLE"),
+    (0x4019_0034, "STATUS_RECOVERY_NOT_NEEDED"),
+    (0x4019_0035, "STATUS_RM_ALREADY_STARTED"),
+    (0x401a_000c, "STATUS_LOG_NO_RESTART"),
+    (0x401b_00ec, "STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST"),
+    (0x401e_000a, "STATUS_GRAPHICS_PARTIAL_DATA_POPULATED"),
+    (0x401e_0201, "STATUS_GRAPHICS_SKIP_ALLOCATION_PREPARATION"),
+    (0x401e_0307, "STATUS_GRAPHICS_MODE_NOT_PINNED"),
+    (0x401e_031e, "STATUS_GRAPHICS_NO_PREFERRED_MODE"),
+    (0x401e_034b, "STATUS_GRAPHICS_DATASET_IS_EMPTY"),
+    (0x401e_034c, "STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET"),
+    (0x401e_0351, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED"),
+    (0x401e_042f, "STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS"),
+    (0x401e_0437, "STATUS_GRAPHICS_LEADLINK_START_DEFERRED"),
+    (0x401e_0439, "STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY"),
+    (0x401e_043a, "STATUS_GRAPHICS_START_DEFERRED"),
+    (0x401e_043c, "STATUS_GRAPHICS_DEPENDABLE_CHILD_STATUS"),
+    (0x4023_0001, "STATUS_NDIS_INDICATION_REQUIRED"),
+    (0x4029_2023, "STATUS_PCP_UNSUPPORTED_PSS_SALT"),
+    (0x8000_0001, "STATUS_GUARD_PAGE_VIOLATION"),
+    (0x8000_0002, "STATUS_DATATYPE_MISALIGNMENT"),
+    (0x8000_0003, "STATUS_BREAKPOINT"),
+    (0x8000_0004, "STATUS_SINGLE_STEP"),
+    (0x8000_0005, "STATUS_BUFFER_OVERFLOW"),
+    (0x8000_0006, "STATUS_NO_MORE_FILES"),
+    (0x8000_0007, "STATUS_WAKE_SYSTEM_DEBUGGER"),
+    (0x8000_000a, "STATUS_HANDLES_CLOSED"),
+    (0x8000_000b, "STATUS_NO_INHERITANCE"),
+    (0x8000_000c, "STATUS_GUID_SUBSTITUTION_MADE"),
+    (0x8000_000d, "STATUS_PARTIAL_COPY"),
+    (0x8000_000e, "STATUS_DEVICE_PAPER_EMPTY"),
+    (0x8000_000f, "STATUS_DEVICE_POWERED_OFF"),
+    (0x8000_0010, "STATUS_DEVICE_OFF_LINE"),
+    (0x8000_0011, "STATUS_DEVICE_BUSY"),
+    (0x8000_0012, "STATUS_NO_MORE_EAS"),
+    (0x8000_0013, "STATUS_INVALID_EA_NAME"),
+    (0x8000_0014, "STATUS_EA_LIST_INCONSISTENT"),
+    (0x8000_0015, "STATUS_INVALID_EA_FLAG"),
+    (0x8000_0016, "STATUS_VERIFY_REQUIRED"),
+    (0x8000_0017, "STATUS_EXTRANEOUS_INFORMATION"),
+    (0x8000_0018, "STATUS_RXACT_COMMIT_NECESSARY"),
+    (0x8000_001a, "STATUS_NO_MORE_ENTRIES"),
+    (0x8000_001b, "STATUS_FILEMARK_DETECTED"),
+    (0x8000_001c, "STATUS_MEDIA_CHANGED"),
+    (0x8000_001d, "STATUS_BUS_RESET"),
+    (0x8000_001e, "STATUS_END_OF_MEDIA"),
+    (0x8000_001f, "STATUS_BEGINNING_OF_MEDIA"),
+    (0x8000_0020, "STATUS_MEDIA_CHECK"),
+    (0x8000_0021, "STATUS_SETMARK_DETECTED"),
+    (0x8000_0022, "STATUS_NO_DATA_DETECTED"),
+    (0x8000_0023, "STATUS_REDIRECTOR_HAS_OPEN_HANDLES"),
+    (0x8000_0024, "STATUS_SERVER_HAS_OPEN_HANDLES"),
+    (0x8000_0025, "STATUS_ALREADY_DISCONNECTED"),
+    (0x8000_0026, "STATUS_LONGJUMP"),
+    (0x8000_0027, "STATUS_CLEANER_CARTRIDGE_INSTALLED"),
+    (0x8000_0028, "STATUS_PLUGPLAY_QUERY_VETOED"),
+    (0x8000_0029, "STATUS_UNWIND_CONSOLIDATE"),
+    (0x8000_002a, "STATUS_REGISTRY_HIVE_RECOVERED"),
+    (0x8000_002b, "STATUS_DLL_MIGHT_BE_INSECURE"),
+    (0x8000_002c, "STATUS_DLL_MIGHT_BE_INCOMPATIBLE"),
+    (0x8000_002d, "STATUS_STOPPED_ON_SYMLINK"),
+    (0x8000_002e, "STATUS_CANNOT_GRANT_REQUESTED_OPLOCK"),
+    (0x8000_002f, "STATUS_NO_ACE_CONDITION"),
+    (0x8000_0030, "STATUS_DEVICE_SUPPORT_IN_PROGRESS"),
+    (0x8000_0031, "STATUS_DEVICE_POWER_CYCLE_REQUIRED"),
+    (0x8000_0032, "STATUS_NO_WORK_DONE"),
+    (0x8000_0033, "STATUS_RETURN_ADDRESS_HIJACK_ATTEMPT"),
+    (0x8000_0034, "STATUS_RECOVERABLE_BUGCHECK"),
+    (0x8000_01b6, "STATUS_DEVICE_RESET_REQUIRED"),
+    (0x8000_0288, "STATUS_DEVICE_REQUIRES_CLEANING"),
+    (0x8000_0289, "STATUS_DEVICE_DOOR_OPEN"),
+    (0x8000_0803, "STATUS_DATA_LOST_REPAIR"),
+    (0x8000_a127, "STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED"),
+    (0x8000_cf00, "STATUS_CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH"),
+    (0x8000_cf04, "STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE"),
+    (0x8000_cf05, "STATUS_CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS"),
+    (0x8001_0001, "DBG_EXCEPTION_NOT_HANDLED"),
+    (0x8013_0001, "STATUS_CLUSTER_NODE_ALREADY_UP"),
+    (0x8013_0002, "STATUS_CLUSTER_NODE_ALREADY_DOWN"),
+    (0x8013_0003, "STATUS_CLUSTER_NETWORK_ALREADY_ONLINE"),
+    (0x8013_0004, "STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE"),
+    (0x8013_0005, "STATUS_CLUSTER_NODE_ALREADY_MEMBER"),
+    (0x8019_0009, "STATUS_COULD_NOT_RESIZE_LOG"),
+    (0x8019_0029, "STATUS_NO_TXF_METADATA"),
+    (0x8019_0031, "STATUS_CANT_RECOVER_WITH_HANDLE_OPEN"),
+    (0x8019_0041, "STATUS_TXF_METADATA_ALREADY_PRESENT"),
+    (0x8019_0042, "STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET"),
+    (0x801b_00eb, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED"),
+    (0x801c_0001, "STATUS_FLT_BUFFER_TOO_SMALL"),
+    (0x801e_0000, "STATUS_GRAPHICS_LINK_CONFIGURATION_IN_PROGRESS"),
+    (0x8021_0001, "STATUS_FVE_PARTIAL_METADATA"),
+    (0x8021_0002, "STATUS_FVE_TRANSIENT_STATE"),
+    (0x8037_0001, "STATUS_VID_REMOTE_NODE_PARENT_GPA_PAGES_USED"),
+    (0x8038_0001, "STATUS_VOLMGR_INCOMPLETE_REGENERATION"),
+    (0x8038_0002, "STATUS_VOLMGR_INCOMPLETE_DISK_MIGRATION"),
+    (0x8039_0001, "STATUS_BCD_NOT_ALL_ENTRIES_IMPORTED"),
+    (0x8039_0003, "STATUS_BCD_NOT_ALL_ENTRIES_SYNCHRONIZED"),
+    (0x803a_0001, "STATUS_QUERY_STORAGE_ERROR"),
+    (0x803f_0001, "STATUS_GDI_HANDLE_LEAK"),
+    (0x8043_0006, "STATUS_SECUREBOOT_NOT_ENABLED"),
+    (0xc000_0001, "STATUS_UNSUCCESSFUL"),
+    (0xc000_0002, "STATUS_NOT_IMPLEMENTED"),
+    (0xc000_0003, "STATUS_INVALID_INFO_CLASS"),
+    (0xc000_0004, "STATUS_INFO_LENGTH_MISMATCH"),
+    (0xc000_0005, "STATUS_ACCESS_VIOLATION"),
+    (0xc000_0006, "STATUS_IN_PAGE_ERROR"),
+    (0xc000_0007, "STATUS_PAGEFILE_QUOTA"),
+    (0xc000_0008, "STATUS_INVALID_HANDLE"),
+    (0xc000_0009, "STATUS_BAD_INITIAL_STACK"),
+    (0xc000_000a, "STATUS_BAD_INITIAL_PC"),
+    (0xc000_000b, "STATUS_INVALID_CID"),
+    (0xc000_000c, "STATUS_TIMER_NOT_CANCELED"),
+    (0xc000_000d, "STATUS_INVALID_PARAMETER"),
+    (0xc000_000e, "STATUS_NO_SUCH_DEVICE"),
+    (0xc000_000f, "STATUS_NO_SUCH_FILE"),
+    (0xc000_0010, "STATUS_INVALID_DEVICE_REQUEST"),
+    (0xc000_0011, "STATUS_END_OF_FILE"),
+    (0xc000_0012, "STATUS_WRONG_VOLUME"),
+    (0xc000_0013, "STATUS_NO_MEDIA_IN_DEVICE"),
+    (0xc000_0014, "STATUS_UNRECOGNIZED_MEDIA"),
+    (0xc000_0015, "STATUS_NONEXISTENT_SECTOR"),
+    (0xc000_0016, "STATUS_MORE_PROCESSING_REQUIRED"),
+    (0xc000_0017, "STATUS_NO_MEMORY"),
+    (0xc000_0018, "STATUS_CONFLICTING_ADDRESSES"),
+    (0xc000_0019, "STATUS_NOT_MAPPED_VIEW"),
+    (0xc000_001a, "STATUS_UNABLE_TO_FREE_VM"),
+    (0xc000_001b, "STATUS_UNABLE_TO_DELETE_SECTION"),
+    (0xc000_001c, "STATUS_INVALID_SYSTEM_SERVICE"),
+    (0xc000_001d, "STATUS_ILLEGAL_INSTRUCTION"),
+    (0xc000_001e, "STATUS_INVALID_LOCK_SEQUENCE"),
+    (0xc000_001f, "STATUS_INVALID_VIEW_SIZE"),
+    (0xc000_0020, "STATUS_INVALID_FILE_FOR_SECTION"),
+    (0xc000_0021, "STATUS_ALREADY_COMMITTED"),
+    (0xc000_0022, "STATUS_ACCESS_DENIED"),
+    (0xc000_0023, "STATUS_BUFFER_TOO_SMALL"),
+    (0xc000_0024, "STATUS_OBJECT_TYPE_MISMATCH"),
+    (0xc000_0025, "STATUS_NONCONTINUABLE_EXCEPTION"),
+    (0xc000_0026, "STATUS_INVALID_DISPOSITION"),
+    (0xc000_0027, "STATUS_UNWIND"),
+    (0xc000_0028, "STATUS_BAD_STACK"),
+    (0xc000_0029, "STATUS_INVALID_UNWIND_TARGET"),
+    (0xc000_002a, "STATUS_NOT_LOCKED"),
+    (0xc000_002b, "STATUS_PARITY_ERROR"),
+    (0xc000_002c, "STATUS_UNABLE_TO_DECOMMIT_VM"),
+    (0xc000_002d, "STATUS_NOT_COMMITTED"),
+    (0xc000_002e, "STATUS_INVALID_PORT_ATTRIBUTES"),
+    (0xc000_002f, "STATUS_PORT_MESSAGE_TOO_LONG"),
+    (0xc000_0030, "STATUS_INVALID_PARAMETER_MIX"),
+    (0xc000_0031, "STATUS_INVALID_QUOTA_LOWER"),
+    (0xc000_0032, "STATUS_DISK_CORRUPT_ERROR"),
+    (0xc000_0033, "STATUS_OBJECT_NAME_INVALID"),
+    (0xc000_0034, "STATUS_OBJECT_NAME_NOT_FOUND"),
+    (0xc000_0035, "STATUS_OBJECT_NAME_COLLISION"),
+    (0xc000_0036, "STATUS_PORT_DO_NOT_DISTURB"),
+    (0xc000_0037, "STATUS_PORT_DISCONNECTED"),
+    (0xc000_0038, "STATUS_DEVICE_ALREADY_ATTACHED"),
+    (0xc000_0039, "STATUS_OBJECT_PATH_INVALID"),
+    (0xc000_003a, "STATUS_OBJECT_PATH_NOT_FOUND"),
+    (0xc000_003b, "STATUS_OBJECT_PATH_SYNTAX_BAD"),
+    (0xc000_003c, "STATUS_DATA_OVERRUN"),
+    (0xc000_003d, "STATUS_DATA_LATE_ERROR"),
+    (0xc000_003e, "STATUS_DATA_ERROR"),
+    (0xc000_003f, "STATUS_CRC_ERROR"),
+    (0xc000_0040, "STATUS_SECTION_TOO_BIG"),
+    (0xc000_0041, "STATUS_PORT_CONNECTION_REFUSED"),
+    (0xc000_0042, "STATUS_INVALID_PORT_HANDLE"),
+    (0xc000_0043, "STATUS_SHARING_VIOLATION"),
+    (0xc000_0044, "STATUS_QUOTA_EXCEEDED"),
+    (0xc000_0045, "STATUS_INVALID_PAGE_PROTECTION"),
+    (0xc000_0046, "STATUS_MUTANT_NOT_OWNED"),
+    (0xc000_0047, "STATUS_SEMAPHORE_LIMIT_EXCEEDED"),
+    (0xc000_0048, "STATUS_PORT_ALREADY_SET"),
+    (0xc000_0049, "STATUS_SECTION_NOT_IMAGE"),
+    (0xc000_004a, "STATUS_SUSPEND_COUNT_EXCEEDED"),
+    (0xc000_004b, "STATUS_THREAD_IS_TERMINATING"),
+    (0xc000_004c, "STATUS_BAD_WORKING_SET_LIMIT"),
+    (0xc000_004d, "STATUS_INCOMPATIBLE_FILE_MAP"),
+    (0xc000_004e, "STATUS_SECTION_PROTECTION"),
+    (0xc000_004f, "STATUS_EAS_NOT_SUPPORTED"),
+    (0xc000_0050, "STATUS_EA_TOO_LARGE"),
+    (0xc000_0051, "STATUS_NONEXISTENT_EA_ENTRY"),
+    (0xc000_0052, "STATUS_NO_EAS_ON_FILE"),
+    (0xc000_0053, "STATUS_EA_CORRUPT_ERROR"),
+    (0xc000_0054, "STATUS_FILE_LOCK_CONFLICT"),
+    (0xc000_0055, "STATUS_LOCK_NOT_GRANTED"),
+    (0xc000_0056, "STATUS_DELETE_PENDING"),
+    (0xc000_0057, "STATUS_CTL_FILE_NOT_SUPPORTED"),
+    (0xc000_0058, "STATUS_UNKNOWN_REVISION"),
+    (0xc000_0059, "STATUS_REVISION_MISMATCH"),
+    (0xc000_005a, "STATUS_INVALID_OWNER"),
+    (0xc000_005b, "STATUS_INVALID_PRIMARY_GROUP"),
+    (0xc000_005c, "STATUS_NO_IMPERSONATION_TOKEN"),
+    (0xc000_005d, "STATUS_CANT_DISABLE_MANDATORY"),
+    (0xc000_005e, "STATUS_NO_LOGON_SERVERS"),
+    (0xc000_0060, "STATUS_NO_SUCH_PRIVILEGE"),
+    (0xc000_0061, "STATUS_PRIVILEGE_NOT_HELD"),
+    (0xc000_0062, "STATUS_INVALID_ACCOUNT_NAME"),
+    (0xc000_0063, "STATUS_USER_EXISTS"),
+    (0xc000_0065, "STATUS_GROUP_EXISTS"),
+    (0xc000_0066, "STATUS_NO_SUCH_GROUP"),
+    (0xc000_0067, "STATUS_MEMBER_IN_GROUP"),
+    (0xc000_0068, "STATUS_MEMBER_NOT_IN_GROUP"),
+    (0xc000_0069, "STATUS_LAST_ADMIN"),
+    (0xc000_006b, "STATUS_ILL_FORMED_PASSWORD"),
+    (0xc000_006c, "STATUS_PASSWORD_RESTRICTION"),
+    (0xc000_006f, "STATUS_INVALID_LOGON_HOURS"),
+    (0xc000_0070, "STATUS_INVALID_WORKSTATION"),
+    (0xc000_0073, "STATUS_NONE_MAPPED"),
+    (0xc000_0074, "STATUS_TOO_MANY_LUIDS_REQUESTED"),
+    (0xc000_0075, "STATUS_LUIDS_EXHAUSTED"),
+    (0xc000_0076, "STATUS_INVALID_SUB_AUTHORITY"),
+    (0xc000_0077, "STATUS_INVALID_ACL"),
+    (0xc000_0078, "STATUS_INVALID_SID"),
+    (0xc000_0079, "STATUS_INVALID_SECURITY_DESCR"),
+    (0xc000_007a, "STATUS_PROCEDURE_NOT_FOUND"),
+    (0xc000_007b, "STATUS_INVALID_IMAGE_FORMAT"),
+    (0xc000_007c, "STATUS_NO_TOKEN"),
+    (0xc000_007d, "STATUS_BAD_INHERITANCE_ACL"),
+    (0xc000_007e, "STATUS_RANGE_NOT_LOCKED"),
+    (0xc000_007f, "STATUS_DISK_FULL"),
+    (0xc000_0080, "STATUS_SERVER_DISABLED"),
+    (0xc000_0081, "STATUS_SERVER_NOT_DISABLED"),
+    (0xc000_0082, "STATUS_TOO_MANY_GUIDS_REQUESTED"),
+    (0xc000_0083, "STATUS_GUIDS_EXHAUSTED"),
+    (0xc000_0084, "STATUS_INVALID_ID_AUTHORITY"),
+    (0xc000_0085, "STATUS_AGENTS_EXHAUSTED"),
+    (0xc000_0086, "STATUS_INVALID_VOLUME_LABEL"),
+    (0xc000_0087, "STATUS_SECTION_NOT_EXTENDED"),
+    (0xc000_0088, "STATUS_NOT_MAPPED_DATA"),
+    (0xc000_0089, "STATUS_RESOURCE_DATA_NOT_FOUND"),
+    (0xc000_008a, "STATUS_RESOURCE_TYPE_NOT_FOUND"),
+    (0xc000_008b, "STATUS_RESOURCE_NAME_NOT_FOUND"),
+    (0xc000_008c, "STATUS_ARRAY_BOUNDS_EXCEEDED"),
+    (0xc000_008d, "STATUS_FLOAT_DENORMAL_OPERAND"),
+    (0xc000_008e, "STATUS_FLOAT_DIVIDE_BY_ZERO"),
+    (0xc000_008f, "STATUS_FLOAT_INEXACT_RESULT"),
+    (0xc000_0090, "STATUS_FLOAT_INVALID_OPERATION"),
+    (0xc000_0091, "STATUS_FLOAT_OVERFLOW"),
+    (0xc000_0092, "STATUS_FLOAT_STACK_CHECK"),
+    (0xc000_0093, "STATUS_FLOAT_UNDERFLOW"),
+    (0xc000_0094, "STATUS_INTEGER_DIVIDE_BY_ZERO"),
+    (0xc000_0095, "STATUS_INTEGER_OVERFLOW"),
+    (0xc000_0096, "STATUS_PRIVILEGED_INSTRUCTION"),
+    (0xc000_0097, "STATUS_TOO_MANY_PAGING_FILES"),
+    (0xc000_0098, "STATUS_FILE_INVALID"),
+    (0xc000_0099, "STATUS_ALLOTTED_SPACE_EXCEEDED"),
+    (0xc000_009a, "STATUS_INSUFFICIENT_RESOURCES"),
+    (0xc000_009b, "STATUS_DFS_EXIT_PATH_FOUND"),
+    (0xc000_009c, "STATUS_DEVICE_DATA_ERROR"),
+    (0xc000_009d, "STATUS_DEVICE_NOT_CONNECTED"),
+    (0xc000_009e, "STATUS_DEVICE_POWER_FAILURE"),
+    (0xc000_009f, "STATUS_FREE_VM_NOT_AT_BASE"),
+    (0xc000_00a0, "STATUS_MEMORY_NOT_ALLOCATED"),
+    (0xc000_00a1, "STATUS_WORKING_SET_QUOTA"),
+    (0xc000_00a2, "STATUS_MEDIA_WRITE_PROTECTED"),
+    (0xc000_00a3, "STATUS_DEVICE_NOT_READY"),
+    (0xc000_00a4, "STATUS_INVALID_GROUP_ATTRIBUTES"),
+    (0xc000_00a5, "STATUS_BAD_IMPERSONATION_LEVEL"),
+    (0xc000_00a6, "STATUS_CANT_OPEN_ANONYMOUS"),
+    (0xc000_00a7, "STATUS_BAD_VALIDATION_CLASS"),
+    (0xc000_00a8, "STATUS_BAD_TOKEN_TYPE"),
+    (0xc000_00a9, "STATUS_BAD_MASTER_BOOT_RECORD"),
+    (0xc000_00aa, "STATUS_INSTRUCTION_MISALIGNMENT"),
+    (0xc000_00ab, "STATUS_INSTANCE_NOT_AVAILABLE"),
+    (0xc000_00ac, "STATUS_PIPE_NOT_AVAILABLE"),
+    (0xc000_00ad, "STATUS_INVALID_PIPE_STATE"),
+    (0xc000_00ae, "STATUS_PIPE_BUSY"),
+    (0xc000_00af, "STATUS_ILLEGAL_FUNCTION"),
+    (0xc000_00b0, "STATUS_PIPE_DISCONNECTED"),
+    (0xc000_00b1, "STATUS_PIPE_CLOSING"),
+    (0xc000_00b2, "STATUS_PIPE_CONNECTED"),
+    (0xc000_00b3, "STATUS_PIPE_LISTENING"),
+    (0xc000_00b4, "STATUS_INVALID_READ_MODE"),
+    (0xc000_00b5, "STATUS_IO_TIMEOUT"),
+    (0xc000_00b6, "STATUS_FILE_FORCED_CLOSED"),
+    (0xc000_00b7, "STATUS_PROFILING_NOT_STARTED"),
+    (0xc000_00b8, "STATUS_PROFILING_NOT_STOPPED"),
+    (0xc000_00b9, "STATUS_COULD_NOT_INTERPRET"),
+    (0xc000_00ba, "STATUS_FILE_IS_A_DIRECTORY"),
+    (0xc000_00bb, "STATUS_NOT_SUPPORTED"),
+    (0xc000_00bc, "STATUS_REMOTE_NOT_LISTENING"),
+    (0xc000_00bd, "STATUS_DUPLICATE_NAME"),
+    (0xc000_00be, "STATUS_BAD_NETWORK_PATH"),
+    (0xc000_00bf, "STATUS_NETWORK_BUSY"),
+    (0xc000_00c0, "STATUS_DEVICE_DOES_NOT_EXIST"),
+    (0xc000_00c1, "STATUS_TOO_MANY_COMMANDS"),
+    (0xc000_00c2, "STATUS_ADAPTER_HARDWARE_ERROR"),
+    (0xc000_00c3, "STATUS_INVALID_NETWORK_RESPONSE"),
+    (0xc000_00c4, "STATUS_UNEXPECTED_NETWORK_ERROR"),
+    (0xc000_00c5, "STATUS_BAD_REMOTE_ADAPTER"),
+    (0xc000_00c6, "STATUS_PRINT_QUEUE_FULL"),
+    (0xc000_00c7, "STATUS_NO_SPOOL_SPACE"),
+    (0xc000_00c8, "STATUS_PRINT_CANCELLED"),
+    (0xc000_00c9, "STATUS_NETWORK_NAME_DELETED"),
+    (0xc000_00ca, "STATUS_NETWORK_ACCESS_DENIED"),
+    (0xc000_00cb, "STATUS_BAD_DEVICE_TYPE"),
+    (0xc000_00cc, "STATUS_BAD_NETWORK_NAME"),
+    (0xc000_00cd, "STATUS_TOO_MANY_NAMES"),
+    (0xc000_00ce, "STATUS_TOO_MANY_SESSIONS"),
+    (0xc000_00cf, "STATUS_SHARING_PAUSED"),
+    (0xc000_00d0, "STATUS_REQUEST_NOT_ACCEPTED"),
+    (0xc000_00d1, "STATUS_REDIRECTOR_PAUSED"),
+    (0xc000_00d2, "STATUS_NET_WRITE_FAULT"),
+    (0xc000_00d3, "STATUS_PROFILING_AT_LIMIT"),
+    (0xc000_00d4, "STATUS_NOT_SAME_DEVICE"),
+    (0xc000_00d5, "STATUS_FILE_RENAMED"),
+    (0xc000_00d6, "STATUS_VIRTUAL_CIRCUIT_CLOSED"),
+    (0xc000_00d7, "STATUS_NO_SECURITY_ON_OBJECT"),
+    (0xc000_00d8, "STATUS_CANT_WAIT"),
+    (0xc000_00d9, "STATUS_PIPE_EMPTY"),
+    (0xc000_00da, "STATUS_CANT_ACCESS_DOMAIN_INFO"),
+    (0xc000_00db, "STATUS_CANT_TERMINATE_SELF"),
+    (0xc000_00dc, "STATUS_INVALID_SERVER_STATE"),
+    (0xc000_00dd, "STATUS_INVALID_DOMAIN_STATE"),
+    (0xc000_00de, "STATUS_INVALID_DOMAIN_ROLE"),
+    (0xc000_00df, "STATUS_NO_SUCH_DOMAIN"),
+    (0xc000_00e0, "STATUS_DOMAIN_EXISTS"),
+    (0xc000_00e1, "STATUS_DOMAIN_LIMIT_EXCEEDED"),
+    (0xc000_00e2, "STATUS_OPLOCK_NOT_GRANTED"),
+    (0xc000_00e3, "STATUS_INVALID_OPLOCK_PROTOCOL"),
+    (0xc000_00e4, "STATUS_INTERNAL_DB_CORRUPTION"),
+    (0xc000_00e5, "STATUS_INTERNAL_ERROR"),
+    (0xc000_00e6, "STATUS_GENERIC_NOT_MAPPED"),
+    (0xc000_00e7, "STATUS_BAD_DESCRIPTOR_FORMAT"),
+    (0xc000_00e8, "STATUS_INVALID_USER_BUFFER"),
+    (0xc000_00e9, "STATUS_UNEXPECTED_IO_ERROR"),
+    (0xc000_00ea, "STATUS_UNEXPECTED_MM_CREATE_ERR"),
+    (0xc000_00eb, "STATUS_UNEXPECTED_MM_MAP_ERROR"),
+    (0xc000_00ec, "STATUS_UNEXPECTED_MM_EXTEND_ERR"),
+    (0xc000_00ed, "STATUS_NOT_LOGON_PROCESS"),
+    (0xc000_00ee, "STATUS_LOGON_SESSION_EXISTS"),
+    (0xc000_00ef, "STATUS_INVALID_PARAMETER_1"),
+    (0xc000_00f0, "STATUS_INVALID_PARAMETER_2"),
+    (0xc000_00f1, "STATUS_INVALID_PARAMETER_3"),
+    (0xc000_00f2, "STATUS_INVALID_PARAMETER_4"),
+    (0xc000_00f3, "STATUS_INVALID_PARAMETER_5"),
+    (0xc000_00f4, "STATUS_INVALID_PARAMETER_6"),
+    (0xc000_00f5, "STATUS_INVALID_PARAMETER_7"),
+    (0xc000_00f6, "STATUS_INVALID_PARAMETER_8"),
+    (0xc000_00f7, "STATUS_INVALID_PARAMETER_9"),
+    (0xc000_00f8, "STATUS_INVALID_PARAMETER_10"),
+    (0xc000_00f9, "STATUS_INVALID_PARAMETER_11"),
+    (0xc000_00fa, "STATUS_INVALID_PARAMETER_12"),
+    (0xc000_00fb, "STATUS_REDIRECTOR_NOT_STARTED"),
+    (0xc000_00fc, "STATUS_REDIRECTOR_STARTED"),
+    (0xc000_00fd, "STATUS_STACK_OVERFLOW"),
+    (0xc000_00fe, "STATUS_NO_SUCH_PACKAGE"),
+    (0xc000_00ff, "STATUS_BAD_FUNCTION_TABLE"),
+    (0xc000_0100, "STATUS_VARIABLE_NOT_FOUND"),
+    (0xc000_0101, "STATUS_DIRECTORY_NOT_EMPTY"),
+    (0xc000_0102, "STATUS_FILE_CORRUPT_ERROR"),
+    (0xc000_0103, "STATUS_NOT_A_DIRECTORY"),
+    (0xc000_0104, "STATUS_BAD_LOGON_SESSION_STATE"),
+    (0xc000_0105, "STATUS_LOGON_SESSION_COLLISION"),
+    (0xc000_0106, "STATUS_NAME_TOO_LONG"),
+    (0xc000_0107, "STATUS_FILES_OPEN"),
+    (0xc000_0108, "STATUS_CONNECTION_IN_USE"),
+    (0xc000_0109, "STATUS_MESSAGE_NOT_FOUND"),
+    (0xc000_010a, "STATUS_PROCESS_IS_TERMINATING"),
+    (0xc000_010b, "STATUS_INVALID_LOGON_TYPE"),
+    (0xc000_010c, "STATUS_NO_GUID_TRANSLATION"),
+    (0xc000_010d, "STATUS_CANNOT_IMPERSONATE"),
+    (0xc000_010e, "STATUS_IMAGE_ALREADY_LOADED"),
+    (0xc000_010f, "STATUS_ABIOS_NOT_PRESENT"),
+    (0xc000_0110, "STATUS_ABIOS_LID_NOT_EXIST"),
+    (0xc000_0111, "STATUS_ABIOS_LID_ALREADY_OWNED"),
+    (0xc000_0112, "STATUS_ABIOS_NOT_LID_OWNER"),
+    (0xc000_0113, "STATUS_ABIOS_INVALID_COMMAND"),
+    (0xc000_0114, "STATUS_ABIOS_INVALID_LID"),
+    (0xc000_0115, "STATUS_ABIOS_SELECTOR_NOT_AVAILABLE"),
+    (0xc000_0116, "STATUS_ABIOS_INVALID_SELECTOR"),
+    (0xc000_0117, "STATUS_NO_LDT"),
+    (0xc000_0118, "STATUS_INVALID_LDT_SIZE"),
+    (0xc000_0119, "STATUS_INVALID_LDT_OFFSET"),
+    (0xc000_011a, "STATUS_INVALID_LDT_DESCRIPTOR"),
+    (0xc000_011b, "STATUS_INVALID_IMAGE_NE_FORMAT"),
+    (0xc000_011c, "STATUS_RXACT_INVALID_STATE"),
+    (0xc000_011d, "STATUS_RXACT_COMMIT_FAILURE"),
+    (0xc000_011e, "STATUS_MAPPED_FILE_SIZE_ZERO"),
+    (0xc000_011f, "STATUS_TOO_MANY_OPENED_FILES"),
+    (0xc000_0120, "STATUS_CANCELLED"),
+    (0xc000_0121, "STATUS_CANNOT_DELETE"),
+    (0xc000_0122, "STATUS_INVALID_COMPUTER_NAME"),
+    (0xc000_0123, "STATUS_FILE_DELETED"),
+    (0xc000_0124, "STATUS_SPECIAL_ACCOUNT"),
+    (0xc000_0125, "STATUS_SPECIAL_GROUP"),
+    (0xc000_0126, "STATUS_SPECIAL_USER"),
+    (0xc000_0127, "STATUS_MEMBERS_PRIMARY_GROUP"),
+    (0xc000_0128, "STATUS_FILE_CLOSED"),
+    (0xc000_0129, "STATUS_TOO_MANY_THREADS"),
+    (0xc000_012a, "STATUS_THREAD_NOT_IN_PROCESS"),
+    (0xc000_012b, "STATUS_TOKEN_ALREADY_IN_USE"),
+    (0xc000_012c, "STATUS_PAGEFILE_QUOTA_EXCEEDED"),
+    (0xc000_012d, "STATUS_COMMITMENT_LIMIT"),
+    (0xc000_012e, "STATUS_INVALID_IMAGE_LE_FORMAT"),
+    (0xc000_012f, "STATUS_INVALID_IMAGE_NOT_MZ"),
+    (0xc000_0130, "STATUS_INVALID_IMAGE_PROTECT"),
+    (0xc000_0131, "STATUS_INVALID_IMAGE_WIN_16"),
+    (0xc000_0132, "STATUS_LOGON_SERVER_CONFLICT"),
+    (0xc000_0133, "STATUS_TIME_DIFFERENCE_AT_DC"),
+    (0xc000_0134, "STATUS_SYNCHRONIZATION_REQUIRED"),
+    (0xc000_0135, "STATUS_DLL_NOT_FOUND"),
+    (0xc000_0136, "STATUS_OPEN_FAILED"),
+    (0xc000_0137, "STATUS_IO_PRIVILEGE_FAILED"),
+    (0xc000_0138, "STATUS_ORDINAL_NOT_FOUND"),
+    (0xc000_0139, "STATUS_ENTRYPOINT_NOT_FOUND"),
+    (0xc000_013a, "STATUS_CONTROL_C_EXIT"),
+    (0xc000_013b, "STATUS_LOCAL_DISCONNECT"),
+    (0xc000_013c, "STATUS_REMOTE_DISCONNECT"),
+    (0xc000_013d, "STATUS_REMOTE_RESOURCES"),
+    (0xc000_013e, "STATUS_LINK_FAILED"),
+    (0xc000_013f, "STATUS_LINK_TIMEOUT"),
+    (0xc000_0140, "STATUS_INVALID_CONNECTION"),
+    (0xc000_0141, "STATUS_INVALID_ADDRESS"),
+    (0xc000_0142, "STATUS_DLL_INIT_FAILED"),
+    (0xc000_0143, "STATUS_MISSING_SYSTEMFILE"),
+    (0xc000_0144, "STATUS_UNHANDLED_EXCEPTION"),
+    (0xc000_0145, "STATUS_APP_INIT_FAILURE"),
+    (0xc000_0146, "STATUS_PAGEFILE_CREATE_FAILED"),
+    (0xc000_0147, "STATUS_NO_PAGEFILE"),
+    (0xc000_0148, "STATUS_INVALID_LEVEL"),
+    (0xc000_0149, "STATUS_WRONG_PASSWORD_CORE"),
+    (0xc000_014a, "STATUS_ILLEGAL_FLOAT_CONTEXT"),
+    (0xc000_014b, "STATUS_PIPE_BROKEN"),
+    (0xc000_014c, "STATUS_REGISTRY_CORRUPT"),
+    (0xc000_014d, "STATUS_REGISTRY_IO_FAILED"),
+    (0xc000_014e, "STATUS_NO_EVENT_PAIR"),
+    (0xc000_014f, "STATUS_UNRECOGNIZED_VOLUME"),
+    (0xc000_0150, "STATUS_SERIAL_NO_DEVICE_INITED"),
+    (0xc000_0151, "STATUS_NO_SUCH_ALIAS"),
+    (0xc000_0152, "STATUS_MEMBER_NOT_IN_ALIAS"),
+    (0xc000_0153, "STATUS_MEMBER_IN_ALIAS"),
+    (0xc000_0154, "STATUS_ALIAS_EXISTS"),
+    (0xc000_0155, "STATUS_LOGON_NOT_GRANTED"),
+    (0xc000_0156, "STATUS_TOO_MANY_SECRETS"),
+    (0xc000_0157, "STATUS_SECRET_TOO_LONG"),
+    (0xc000_0158, "STATUS_INTERNAL_DB_ERROR"),
+    (0xc000_0159, "STATUS_FULLSCREEN_MODE"),
+    (0xc000_015a, "STATUS_TOO_MANY_CONTEXT_IDS"),
+    (0xc000_015c, "STATUS_NOT_REGISTRY_FILE"),
+    (0xc000_015d, "STATUS_NT_CROSS_ENCRYPTION_REQUIRED"),
+    (0xc000_015e, "STATUS_DOMAIN_CTRLR_CONFIG_ERROR"),
+    (0xc000_015f, "STATUS_FT_MISSING_MEMBER"),
+    (0xc000_0160, "STATUS_ILL_FORMED_SERVICE_ENTRY"),
+    (0xc000_0161, "STATUS_ILLEGAL_CHARACTER"),
+    (0xc000_0162, "STATUS_UNMAPPABLE_CHARACTER"),
+    (0xc000_0163, "STATUS_UNDEFINED_CHARACTER"),
+    (0xc000_0164, "STATUS_FLOPPY_VOLUME"),
+    (0xc000_0165, "STATUS_FLOPPY_ID_MARK_NOT_FOUND"),
+    (0xc000_0166, "STATUS_FLOPPY_WRONG_CYLINDER"),
+    (0xc000_0167, "STATUS_FLOPPY_UNKNOWN_ERROR"),
+    (0xc000_0168, "STATUS_FLOPPY_BAD_REGISTERS"),
+    (0xc000_0169, "STATUS_DISK_RECALIBRATE_FAILED"),
+    (0xc000_016a, "STATUS_DISK_OPERATION_FAILED"),
+    (0xc000_016b, "STATUS_DISK_RESET_FAILED"),
+    (0xc000_016c, "STATUS_SHARED_IRQ_BUSY"),
+    (0xc000_016d, "STATUS_FT_ORPHANING"),
+    (0xc000_016e, "STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT"),
+    (0xc000_0172, "STATUS_PARTITION_FAILURE"),
+    (0xc000_0173, "STATUS_INVALID_BLOCK_LENGTH"),
+    (0xc000_0174, "STATUS_DEVICE_NOT_PARTITIONED"),
+    (0xc000_0175, "STATUS_UNABLE_TO_LOCK_MEDIA"),
+    (0xc000_0176, "STATUS_UNABLE_TO_UNLOAD_MEDIA"),
+    (0xc000_0177, "STATUS_EOM_OVERFLOW"),
+    (0xc000_0178, "STATUS_NO_MEDIA"),
+    (0xc000_017a, "STATUS_NO_SUCH_MEMBER"),
+    (0xc000_017b, "STATUS_INVALID_MEMBER"),
+    (0xc000_017c, "STATUS_KEY_DELETED"),
+    (0xc000_017d, "STATUS_NO_LOG_SPACE"),
+    (0xc000_017e, "STATUS_TOO_MANY_SIDS"),
+    (0xc000_017f, "STATUS_LM_CROSS_ENCRYPTION_REQUIRED"),
+    (0xc000_0180, "STATUS_KEY_HAS_CHILDREN"),
+    (0xc000_0181, "STATUS_CHILD_MUST_BE_VOLATILE"),
+    (0xc000_0182, "STATUS_DEVICE_CONFIGURATION_ERROR"),
+    (0xc000_0183, "STATUS_DRIVER_INTERNAL_ERROR"),
+    (0xc000_0184, "STATUS_INVALID_DEVICE_STATE"),
+    (0xc000_0185, "STATUS_IO_DEVICE_ERROR"),
+    (0xc000_0186, "STATUS_DEVICE_PROTOCOL_ERROR"),
+    (0xc000_0187, "STATUS_BACKUP_CONTROLLER"),
+    (0xc000_0188, "STATUS_LOG_FILE_FULL"),
+    (0xc000_0189, "STATUS_TOO_LATE"),
+    (0xc000_018a, "STATUS_NO_TRUST_LSA_SECRET"),
+    (0xc000_018b, "STATUS_NO_TRUST_SAM_ACCOUNT"),
+    (0xc000_018c, "STATUS_TRUSTED_DOMAIN_FAILURE"),
+    (0xc000_018d, "STATUS_TRUSTED_RELATIONSHIP_FAILURE"),
+    (0xc000_018e, "STATUS_EVENTLOG_FILE_CORRUPT"),
+    (0xc000_018f, "STATUS_EVENTLOG_CANT_START"),
+    (0xc000_0190, "STATUS_TRUST_FAILURE"),
+    (0xc000_0191, "STATUS_MUTANT_LIMIT_EXCEEDED"),
+    (0xc000_0192, "STATUS_NETLOGON_NOT_STARTED"),
+    (0xc000_0194, "STATUS_POSSIBLE_DEADLOCK"),
+    (0xc000_0195, "STATUS_NETWORK_CREDENTIAL_CONFLICT"),
+    (0xc000_0196, "STATUS_REMOTE_SESSION_LIMIT"),
+    (0xc000_0197, "STATUS_EVENTLOG_FILE_CHANGED"),
+    (0xc000_0198, "STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT"),
+    (0xc000_0199, "STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT"),
+    (0xc000_019a, "STATUS_NOLOGON_SERVER_TRUST_ACCOUNT"),
+    (0xc000_019b, "STATUS_DOMAIN_TRUST_INCONSISTENT"),
+    (0xc000_019c, "STATUS_FS_DRIVER_REQUIRED"),
+    (0xc000_019d, "STATUS_IMAGE_ALREADY_LOADED_AS_DLL"),
+    (0xc000_019e, "STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING"),
+    (0xc000_019f, "STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME"),
+    (0xc000_01a0, "STATUS_SECURITY_STREAM_IS_INCONSISTENT"),
+    (0xc000_01a1, "STATUS_INVALID_LOCK_RANGE"),
+    (0xc000_01a2, "STATUS_INVALID_ACE_CONDITION"),
+    (0xc000_01a3, "STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT"),
+    (0xc000_01a4, "STATUS_NOTIFICATION_GUID_ALREADY_DEFINED"),
+    (0xc000_01a5, "STATUS_INVALID_EXCEPTION_HANDLER"),
+    (0xc000_01a6, "STATUS_DUPLICATE_PRIVILEGES"),
+    (0xc000_01a7, "STATUS_NOT_ALLOWED_ON_SYSTEM_FILE"),
+    (0xc000_01a8, "STATUS_REPAIR_NEEDED"),
+    (0xc000_01a9, "STATUS_QUOTA_NOT_ENABLED"),
+    (0xc000_01aa, "STATUS_NO_APPLICATION_PACKAGE"),
+    (0xc000_01ab, "STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS"),
+    (0xc000_01ac, "STATUS_NOT_SAME_OBJECT"),
+    (0xc000_01ad, "STATUS_FATAL_MEMORY_EXHAUSTION"),
+    (0xc000_01ae, "STATUS_ERROR_PROCESS_NOT_IN_JOB"),
+    (0xc000_01af, "STATUS_CPU_SET_INVALID"),
+    (0xc000_01b0, "STATUS_IO_DEVICE_INVALID_DATA"),
+    (0xc000_01b1, "STATUS_IO_UNALIGNED_WRITE"),
+    (0xc000_01b2, "STATUS_CONTROL_STACK_VIOLATION"),
+    (0xc000_01b3, "STATUS_WEAK_WHFBKEY_BLOCKED"),
+    (0xc000_01b4, "STATUS_SERVER_TRANSPORT_CONFLICT"),
+    (0xc000_01b5, "STATUS_CERTIFICATE_VALIDATION_PREFERENCE_CONFLICT"),
+    (0xc000_0201, "STATUS_NETWORK_OPEN_RESTRICTION"),
+    (0xc000_0202, "STATUS_NO_USER_SESSION_KEY"),
+    (0xc000_0203, "STATUS_USER_SESSION_DELETED"),
+    (0xc000_0204, "STATUS_RESOURCE_LANG_NOT_FOUND"),
+    (0xc000_0205, "STATUS_INSUFF_SERVER_RESOURCES"),
+    (0xc000_0206, "STATUS_INVALID_BUFFER_SIZE"),
+    (0xc000_0207, "STATUS_INVALID_ADDRESS_COMPONENT"),
+    (0xc000_0208, "STATUS_INVALID_ADDRESS_WILDCARD"),
+    (0xc000_0209, "STATUS_TOO_MANY_ADDRESSES"),
+    (0xc000_020a, "STATUS_ADDRESS_ALREADY_EXISTS"),
+    (0xc000_020b, "STATUS_ADDRESS_CLOSED"),
+    (0xc000_020c, "STATUS_CONNECTION_DISCONNECTED"),
+    (0xc000_020d, "STATUS_CONNECTION_RESET"),
+    (0xc000_020e, "STATUS_TOO_MANY_NODES"),
+    (0xc000_020f, "STATUS_TRANSACTION_ABORTED"),
+    (0xc000_0210, "STATUS_TRANSACTION_TIMED_OUT"),
+    (0xc000_0211, "STATUS_TRANSACTION_NO_RELEASE"),
+    (0xc000_0212, "STATUS_TRANSACTION_NO_MATCH"),
+    (0xc000_0213, "STATUS_TRANSACTION_RESPONDED"),
+    (0xc000_0214, "STATUS_TRANSACTION_INVALID_ID"),
+    (0xc000_0215, "STATUS_TRANSACTION_INVALID_TYPE"),
+    (0xc000_0216, "STATUS_NOT_SERVER_SESSION"),
+    (0xc000_0217, "STATUS_NOT_CLIENT_SESSION"),
+    (0xc000_0218, "STATUS_CANNOT_LOAD_REGISTRY_FILE"),
+    (0xc000_0219, "STATUS_DEBUG_ATTACH_FAILED"),
+    (0xc000_021a, "STATUS_SYSTEM_PROCESS_TERMINATED"),
+    (0xc000_021b, "STATUS_DATA_NOT_ACCEPTED"),
+    (0xc000_021c, "STATUS_NO_BROWSER_SERVERS_FOUND"),
+    (0xc000_021d, "STATUS_VDM_HARD_ERROR"),
+    (0xc000_021e, "STATUS_DRIVER_CANCEL_TIMEOUT"),
+    (0xc000_021f, "STATUS_REPLY_MESSAGE_MISMATCH"),
+    (0xc000_0220, "STATUS_MAPPED_ALIGNMENT"),
+    (0xc000_0221, "STATUS_IMAGE_CHECKSUM_MISMATCH"),
+    (0xc000_0222, "STATUS_LOST_WRITEBEHIND_DATA"),
+    (0xc000_0223, "STATUS_CLIENT_SERVER_PARAMETERS_INVALID"),
+    (0xc000_0225, "STATUS_NOT_FOUND"),
+    (0xc000_0226, "STATUS_NOT_TINY_STREAM"),
+    (0xc000_0227, "STATUS_RECOVERY_FAILURE"),
+    (0xc000_0228, "STATUS_STACK_OVERFLOW_READ"),
+    (0xc000_0229, "STATUS_FAIL_CHECK"),
+    (0xc000_022a, "STATUS_DUPLICATE_OBJECTID"),
+    (0xc000_022b, "STATUS_OBJECTID_EXISTS"),
+    (0xc000_022c, "STATUS_CONVERT_TO_LARGE"),
+    (0xc000_022d, "STATUS_RETRY"),
+    (0xc000_022e, "STATUS_FOUND_OUT_OF_SCOPE"),
+    (0xc000_022f, "STATUS_ALLOCATE_BUCKET"),
+    (0xc000_0230, "STATUS_PROPSET_NOT_FOUND"),
+    (0xc000_0231, "STATUS_MARSHALL_OVERFLOW"),
+    (0xc000_0232, "STATUS_INVALID_VARIANT"),
+    (0xc000_0233, "STATUS_DOMAIN_CONTROLLER_NOT_FOUND"),
+    (0xc000_0235, "STATUS_HANDLE_NOT_CLOSABLE"),
+    (0xc000_0236, "STATUS_CONNECTION_REFUSED"),
+    (0xc000_0237, "STATUS_GRACEFUL_DISCONNECT"),
+    (0xc000_0238, "STATUS_ADDRESS_ALREADY_ASSOCIATED"),
+    (0xc000_0239, "STATUS_ADDRESS_NOT_ASSOCIATED"),
+    (0xc000_023a, "STATUS_CONNECTION_INVALID"),
+    (0xc000_023b, "STATUS_CONNECTION_ACTIVE"),
+    (0xc000_023c, "STATUS_NETWORK_UNREACHABLE"),
+    (0xc000_023d, "STATUS_HOST_UNREACHABLE"),
+    (0xc000_023e, "STATUS_PROTOCOL_UNREACHABLE"),
+    (0xc000_023f, "STATUS_PORT_UNREACHABLE"),
+    (0xc000_0240, "STATUS_REQUEST_ABORTED"),
+    (0xc000_0241, "STATUS_CONNECTION_ABORTED"),
+    (0xc000_0242, "STATUS_BAD_COMPRESSION_BUFFER"),
+    (0xc000_0243, "STATUS_USER_MAPPED_FILE"),
+    (0xc000_0244, "STATUS_AUDIT_FAILED"),
+    (0xc000_0245, "STATUS_TIMER_RESOLUTION_NOT_SET"),
+    (0xc000_0246, "STATUS_CONNECTION_COUNT_LIMIT"),
+    (0xc000_0247, "STATUS_LOGIN_TIME_RESTRICTION"),
+    (0xc000_0248, "STATUS_LOGIN_WKSTA_RESTRICTION"),
+    (0xc000_0249, "STATUS_IMAGE_MP_UP_MISMATCH"),
+    (0xc000_0250, "STATUS_INSUFFICIENT_LOGON_INFO"),
+    (0xc000_0251, "STATUS_BAD_DLL_ENTRYPOINT"),
+    (0xc000_0252, "STATUS_BAD_SERVICE_ENTRYPOINT"),
+    (0xc000_0253, "STATUS_LPC_REPLY_LOST"),
+    (0xc000_0254, "STATUS_IP_ADDRESS_CONFLICT1"),
+    (0xc000_0255, "STATUS_IP_ADDRESS_CONFLICT2"),
+    (0xc000_0256, "STATUS_REGISTRY_QUOTA_LIMIT"),
+    (0xc000_0257, "STATUS_PATH_NOT_COVERED"),
+    (0xc000_0258, "STATUS_NO_CALLBACK_ACTIVE"),
+    (0xc000_0259, "STATUS_LICENSE_QUOTA_EXCEEDED"),
+    (0xc000_025a, "STATUS_PWD_TOO_SHORT"),
+    (0xc000_025b, "STATUS_PWD_TOO_RECENT"),
+    (0xc000_025c, "STATUS_PWD_HISTORY_CONFLICT"),
+    (0xc000_025e, "STATUS_PLUGPLAY_NO_DEVICE"),
+    (0xc000_025f, "STATUS_UNSUPPORTED_COMPRESSION"),
+    (0xc000_0260, "STATUS_INVALID_HW_PROFILE"),
+    (0xc000_0261, "STATUS_INVALID_PLUGPLAY_DEVICE_PATH"),
+    (0xc000_0262, "STATUS_DRIVER_ORDINAL_NOT_FOUND"),
+    (0xc000_0263, "STATUS_DRIVER_ENTRYPOINT_NOT_FOUND"),
+    (0xc000_0264, "STATUS_RESOURCE_NOT_OWNED"),
+    (0xc000_0265, "STATUS_TOO_MANY_LINKS"),
+    (0xc000_0266, "STATUS_QUOTA_LIST_INCONSISTENT"),
+    (0xc000_0267, "STATUS_FILE_IS_OFFLINE"),
+    (0xc000_0268, "STATUS_EVALUATION_EXPIRATION"),
+    (0xc000_0269, "STATUS_ILLEGAL_DLL_RELOCATION"),
+    (0xc000_026a, "STATUS_LICENSE_VIOLATION"),
+    (0xc000_026b, "STATUS_DLL_INIT_FAILED_LOGOFF"),
+    (0xc000_026c, "STATUS_DRIVER_UNABLE_TO_LOAD"),
+    (0xc000_026d, "STATUS_DFS_UNAVAILABLE"),
+    (0xc000_026e, "STATUS_VOLUME_DISMOUNTED"),
+    (0xc000_026f, "STATUS_WX86_INTERNAL_ERROR"),
+    (0xc000_0270, "STATUS_WX86_FLOAT_STACK_CHECK"),
+    (0xc000_0271, "STATUS_VALIDATE_CONTINUE"),
+    (0xc000_0272, "STATUS_NO_MATCH"),
+    (0xc000_0273, "STATUS_NO_MORE_MATCHES"),
+    (0xc000_0275, "STATUS_NOT_A_REPARSE_POINT"),
+    (0xc000_0276, "STATUS_IO_REPARSE_TAG_INVALID"),
+    (0xc000_0277, "STATUS_IO_REPARSE_TAG_MISMATCH"),
+    (0xc000_0278, "STATUS_IO_REPARSE_DATA_INVALID"),
+    (0xc000_0279, "STATUS_IO_REPARSE_TAG_NOT_HANDLED"),
+    (0xc000_027a, "STATUS_PWD_TOO_LONG"),
+    (0xc000_027b, "STATUS_STOWED_EXCEPTION"),
+    (0xc000_027c, "STATUS_CONTEXT_STOWED_EXCEPTION"),
+    (0xc000_0280, "STATUS_REPARSE_POINT_NOT_RESOLVED"),
+    (0xc000_0281, "STATUS_DIRECTORY_IS_A_REPARSE_POINT"),
+    (0xc000_0282, "STATUS_RANGE_LIST_CONFLICT"),
+    (0xc000_0283, "STATUS_SOURCE_ELEMENT_EMPTY"),
+    (0xc000_0284, "STATUS_DESTINATION_ELEMENT_FULL"),
+    (0xc000_0285, "STATUS_ILLEGAL_ELEMENT_ADDRESS"),
+    (0xc000_0286, "STATUS_MAGAZINE_NOT_PRESENT"),
+    (0xc000_0287, "STATUS_REINITIALIZATION_NEEDED"),
+    (0xc000_028a, "STATUS_ENCRYPTION_FAILED"),
+    (0xc000_028b, "STATUS_DECRYPTION_FAILED"),
+    (0xc000_028c, "STATUS_RANGE_NOT_FOUND"),
+    (0xc000_028d, "STATUS_NO_RECOVERY_POLICY"),
+    (0xc000_028e, "STATUS_NO_EFS"),
+    (0xc000_028f, "STATUS_WRONG_EFS"),
+    (0xc000_0290, "STATUS_NO_USER_KEYS"),
+    (0xc000_0291, "STATUS_FILE_NOT_ENCRYPTED"),
+    (0xc000_0292, "STATUS_NOT_EXPORT_FORMAT"),
+    (0xc000_0293, "STATUS_FILE_ENCRYPTED"),
+    (0xc000_0295, "STATUS_WMI_GUID_NOT_FOUND"),
+    (0xc000_0296, "STATUS_WMI_INSTANCE_NOT_FOUND"),
+    (0xc000_0297, "STATUS_WMI_ITEMID_NOT_FOUND"),
+    (0xc000_0298, "STATUS_WMI_TRY_AGAIN"),
+    (0xc000_0299, "STATUS_SHARED_POLICY"),
+    (0xc000_029a, "STATUS_POLICY_OBJECT_NOT_FOUND"),
+    (0xc000_029b, "STATUS_POLICY_ONLY_IN_DS"),
+    (0xc000_029c, "STATUS_VOLUME_NOT_UPGRADED"),
+    (0xc000_029d, "STATUS_REMOTE_STORAGE_NOT_ACTIVE"),
+    (0xc000_029e, "STATUS_REMOTE_STORAGE_MEDIA_ERROR"),
+    (0xc000_029f, "STATUS_NO_TRACKING_SERVICE"),
+    (0xc000_02a0, "STATUS_SERVER_SID_MISMATCH"),
+    (0xc000_02a1, "STATUS_DS_NO_ATTRIBUTE_OR_VALUE"),
+    (0xc000_02a2, "STATUS_DS_INVALID_ATTRIBUTE_SYNTAX"),
+    (0xc000_02a3, "STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED"),
+    (0xc000_02a4, "STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS"),
+    (0xc000_02a5, "STATUS_DS_BUSY"),
+    (0xc000_02a6, "STATUS_DS_UNAVAILABLE"),
+    (0xc000_02a7, "STATUS_DS_NO_RIDS_ALLOCATED"),
+    (0xc000_02a8, "STATUS_DS_NO_MORE_RIDS"),
+    (0xc000_02a9, "STATUS_DS_INCORRECT_ROLE_OWNER"),
+    (0xc000_02aa, "STATUS_DS_RIDMGR_INIT_ERROR"),
+    (0xc000_02ab, "STATUS_DS_OBJ_CLASS_VIOLATION"),
+    (0xc000_02ac, "STATUS_DS_CANT_ON_NON_LEAF"),
+    (0xc000_02ad, "STATUS_DS_CANT_ON_RDN"),
+    (0xc000_02ae, "STATUS_DS_CANT_MOD_OBJ_CLASS"),
+    (0xc000_02af, "STATUS_DS_CROSS_DOM_MOVE_FAILED"),
+    (0xc000_02b0, "STATUS_DS_GC_NOT_AVAILABLE"),
+    (0xc000_02b1, "STATUS_DIRECTORY_SERVICE_REQUIRED"),
+    (0xc000_02b2, "STATUS_REPARSE_ATTRIBUTE_CONFLICT"),
+    (0xc000_02b3, "STATUS_CANT_ENABLE_DENY_ONLY"),
+    (0xc000_02b4, "STATUS_FLOAT_MULTIPLE_FAULTS"),
+    (0xc000_02b5, "STATUS_FLOAT_MULTIPLE_TRAPS"),
+    (0xc000_02b6, "STATUS_DEVICE_REMOVED"),
+    (0xc000_02b7, "STATUS_JOURNAL_DELETE_IN_PROGRESS"),
+    (0xc000_02b8, "STATUS_JOURNAL_NOT_ACTIVE"),
+    (0xc000_02b9, "STATUS_NOINTERFACE"),
+    (0xc000_02ba, "STATUS_DS_RIDMGR_DISABLED"),
+    (0xc000_02c1, "STATUS_DS_ADMIN_LIMIT_EXCEEDED"),
+    (0xc000_02c2, "STATUS_DRIVER_FAILED_SLEEP"),
+    (0xc000_02c3, "STATUS_MUTUAL_AUTHENTICATION_FAILED"),
+    (0xc000_02c4, "STATUS_CORRUPT_SYSTEM_FILE"),
+    (0xc000_02c5, "STATUS_DATATYPE_MISALIGNMENT_ERROR"),
+    (0xc000_02c6, "STATUS_WMI_READ_ONLY"),
+    (0xc000_02c7, "STATUS_WMI_SET_FAILURE"),
+    (0xc000_02c8, "STATUS_COMMITMENT_MINIMUM"),
+    (0xc000_02c9, "STATUS_REG_NAT_CONSUMPTION"),
+    (0xc000_02ca, "STATUS_TRANSPORT_FULL"),
+    (0xc000_02cb, "STATUS_DS_SAM_INIT_FAILURE"),
+    (0xc000_02cc, "STATUS_ONLY_IF_CONNECTED"),
+    (0xc000_02cd, "STATUS_DS_SENSITIVE_GROUP_VIOLATION"),
+    (0xc000_02ce, "STATUS_PNP_RESTART_ENUMERATION"),
+    (0xc000_02cf, "STATUS_JOURNAL_ENTRY_DELETED"),
+    (0xc000_02d0, "STATUS_DS_CANT_MOD_PRIMARYGROUPID"),
+    (0xc000_02d1, "STATUS_SYSTEM_IMAGE_BAD_SIGNATURE"),
+    (0xc000_02d2, "STATUS_PNP_REBOOT_REQUIRED"),
+    (0xc000_02d3, "STATUS_POWER_STATE_INVALID"),
+    (0xc000_02d4, "STATUS_DS_INVALID_GROUP_TYPE"),
+    (0xc000_02d5, "STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN"),
+    (0xc000_02d6, "STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN"),
+    (0xc000_02d7, "STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0xc000_02d8, "STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0xc000_02d9, "STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0xc000_02da, "STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER"),
+    (0xc000_02db, "STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER"),
+    (0xc000_02dc, "STATUS_DS_HAVE_PRIMARY_MEMBERS"),
+    (0xc000_02dd, "STATUS_WMI_NOT_SUPPORTED"),
+    (0xc000_02de, "STATUS_INSUFFICIENT_POWER"),
+    (0xc000_02df, "STATUS_SAM_NEED_BOOTKEY_PASSWORD"),
+    (0xc000_02e0, "STATUS_SAM_NEED_BOOTKEY_FLOPPY"),
+    (0xc000_02e1, "STATUS_DS_CANT_START"),
+    (0xc000_02e2, "STATUS_DS_INIT_FAILURE"),
+    (0xc000_02e3, "STATUS_SAM_INIT_FAILURE"),
+    (0xc000_02e4, "STATUS_DS_GC_REQUIRED"),
+    (0xc000_02e5, "STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY"),
+    (0xc000_02e6, "STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS"),
+    (0xc000_02e7, "STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"),
+    (0xc000_02e8, "STATUS_MULTIPLE_FAULT_VIOLATION"),
+    (0xc000_02e9, "STATUS_CURRENT_DOMAIN_NOT_ALLOWED"),
+    (0xc000_02ea, "STATUS_CANNOT_MAKE"),
+    (0xc000_02eb, "STATUS_SYSTEM_SHUTDOWN"),
+    (0xc000_02ec, "STATUS_DS_INIT_FAILURE_CONSOLE"),
+    (0xc000_02ed, "STATUS_DS_SAM_INIT_FAILURE_CONSOLE"),
+    (0xc000_02ee, "STATUS_UNFINISHED_CONTEXT_DELETED"),
+    (0xc000_02ef, "STATUS_NO_TGT_REPLY"),
+    (0xc000_02f0, "STATUS_OBJECTID_NOT_FOUND"),
+    (0xc000_02f1, "STATUS_NO_IP_ADDRESSES"),
+    (0xc000_02f2, "STATUS_WRONG_CREDENTIAL_HANDLE"),
+    (0xc000_02f3, "STATUS_CRYPTO_SYSTEM_INVALID"),
+    (0xc000_02f4, "STATUS_MAX_REFERRALS_EXCEEDED"),
+    (0xc000_02f5, "STATUS_MUST_BE_KDC"),
+    (0xc000_02f6, "STATUS_STRONG_CRYPTO_NOT_SUPPORTED"),
+    (0xc000_02f7, "STATUS_TOO_MANY_PRINCIPALS"),
+    (0xc000_02f8, "STATUS_NO_PA_DATA"),
+    (0xc000_02f9, "STATUS_PKINIT_NAME_MISMATCH"),
+    (0xc000_02fa, "STATUS_SMARTCARD_LOGON_REQUIRED"),
+    (0xc000_02fb, "STATUS_KDC_INVALID_REQUEST"),
+    (0xc000_02fc, "STATUS_KDC_UNABLE_TO_REFER"),
+    (0xc000_02fd, "STATUS_KDC_UNKNOWN_ETYPE"),
+    (0xc000_02fe, "STATUS_SHUTDOWN_IN_PROGRESS"),
+    (0xc000_02ff, "STATUS_SERVER_SHUTDOWN_IN_PROGRESS"),
+    (0xc000_0300, "STATUS_NOT_SUPPORTED_ON_SBS"),
+    (0xc000_0301, "STATUS_WMI_GUID_DISCONNECTED"),
+    (0xc000_0302, "STATUS_WMI_ALREADY_DISABLED"),
+    (0xc000_0303, "STATUS_WMI_ALREADY_ENABLED"),
+    (0xc000_0304, "STATUS_MFT_TOO_FRAGMENTED"),
+    (0xc000_0305, "STATUS_COPY_PROTECTION_FAILURE"),
+    (0xc000_0306, "STATUS_CSS_AUTHENTICATION_FAILURE"),
+    (0xc000_0307, "STATUS_CSS_KEY_NOT_PRESENT"),
+    (0xc000_0308, "STATUS_CSS_KEY_NOT_ESTABLISHED"),
+    (0xc000_0309, "STATUS_CSS_SCRAMBLED_SECTOR"),
+    (0xc000_030a, "STATUS_CSS_REGION_MISMATCH"),
+    (0xc000_030b, "STATUS_CSS_RESETS_EXHAUSTED"),
+    (0xc000_030c, "STATUS_PASSWORD_CHANGE_REQUIRED"),
+    (0xc000_030d, "STATUS_LOST_MODE_LOGON_RESTRICTION"),
+    (0xc000_0320, "STATUS_PKINIT_FAILURE"),
+    (0xc000_0321, "STATUS_SMARTCARD_SUBSYSTEM_FAILURE"),
+    (0xc000_0322, "STATUS_NO_KERB_KEY"),
+    (0xc000_0350, "STATUS_HOST_DOWN"),
+    (0xc000_0351, "STATUS_UNSUPPORTED_PREAUTH"),
+    (0xc000_0352, "STATUS_EFS_ALG_BLOB_TOO_BIG"),
+    (0xc000_0353, "STATUS_PORT_NOT_SET"),
+    (0xc000_0354, "STATUS_DEBUGGER_INACTIVE"),
+    (0xc000_0355, "STATUS_DS_VERSION_CHECK_FAILURE"),
+    (0xc000_0356, "STATUS_AUDITING_DISABLED"),
+    (0xc000_0357, "STATUS_PRENT4_MACHINE_ACCOUNT"),
+    (0xc000_0358, "STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0xc000_0359, "STATUS_INVALID_IMAGE_WIN_32"),
+    (0xc000_035a, "STATUS_INVALID_IMAGE_WIN_64"),
+    (0xc000_035b, "STATUS_BAD_BINDINGS"),
+    (0xc000_035c, "STATUS_NETWORK_SESSION_EXPIRED"),
+    (0xc000_035d, "STATUS_APPHELP_BLOCK"),
+    (0xc000_035e, "STATUS_ALL_SIDS_FILTERED"),
+    (0xc000_035f, "STATUS_NOT_SAFE_MODE_DRIVER"),
+    (0xc000_0361, "STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT"),
+    (0xc000_0362, "STATUS_ACCESS_DISABLED_BY_POLICY_PATH"),
+    (0xc000_0363, "STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER"),
+    (0xc000_0364, "STATUS_ACCESS_DISABLED_BY_POLICY_OTHER"),
+    (0xc000_0365, "STATUS_FAILED_DRIVER_ENTRY"),
+    (0xc000_0366, "STATUS_DEVICE_ENUMERATION_ERROR"),
+    (0xc000_0368, "STATUS_MOUNT_POINT_NOT_RESOLVED"),
+    (0xc000_0369, "STATUS_INVALID_DEVICE_OBJECT_PARAMETER"),
+    (0xc000_036a, "STATUS_MCA_OCCURED"),
+    (0xc000_036b, "STATUS_DRIVER_BLOCKED_CRITICAL"),
+    (0xc000_036c, "STATUS_DRIVER_BLOCKED"),
+    (0xc000_036d, "STATUS_DRIVER_DATABASE_ERROR"),
+    (0xc000_036e, "STATUS_SYSTEM_HIVE_TOO_LARGE"),
+    (0xc000_036f, "STATUS_INVALID_IMPORT_OF_NON_DLL"),
+    (0xc000_0371, "STATUS_NO_SECRETS"),
+    (0xc000_0372, "STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY"),
+    (0xc000_0373, "STATUS_FAILED_STACK_SWITCH"),
+    (0xc000_0374, "STATUS_HEAP_CORRUPTION"),
+    (0xc000_0380, "STATUS_SMARTCARD_WRONG_PIN"),
+    (0xc000_0381, "STATUS_SMARTCARD_CARD_BLOCKED"),
+    (0xc000_0382, "STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED"),
+    (0xc000_0383, "STATUS_SMARTCARD_NO_CARD"),
+    (0xc000_0384, "STATUS_SMARTCARD_NO_KEY_CONTAINER"),
+    (0xc000_0385, "STATUS_SMARTCARD_NO_CERTIFICATE"),
+    (0xc000_0386, "STATUS_SMARTCARD_NO_KEYSET"),
+    (0xc000_0387, "STATUS_SMARTCARD_IO_ERROR"),
+    (0xc000_0389, "STATUS_SMARTCARD_CERT_REVOKED"),
+    (0xc000_038a, "STATUS_ISSUING_CA_UNTRUSTED"),
+    (0xc000_038b, "STATUS_REVOCATION_OFFLINE_C"),
+    (0xc000_038c, "STATUS_PKINIT_CLIENT_FAILURE"),
+    (0xc000_038d, "STATUS_SMARTCARD_CERT_EXPIRED"),
+    (0xc000_038e, "STATUS_DRIVER_FAILED_PRIOR_UNLOAD"),
+    (0xc000_038f, "STATUS_SMARTCARD_SILENT_CONTEXT"),
+    (0xc000_0401, "STATUS_PER_USER_TRUST_QUOTA_EXCEEDED"),
+    (0xc000_0402, "STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED"),
+    (0xc000_0403, "STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED"),
+    (0xc000_0404, "STATUS_DS_NAME_NOT_UNIQUE"),
+    (0xc000_0405, "STATUS_DS_DUPLICATE_ID_FOUND"),
+    (0xc000_0406, "STATUS_DS_GROUP_CONVERSION_ERROR"),
+    (0xc000_0407, "STATUS_VOLSNAP_PREPARE_HIBERNATE"),
+    (0xc000_0408, "STATUS_USER2USER_REQUIRED"),
+    (0xc000_0409, "STATUS_STACK_BUFFER_OVERRUN"),
+    (0xc000_040a, "STATUS_NO_S4U_PROT_SUPPORT"),
+    (0xc000_040b, "STATUS_CROSSREALM_DELEGATION_FAILURE"),
+    (0xc000_040c, "STATUS_REVOCATION_OFFLINE_KDC"),
+    (0xc000_040d, "STATUS_ISSUING_CA_UNTRUSTED_KDC"),
+    (0xc000_040e, "STATUS_KDC_CERT_EXPIRED"),
+    (0xc000_040f, "STATUS_KDC_CERT_REVOKED"),
+    (0xc000_0410, "STATUS_PARAMETER_QUOTA_EXCEEDED"),
+    (0xc000_0411, "STATUS_HIBERNATION_FAILURE"),
+    (0xc000_0412, "STATUS_DELAY_LOAD_FAILED"),
+    (0xc000_0414, "STATUS_VDM_DISALLOWED"),
+    (0xc000_0415, "STATUS_HUNG_DISPLAY_DRIVER_THREAD"),
+    (0xc000_0416, "STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE"),
+    (0xc000_0417, "STATUS_INVALID_CRUNTIME_PARAMETER"),
+    (0xc000_0418, "STATUS_NTLM_BLOCKED"),
+    (0xc000_0419, "STATUS_DS_SRC_SID_EXISTS_IN_FOREST"),
+    (0xc000_041a, "STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST"),
+    (0xc000_041b, "STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST"),
+    (0xc000_041c, "STATUS_INVALID_USER_PRINCIPAL_NAME"),
+    (0xc000_041d, "STATUS_FATAL_USER_CALLBACK_EXCEPTION"),
+    (0xc000_0420, "STATUS_ASSERTION_FAILURE"),
+    (0xc000_0421, "STATUS_VERIFIER_STOP"),
+    (0xc000_0423, "STATUS_CALLBACK_POP_STACK"),
+    (0xc000_0424, "STATUS_INCOMPATIBLE_DRIVER_BLOCKED"),
+    (0xc000_0425, "STATUS_HIVE_UNLOADED"),
+    (0xc000_0426, "STATUS_COMPRESSION_DISABLED"),
+    (0xc000_0427, "STATUS_FILE_SYSTEM_LIMITATION"),
+    (0xc000_0428, "STATUS_INVALID_IMAGE_HASH"),
+    (0xc000_0429, "STATUS_NOT_CAPABLE"),
+    (0xc000_042a, "STATUS_REQUEST_OUT_OF_SEQUENCE"),
+    (0xc000_042b, "STATUS_IMPLEMENTATION_LIMIT"),
+    (0xc000_042c, "STATUS_ELEVATION_REQUIRED"),
+    (0xc000_042d, "STATUS_NO_SECURITY_CONTEXT"),
+    (0xc000_042f, "STATUS_PKU2U_CERT_FAILURE"),
+    (0xc000_0432, "STATUS_BEYOND_VDL"),
+    (0xc000_0433, "STATUS_ENCOUNTERED_WRITE_IN_PROGRESS"),
+    (0xc000_0434, "STATUS_PTE_CHANGED"),
+    (0xc000_0435, "STATUS_PURGE_FAILED"),
+    (0xc000_0440, "STATUS_CRED_REQUIRES_CONFIRMATION"),
+    (0xc000_0441, "STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE"),
+    (0xc000_0442, "STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER"),
+    (0xc000_0443, "STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE"),
+    (0xc000_0444, "STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE"),
+    (0xc000_0445, "STATUS_CS_ENCRYPTION_FILE_NOT_CSE"),
+    (0xc000_0446, "STATUS_INVALID_LABEL"),
+    (0xc000_0450, "STATUS_DRIVER_PROCESS_TERMINATED"),
+    (0xc000_0451, "STATUS_AMBIGUOUS_SYSTEM_DEVICE"),
+    (0xc000_0452, "STATUS_SYSTEM_DEVICE_NOT_FOUND"),
+    (0xc000_0453, "STATUS_RESTART_BOOT_APPLICATION"),
+    (0xc000_0454, "STATUS_INSUFFICIENT_NVRAM_RESOURCES"),
+    (0xc000_0455, "STATUS_INVALID_SESSION"),
+    (0xc000_0456, "STATUS_THREAD_ALREADY_IN_SESSION"),
+    (0xc000_0457, "STATUS_THREAD_NOT_IN_SESSION"),
+    (0xc000_0458, "STATUS_INVALID_WEIGHT"),
+    (0xc000_0459, "STATUS_REQUEST_PAUSED"),
+    (0xc000_0460, "STATUS_NO_RANGES_PROCESSED"),
+    (0xc000_0461, "STATUS_DISK_RESOURCES_EXHAUSTED"),
+    (0xc000_0462, "STATUS_NEEDS_REMEDIATION"),
+    (0xc000_0463, "STATUS_DEVICE_FEATURE_NOT_SUPPORTED"),
+    (0xc000_0464, "STATUS_DEVICE_UNREACHABLE"),
+    (0xc000_0465, "STATUS_INVALID_TOKEN"),
+    (0xc000_0466, "STATUS_SERVER_UNAVAILABLE"),
+    (0xc000_0467, "STATUS_FILE_NOT_AVAILABLE"),
+    (0xc000_0468, "STATUS_DEVICE_INSUFFICIENT_RESOURCES"),
+    (0xc000_0469, "STATUS_PACKAGE_UPDATING"),
+    (0xc000_046a, "STATUS_NOT_READ_FROM_COPY"),
+    (0xc000_046b, "STATUS_FT_WRITE_FAILURE"),
+    (0xc000_046c, "STATUS_FT_DI_SCAN_REQUIRED"),
+    (0xc000_046d, "STATUS_OBJECT_NOT_EXTERNALLY_BACKED"),
+    (0xc000_046e, "STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN"),
+    (0xc000_046f, "STATUS_COMPRESSION_NOT_BENEFICIAL"),
+    (0xc000_0470, "STATUS_DATA_CHECKSUM_ERROR"),
+    (0xc000_0471, "STATUS_INTERMIXED_KERNEL_EA_OPERATION"),
+    (0xc000_0472, "STATUS_TRIM_READ_ZERO_NOT_SUPPORTED"),
+    (0xc000_0473, "STATUS_TOO_MANY_SEGMENT_DESCRIPTORS"),
+    (0xc000_0474, "STATUS_INVALID_OFFSET_ALIGNMENT"),
+    (0xc000_0475, "STATUS_INVALID_FIELD_IN_PARAMETER_LIST"),
+    (0xc000_0476, "STATUS_OPERATION_IN_PROGRESS"),
+    (0xc000_0477, "STATUS_INVALID_INITIATOR_TARGET_PATH"),
+    (0xc000_0478, "STATUS_SCRUB_DATA_DISABLED"),
+    (0xc000_0479, "STATUS_NOT_REDUNDANT_STORAGE"),
+    (0xc000_047a, "STATUS_RESIDENT_FILE_NOT_SUPPORTED"),
+    (0xc000_047b, "STATUS_COMPRESSED_FILE_NOT_SUPPORTED"),
+    (0xc000_047c, "STATUS_DIRECTORY_NOT_SUPPORTED"),
+    (0xc000_047d, "STATUS_IO_OPERATION_TIMEOUT"),
+    (0xc000_047e, "STATUS_SYSTEM_NEEDS_REMEDIATION"),
+    (0xc000_047f, "STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN"),
+    (0xc000_0480, "STATUS_SHARE_UNAVAILABLE"),
+    (0xc000_0481, "STATUS_APISET_NOT_HOSTED"),
+    (0xc000_0482, "STATUS_APISET_NOT_PRESENT"),
+    (0xc000_0483, "STATUS_DEVICE_HARDWARE_ERROR"),
+    (0xc000_0484, "STATUS_FIRMWARE_SLOT_INVALID"),
+    (0xc000_0485, "STATUS_FIRMWARE_IMAGE_INVALID"),
+    (0xc000_0486, "STATUS_STORAGE_TOPOLOGY_ID_MISMATCH"),
+    (0xc000_0487, "STATUS_WIM_NOT_BOOTABLE"),
+    (0xc000_0488, "STATUS_BLOCKED_BY_PARENTAL_CONTROLS"),
+    (0xc000_0489, "STATUS_NEEDS_REGISTRATION"),
+    (0xc000_048a, "STATUS_QUOTA_ACTIVITY"),
+    (0xc000_048b, "STATUS_CALLBACK_INVOKE_INLINE"),
+    (0xc000_048c, "STATUS_BLOCK_TOO_MANY_REFERENCES"),
+    (0xc000_048d, "STATUS_MARKED_TO_DISALLOW_WRITES"),
+    (0xc000_048e, "STATUS_NETWORK_ACCESS_DENIED_EDP"),
+    (0xc000_048f, "STATUS_ENCLAVE_FAILURE"),
+    (0xc000_0490, "STATUS_PNP_NO_COMPAT_DRIVERS"),
+    (0xc000_0491, "STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND"),
+    (0xc000_0492, "STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND"),
+    (0xc000_0493, "STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE"),
+    (0xc000_0494, "STATUS_PNP_FUNCTION_DRIVER_REQUIRED"),
+    (0xc000_0495, "STATUS_PNP_DEVICE_CONFIGURATION_PENDING"),
+    (0xc000_0496, "STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL"),
+    (0xc000_0497, "STATUS_PACKAGE_NOT_AVAILABLE"),
+    (0xc000_0499, "STATUS_DEVICE_IN_MAINTENANCE"),
+    (0xc000_049a, "STATUS_NOT_SUPPORTED_ON_DAX"),
+    (0xc000_049b, "STATUS_FREE_SPACE_TOO_FRAGMENTED"),
+    (0xc000_049c, "STATUS_DAX_MAPPING_EXISTS"),
+    (0xc000_049d, "STATUS_CHILD_PROCESS_BLOCKED"),
+    (0xc000_049e, "STATUS_STORAGE_LOST_DATA_PERSISTENCE"),
+    (0xc000_04a0, "STATUS_PARTITION_TERMINATING"),
+    (0xc000_04a1, "STATUS_EXTERNAL_SYSKEY_NOT_SUPPORTED"),
+    (0xc000_04a2, "STATUS_ENCLAVE_VIOLATION"),
+    (0xc000_04a3, "STATUS_FILE_PROTECTED_UNDER_DPL"),
+    (0xc000_04a4, "STATUS_VOLUME_NOT_CLUSTER_ALIGNED"),
+    (0xc000_04a5, "STATUS_NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND"),
+    (0xc000_04a6, "STATUS_APPX_FILE_NOT_ENCRYPTED"),
+    (0xc000_04a7, "STATUS_RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED"),
+    (0xc000_04a8, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET"),
+    (0xc000_04a9, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE"),
+    (0xc000_04aa, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER"),
+    (0xc000_04ab, "STATUS_FT_READ_FAILURE"),
+    (0xc000_04ac, "STATUS_PATCH_CONFLICT"),
+    (0xc000_04ad, "STATUS_STORAGE_RESERVE_ID_INVALID"),
+    (0xc000_04ae, "STATUS_STORAGE_RESERVE_DOES_NOT_EXIST"),
+    (0xc000_04af, "STATUS_STORAGE_RESERVE_ALREADY_EXISTS"),
+    (0xc000_04b0, "STATUS_STORAGE_RESERVE_NOT_EMPTY"),
+    (0xc000_04b1, "STATUS_NOT_A_DAX_VOLUME"),
+    (0xc000_04b2, "STATUS_NOT_DAX_MAPPABLE"),
+    (0xc000_04b3, "STATUS_CASE_DIFFERING_NAMES_IN_DIR"),
+    (0xc000_04b4, "STATUS_FILE_NOT_SUPPORTED"),
+    (0xc000_04b5, "STATUS_NOT_SUPPORTED_WITH_BTT"),
+    (0xc000_04b6, "STATUS_ENCRYPTION_DISABLED"),
+    (0xc000_04b7, "STATUS_ENCRYPTING_METADATA_DISALLOWED"),
+    (0xc000_04b8, "STATUS_CANT_CLEAR_ENCRYPTION_FLAG"),
+    (0xc000_04b9, "STATUS_UNSATISFIED_DEPENDENCIES"),
+    (0xc000_04ba, "STATUS_CASE_SENSITIVE_PATH"),
+    (0xc000_04bb, "STATUS_UNSUPPORTED_PAGING_MODE"),
+    (0xc000_04bc, "STATUS_UNTRUSTED_MOUNT_POINT"),
+    (0xc000_04bd, "STATUS_HAS_SYSTEM_CRITICAL_FILES"),
+    (0xc000_04be, "STATUS_OBJECT_IS_IMMUTABLE"),
+    (0xc000_04bf, "STATUS_FT_READ_FROM_COPY_FAILURE"),
+    (0xc000_04c0, "STATUS_IMAGE_LOADED_AS_PATCH_IMAGE"),
+    (0xc000_04c1, "STATUS_STORAGE_STACK_ACCESS_DENIED"),
+    (0xc000_04c2, "STATUS_INSUFFICIENT_VIRTUAL_ADDR_RESOURCES"),
+    (0xc000_04c3, "STATUS_ENCRYPTED_FILE_NOT_SUPPORTED"),
+    (0xc000_04c4, "STATUS_SPARSE_FILE_NOT_SUPPORTED"),
+    (0xc000_04c5, "STATUS_PAGEFILE_NOT_SUPPORTED"),
+    (0xc000_04c6, "STATUS_VOLUME_NOT_SUPPORTED"),
+    (0xc000_04c7, "STATUS_NOT_SUPPORTED_WITH_BYPASSIO"),
+    (0xc000_04c8, "STATUS_NO_BYPASSIO_DRIVER_SUPPORT"),
+    (0xc000_04c9, "STATUS_NOT_SUPPORTED_WITH_ENCRYPTION"),
+    (0xc000_04ca, "STATUS_NOT_SUPPORTED_WITH_COMPRESSION"),
+    (0xc000_04cb, "STATUS_NOT_SUPPORTED_WITH_REPLICATION"),
+    (0xc000_04cc, "STATUS_NOT_SUPPORTED_WITH_DEDUPLICATION"),
+    (0xc000_04cd, "STATUS_NOT_SUPPORTED_WITH_AUDITING"),
+    (0xc000_04ce, "STATUS_NOT_SUPPORTED_WITH_MONITORING"),
+    (0xc000_04cf, "STATUS_NOT_SUPPORTED_WITH_SNAPSHOT"),
+    (0xc000_04d0, "STATUS_NOT_SUPPORTED_WITH_VIRTUALIZATION"),
+    (0xc000_04d1, "STATUS_INDEX_OUT_OF_BOUNDS"),
+    (0xc000_04d2, "STATUS_BYPASSIO_FLT_NOT_SUPPORTED"),
+    (0xc000_04d3, "STATUS_VOLUME_WRITE_ACCESS_DENIED"),
+    (0xc000_04d4, "STATUS_PATCH_NOT_REGISTERED"),
+    (0xc000_04d5, "STATUS_NOT_SUPPORTED_WITH_CACHED_HANDLE"),
+    (0xc000_04d9, "STATUS_VOLUME_UPGRADE_NOT_NEEDED"),
+    (0xc000_04da, "STATUS_VOLUME_UPGRADE_PENDING"),
+    (0xc000_04db, "STATUS_VOLUME_UPGRADE_DISABLED"),
+    (0xc000_04dc, "STATUS_VOLUME_UPGRADE_DISABLED_TILL_OS_DOWNGRADE_EXPIRED"),
+    (0xc000_04dd, "STATUS_NOT_A_DEV_VOLUME"),
+    (0xc000_04de, "STATUS_FS_GUID_MISMATCH"),
+    (0xc000_04df, "STATUS_CANT_ATTACH_TO_DEV_VOLUME"),
+    (0xc000_04e0, "STATUS_INVALID_CONFIG_VALUE"),
+    (0xc000_0500, "STATUS_INVALID_TASK_NAME"),
+    (0xc000_0501, "STATUS_INVALID_TASK_INDEX"),
+    (0xc000_0502, "STATUS_THREAD_ALREADY_IN_TASK"),
+    (0xc000_0503, "STATUS_CALLBACK_BYPASS"),
+    (0xc000_0504, "STATUS_UNDEFINED_SCOPE"),
+    (0xc000_0505, "STATUS_INVALID_CAP"),
+    (0xc000_0506, "STATUS_NOT_GUI_PROCESS"),
+    (0xc000_0507, "STATUS_DEVICE_HUNG"),
+    (0xc000_0508, "STATUS_CONTAINER_ASSIGNED"),
+    (0xc000_0509, "STATUS_JOB_NO_CONTAINER"),
+    (0xc000_050a, "STATUS_DEVICE_UNRESPONSIVE"),
+    (0xc000_050b, "STATUS_REPARSE_POINT_ENCOUNTERED"),
+    (0xc000_050c, "STATUS_ATTRIBUTE_NOT_PRESENT"),
+    (0xc000_050d, "STATUS_NOT_A_TIERED_VOLUME"),
+    (0xc000_050e, "STATUS_ALREADY_HAS_STREAM_ID"),
+    (0xc000_050f, "STATUS_JOB_NOT_EMPTY"),
+    (0xc000_0510, "STATUS_ALREADY_INITIALIZED"),
+    (0xc000_0511, "STATUS_ENCLAVE_NOT_TERMINATED"),
+    (0xc000_0512, "STATUS_ENCLAVE_IS_TERMINATING"),
+    (0xc000_0513, "STATUS_SMB1_NOT_AVAILABLE"),
+    (0xc000_0514, "STATUS_SMR_GARBAGE_COLLECTION_REQUIRED"),
+    (0xc000_0515, "STATUS_INTERRUPTED"),
+    (0xc000_0516, "STATUS_THREAD_NOT_RUNNING"),
+    (0xc000_0517, "STATUS_SESSION_KEY_TOO_SHORT"),
+    (0xc000_0518, "STATUS_FS_METADATA_INCONSISTENT"),
+    (0xc000_0602, "STATUS_FAIL_FAST_EXCEPTION"),
+    (0xc000_0603, "STATUS_IMAGE_CERT_REVOKED"),
+    (0xc000_0604, "STATUS_DYNAMIC_CODE_BLOCKED"),
+    (0xc000_0605, "STATUS_IMAGE_CERT_EXPIRED"),
+    (0xc000_0606, "STATUS_STRICT_CFG_VIOLATION"),
+    (0xc000_060a, "STATUS_SET_CONTEXT_DENIED"),
+    (0xc000_060b, "STATUS_CROSS_PARTITION_VIOLATION"),
+    (0xc000_0700, "STATUS_PORT_CLOSED"),
+    (0xc000_0701, "STATUS_MESSAGE_LOST"),
+    (0xc000_0702, "STATUS_INVALID_MESSAGE"),
+    (0xc000_0703, "STATUS_REQUEST_CANCELED"),
+    (0xc000_0704, "STATUS_RECURSIVE_DISPATCH"),
+    (0xc000_0705, "STATUS_LPC_RECEIVE_BUFFER_EXPECTED"),
+    (0xc000_0706, "STATUS_LPC_INVALID_CONNECTION_USAGE"),
+    (0xc000_0707, "STATUS_LPC_REQUESTS_NOT_ALLOWED"),
+    (0xc000_0708, "STATUS_RESOURCE_IN_USE"),
+    (0xc000_0709, "STATUS_HARDWARE_MEMORY_ERROR"),
+    (0xc000_070a, "STATUS_THREADPOOL_HANDLE_EXCEPTION"),
+    (0xc000_070b, "STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED"),
+    (0xc000_070c, "STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED"),
+    (0xc000_070d, "STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED"),
+    (0xc000_070e, "STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED"),
+    (0xc000_070f, "STATUS_THREADPOOL_RELEASED_DURING_OPERATION"),
+    (0xc000_0710, "STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING"),
+    (0xc000_0711, "STATUS_APC_RETURNED_WHILE_IMPERSONATING"),
+    (0xc000_0712, "STATUS_PROCESS_IS_PROTECTED"),
+    (0xc000_0713, "STATUS_MCA_EXCEPTION"),
+    (0xc000_0714, "STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE"),
+    (0xc000_0715, "STATUS_SYMLINK_CLASS_DISABLED"),
+    (0xc000_0716, "STATUS_INVALID_IDN_NORMALIZATION"),
+    (0xc000_0717, "STATUS_NO_UNICODE_TRANSLATION"),
+    (0xc000_0718, "STATUS_ALREADY_REGISTERED"),
+    (0xc000_0719, "STATUS_CONTEXT_MISMATCH"),
+    (0xc000_071a, "STATUS_PORT_ALREADY_HAS_COMPLETION_LIST"),
+    (0xc000_071b, "STATUS_CALLBACK_RETURNED_THREAD_PRIORITY"),
+    (0xc000_071c, "STATUS_INVALID_THREAD"),
+    (0xc000_071d, "STATUS_CALLBACK_RETURNED_TRANSACTION"),
+    (0xc000_071e, "STATUS_CALLBACK_RETURNED_LDR_LOCK"),
+    (0xc000_071f, "STATUS_CALLBACK_RETURNED_LANG"),
+    (0xc000_0720, "STATUS_CALLBACK_RETURNED_PRI_BACK"),
+    (0xc000_0721, "STATUS_CALLBACK_RETURNED_THREAD_AFFINITY"),
+    (0xc000_0722, "STATUS_LPC_HANDLE_COUNT_EXCEEDED"),
+    (0xc000_0723, "STATUS_EXECUTABLE_MEMORY_WRITE"),
+    (0xc000_0724, "STATUS_KERNEL_EXECUTABLE_MEMORY_WRITE"),
+    (0xc000_0725, "STATUS_ATTACHED_EXECUTABLE_MEMORY_WRITE"),
+    (0xc000_0726, "STATUS_TRIGGERED_EXECUTABLE_MEMORY_WRITE"),
+    (0xc000_0800, "STATUS_DISK_REPAIR_DISABLED"),
+    (0xc000_0801, "STATUS_DS_DOMAIN_RENAME_IN_PROGRESS"),
+    (0xc000_0802, "STATUS_DISK_QUOTA_EXCEEDED"),
+    (0xc000_0804, "STATUS_CONTENT_BLOCKED"),
+    (0xc000_0805, "STATUS_BAD_CLUSTERS"),
+    (0xc000_0806, "STATUS_VOLUME_DIRTY"),
+    (0xc000_0808, "STATUS_DISK_REPAIR_UNSUCCESSFUL"),
+    (0xc000_0809, "STATUS_CORRUPT_LOG_OVERFULL"),
+    (0xc000_080a, "STATUS_CORRUPT_LOG_CORRUPTED"),
+    (0xc000_080b, "STATUS_CORRUPT_LOG_UNAVAILABLE"),
+    (0xc000_080c, "STATUS_CORRUPT_LOG_DELETED_FULL"),
+    (0xc000_080d, "STATUS_CORRUPT_LOG_CLEARED"),
+    (0xc000_080e, "STATUS_ORPHAN_NAME_EXHAUSTED"),
+    (0xc000_080f, "STATUS_PROACTIVE_SCAN_IN_PROGRESS"),
+    (0xc000_0810, "STATUS_ENCRYPTED_IO_NOT_POSSIBLE"),
+    (0xc000_0811, "STATUS_CORRUPT_LOG_UPLEVEL_RECORDS"),
+    (0xc000_0901, "STATUS_FILE_CHECKED_OUT"),
+    (0xc000_0902, "STATUS_CHECKOUT_REQUIRED"),
+    (0xc000_0903, "STATUS_BAD_FILE_TYPE"),
+    (0xc000_0904, "STATUS_FILE_TOO_LARGE"),
+    (0xc000_0905, "STATUS_FORMS_AUTH_REQUIRED"),
+    (0xc000_0906, "STATUS_VIRUS_INFECTED"),
+    (0xc000_0907, "STATUS_VIRUS_DELETED"),
+    (0xc000_0908, "STATUS_BAD_MCFG_TABLE"),
+    (0xc000_0909, "STATUS_CANNOT_BREAK_OPLOCK"),
+    (0xc000_090a, "STATUS_BAD_KEY"),
+    (0xc000_090b, "STATUS_BAD_DATA"),
+    (0xc000_090c, "STATUS_NO_KEY"),
+    (0xc000_0910, "STATUS_FILE_HANDLE_REVOKED"),
+    (0xc000_0911, "STATUS_SECTION_DIRECT_MAP_ONLY"),
+    (0xc000_0912, "STATUS_BLOCK_WEAK_REFERENCE_INVALID"),
+    (0xc000_0913, "STATUS_BLOCK_SOURCE_WEAK_REFERENCE_INVALID"),
+    (0xc000_0914, "STATUS_BLOCK_TARGET_WEAK_REFERENCE_INVALID"),
+    (0xc000_0915, "STATUS_BLOCK_SHARED"),
+    (0xc000_0c08, "STATUS_VRF_VOLATILE_CFG_AND_IO_ENABLED"),
+    (0xc000_0c09, "STATUS_VRF_VOLATILE_NOT_STOPPABLE"),
+    (0xc000_0c0a, "STATUS_VRF_VOLATILE_SAFE_MODE"),
+    (0xc000_0c0b, "STATUS_VRF_VOLATILE_NOT_RUNNABLE_SYSTEM"),
+    (0xc000_0c0c, "STATUS_VRF_VOLATILE_NOT_SUPPORTED_RULECLASS"),
+    (0xc000_0c0d, "STATUS_VRF_VOLATILE_PROTECTED_DRIVER"),
+    (0xc000_0c0e, "STATUS_VRF_VOLATILE_NMI_REGISTERED"),
+    (0xc000_0c0f, "STATUS_VRF_VOLATILE_SETTINGS_CONFLICT"),
+    (0xc000_0c76, "STATUS_DIF_IOCALLBACK_NOT_REPLACED"),
+    (0xc000_0c77, "STATUS_DIF_LIVEDUMP_LIMIT_EXCEEDED"),
+    (0xc000_0c78, "STATUS_DIF_VOLATILE_SECTION_NOT_LOCKED"),
+    (0xc000_0c79, "STATUS_DIF_VOLATILE_DRIVER_HOTPATCHED"),
+    (0xc000_0c7a, "STATUS_DIF_VOLATILE_INVALID_INFO"),
+    (0xc000_0c7b, "STATUS_DIF_VOLATILE_DRIVER_IS_NOT_RUNNING"),
+    (0xc000_0c7c, "STATUS_DIF_VOLATILE_PLUGIN_IS_NOT_RUNNING"),
+    (0xc000_0c7d, "STATUS_DIF_VOLATILE_PLUGIN_CHANGE_NOT_ALLOWED"),
+    (0xc000_0c7e, "STATUS_DIF_VOLATILE_NOT_ALLOWED"),
+    (0xc000_0c7f, "STATUS_DIF_BINDING_API_NOT_FOUND"),
+    (0xc000_9898, "STATUS_WOW_ASSERTION"),
+    (0xc000_a000, "STATUS_INVALID_SIGNATURE"),
+    (0xc000_a001, "STATUS_HMAC_NOT_SUPPORTED"),
+    (0xc000_a002, "STATUS_AUTH_TAG_MISMATCH"),
+    (0xc000_a003, "STATUS_INVALID_STATE_TRANSITION"),
+    (0xc000_a004, "STATUS_INVALID_KERNEL_INFO_VERSION"),
+    (0xc000_a005, "STATUS_INVALID_PEP_INFO_VERSION"),
+    (0xc000_a006, "STATUS_HANDLE_REVOKED"),
+    (0xc000_a007, "STATUS_EOF_ON_GHOSTED_RANGE"),
+    (0xc000_a008, "STATUS_CC_NEEDS_CALLBACK_SECTION_DRAIN"),
+    (0xc000_a010, "STATUS_IPSEC_QUEUE_OVERFLOW"),
+    (0xc000_a011, "STATUS_ND_QUEUE_OVERFLOW"),
+    (0xc000_a012, "STATUS_HOPLIMIT_EXCEEDED"),
+    (0xc000_a013, "STATUS_PROTOCOL_NOT_SUPPORTED"),
+    (0xc000_a014, "STATUS_FASTPATH_REJECTED"),
+    (0xc000_a080, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED"),
+    (0xc000_a081, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR"),
+    (0xc000_a082, "STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR"),
+    (0xc000_a083, "STATUS_XML_PARSE_ERROR"),
+    (0xc000_a084, "STATUS_XMLDSIG_ERROR"),
+    (0xc000_a085, "STATUS_WRONG_COMPARTMENT"),
+    (0xc000_a086, "STATUS_AUTHIP_FAILURE"),
+    (0xc000_a087, "STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS"),
+    (0xc000_a088, "STATUS_DS_OID_NOT_FOUND"),
+    (0xc000_a089, "STATUS_INCORRECT_ACCOUNT_TYPE"),
+    (0xc000_a08a, "STATUS_LOCAL_POLICY_MODIFICATION_NOT_SUPPORTED"),
+    (0xc000_a08b, "STATUS_POLICY_CONTROLLED_ACCOUNT"),
+    (0xc000_a08c, "STATUS_LAPS_LEGACY_SCHEMA_MISSING"),
+    (0xc000_a08d, "STATUS_LAPS_SCHEMA_MISSING"),
+    (0xc000_a08e, "STATUS_LAPS_ENCRYPTION_REQUIRES_2016_DFL"),
+    (0xc000_a100, "STATUS_HASH_NOT_SUPPORTED"),
+    (0xc000_a101, "STATUS_HASH_NOT_PRESENT"),
+    (0xc000_a121, "STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED"),
+    (0xc000_a122, "STATUS_GPIO_CLIENT_INFORMATION_INVALID"),
+    (0xc000_a123, "STATUS_GPIO_VERSION_NOT_SUPPORTED"),
+    (0xc000_a124, "STATUS_GPIO_INVALID_REGISTRATION_PACKET"),
+    (0xc000_a125, "STATUS_GPIO_OPERATION_DENIED"),
+    (0xc000_a126, "STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE"),
+    (0xc000_a141, "STATUS_CANNOT_SWITCH_RUNLEVEL"),
+    (0xc000_a142, "STATUS_INVALID_RUNLEVEL_SETTING"),
+    (0xc000_a143, "STATUS_RUNLEVEL_SWITCH_TIMEOUT"),
+    (0xc000_a145, "STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT"),
+    (0xc000_a146, "STATUS_RUNLEVEL_SWITCH_IN_PROGRESS"),
+    (0xc000_a200, "STATUS_NOT_APPCONTAINER"),
+    (0xc000_a201, "STATUS_NOT_SUPPORTED_IN_APPCONTAINER"),
+    (0xc000_a202, "STATUS_INVALID_PACKAGE_SID_LENGTH"),
+    (0xc000_a203, "STATUS_LPAC_ACCESS_DENIED"),
+    (0xc000_a204, "STATUS_ADMINLESS_ACCESS_DENIED"),
+    (0xc000_a281, "STATUS_APP_DATA_NOT_FOUND"),
+    (0xc000_a282, "STATUS_APP_DATA_EXPIRED"),
+    (0xc000_a283, "STATUS_APP_DATA_CORRUPT"),
+    (0xc000_a284, "STATUS_APP_DATA_LIMIT_EXCEEDED"),
+    (0xc000_a285, "STATUS_APP_DATA_REBOOT_REQUIRED"),
+    (0xc000_a2a1, "STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED"),
+    (0xc000_a2a2, "STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED"),
+    (0xc000_a2a3, "STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED"),
+    (0xc000_a2a4, "STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED"),
+    (0xc000_a2a5, "STATUS_WOF_WIM_HEADER_CORRUPT"),
+    (0xc000_a2a6, "STATUS_WOF_WIM_RESOURCE_TABLE_CORRUPT"),
+    (0xc000_a2a7, "STATUS_WOF_FILE_RESOURCE_TABLE_CORRUPT"),
+    (0xc000_c001, "STATUS_CIMFS_IMAGE_CORRUPT"),
+    (0xc000_c002, "STATUS_CIMFS_IMAGE_VERSION_NOT_SUPPORTED"),
+    (0xc000_ce01, "STATUS_FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE"),
+    (0xc000_ce02, "STATUS_FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT"),
+    (0xc000_ce03, "STATUS_FILE_SYSTEM_VIRTUALIZATION_BUSY"),
+    (0xc000_ce04, "STATUS_FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN"),
+    (0xc000_ce05, "STATUS_FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION"),
+    (0xc000_cf00, "STATUS_CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT"),
+    (0xc000_cf01, "STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING"),
+    (0xc000_cf02, "STATUS_CLOUD_FILE_METADATA_CORRUPT"),
+    (0xc000_cf03, "STATUS_CLOUD_FILE_METADATA_TOO_LARGE"),
+    (0xc000_cf06, "STATUS_CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED"),
+    (0xc000_cf07, "STATUS_NOT_A_CLOUD_FILE"),
+    (0xc000_cf08, "STATUS_CLOUD_FILE_NOT_IN_SYNC"),
+    (0xc000_cf09, "STATUS_CLOUD_FILE_ALREADY_CONNECTED"),
+    (0xc000_cf0a, "STATUS_CLOUD_FILE_NOT_SUPPORTED"),
+    (0xc000_cf0b, "STATUS_CLOUD_FILE_INVALID_REQUEST"),
+    (0xc000_cf0c, "STATUS_CLOUD_FILE_READ_ONLY_VOLUME"),
+    (0xc000_cf0d, "STATUS_CLOUD_FILE_CONNECTED_PROVIDER_ONLY"),
+    (0xc000_cf0e, "STATUS_CLOUD_FILE_VALIDATION_FAILED"),
+    (0xc000_cf0f, "STATUS_CLOUD_FILE_AUTHENTICATION_FAILED"),
+    (0xc000_cf10, "STATUS_CLOUD_FILE_INSUFFICIENT_RESOURCES"),
+    (0xc000_cf11, "STATUS_CLOUD_FILE_NETWORK_UNAVAILABLE"),
+    (0xc000_cf12, "STATUS_CLOUD_FILE_UNSUCCESSFUL"),
+    (0xc000_cf13, "STATUS_CLOUD_FILE_NOT_UNDER_SYNC_ROOT"),
+    (0xc000_cf14, "STATUS_CLOUD_FILE_IN_USE"),
+    (0xc000_cf15, "STATUS_CLOUD_FILE_PINNED"),
+    (0xc000_cf16, "STATUS_CLOUD_FILE_REQUEST_ABORTED"),
+    (0xc000_cf17, "STATUS_CLOUD_FILE_PROPERTY_CORRUPT"),
+    (0xc000_cf18, "STATUS_CLOUD_FILE_ACCESS_DENIED"),
+    (0xc000_cf19, "STATUS_CLOUD_FILE_INCOMPATIBLE_HARDLINKS"),
+    (0xc000_cf1a, "STATUS_CLOUD_FILE_PROPERTY_LOCK_CONFLICT"),
+    (0xc000_cf1b, "STATUS_CLOUD_FILE_REQUEST_CANCELED"),
+    (0xc000_cf1d, "STATUS_CLOUD_FILE_PROVIDER_TERMINATED"),
+    (0xc000_cf1e, "STATUS_NOT_A_CLOUD_SYNC_ROOT"),
+    (0xc000_cf1f, "STATUS_CLOUD_FILE_REQUEST_TIMEOUT"),
+    (0xc000_cf20, "STATUS_CLOUD_FILE_DEHYDRATION_DISALLOWED"),
+    (0xc000_cf21, "STATUS_CLOUD_FILE_US_MESSAGE_TIMEOUT"),
+    (0xc000_f500, "STATUS_FILE_SNAP_IN_PROGRESS"),
+    (0xc000_f501, "STATUS_FILE_SNAP_USER_SECTION_NOT_SUPPORTED"),
+    (0xc000_f502, "STATUS_FILE_SNAP_MODIFY_NOT_SUPPORTED"),
+    (0xc000_f503, "STATUS_FILE_SNAP_IO_NOT_COORDINATED"),
+    (0xc000_f504, "STATUS_FILE_SNAP_UNEXPECTED_ERROR"),
+    (0xc000_f505, "STATUS_FILE_SNAP_INVALID_PARAMETER"),
+    (0xc001_0001, "DBG_NO_STATE_CHANGE"),
+    (0xc001_0002, "DBG_APP_NOT_IDLE"),
+    (0xc002_0001, "RPC_NT_INVALID_STRING_BINDING"),
+    (0xc002_0002, "RPC_NT_WRONG_KIND_OF_BINDING"),
+    (0xc002_0003, "RPC_NT_INVALID_BINDING"),
+    (0xc002_0004, "RPC_NT_PROTSEQ_NOT_SUPPORTED"),
+    (0xc002_0005, "RPC_NT_INVALID_RPC_PROTSEQ"),
+    (0xc002_0006, "RPC_NT_INVALID_STRING_UUID"),
+    (0xc002_0007, "RPC_NT_INVALID_ENDPOINT_FORMAT"),
+    (0xc002_0008, "RPC_NT_INVALID_NET_ADDR"),
+    (0xc002_0009, "RPC_NT_NO_ENDPOINT_FOUND"),
+    (0xc002_000a, "RPC_NT_INVALID_TIMEOUT"),
+    (0xc002_000b, "RPC_NT_OBJECT_NOT_FOUND"),
+    (0xc002_000c, "RPC_NT_ALREADY_REGISTERED"),
+    (0xc002_000d, "RPC_NT_TYPE_ALREADY_REGISTERED"),
+    (0xc002_000e, "RPC_NT_ALREADY_LISTENING"),
+    (0xc002_000f, "RPC_NT_NO_PROTSEQS_REGISTERED"),
+    (0xc002_0010, "RPC_NT_NOT_LISTENING"),
+    (0xc002_0011, "RPC_NT_UNKNOWN_MGR_TYPE"),
+    (0xc002_0012, "RPC_NT_UNKNOWN_IF"),
+    (0xc002_0013, "RPC_NT_NO_BINDINGS"),
+    (0xc002_0014, "RPC_NT_NO_PROTSEQS"),
+    (0xc002_0015, "RPC_NT_CANT_CREATE_ENDPOINT"),
+    (0xc002_0016, "RPC_NT_OUT_OF_RESOURCES"),
+    (0xc002_0017, "RPC_NT_SERVER_UNAVAILABLE"),
+    (0xc002_0018, "RPC_NT_SERVER_TOO_BUSY"),
+    (0xc002_0019, "RPC_NT_INVALID_NETWORK_OPTIONS"),
+    (0xc002_001a, "RPC_NT_NO_CALL_ACTIVE"),
+    (0xc002_001b, "RPC_NT_CALL_FAILED"),
+    (0xc002_001c, "RPC_NT_CALL_FAILED_DNE"),
+    (0xc002_001d, "RPC_NT_PROTOCOL_ERROR"),
+    (0xc002_001f, "RPC_NT_UNSUPPORTED_TRANS_SYN"),
+    (0xc002_0021, "RPC_NT_UNSUPPORTED_TYPE"),
+    (0xc002_0022, "RPC_NT_INVALID_TAG"),
+    (0xc002_0023, "RPC_NT_INVALID_BOUND"),
+    (0xc002_0024, "RPC_NT_NO_ENTRY_NAME"),
+    (0xc002_0025, "RPC_NT_INVALID_NAME_SYNTAX"),
+    (0xc002_0026, "RPC_NT_UNSUPPORTED_NAME_SYNTAX"),
+    (0xc002_0028, "RPC_NT_UUID_NO_ADDRESS"),
+    (0xc002_0029, "RPC_NT_DUPLICATE_ENDPOINT"),
+    (0xc002_002a, "RPC_NT_UNKNOWN_AUTHN_TYPE"),
+    (0xc002_002b, "RPC_NT_MAX_CALLS_TOO_SMALL"),
+    (0xc002_002c, "RPC_NT_STRING_TOO_LONG"),
+    (0xc002_002d, "RPC_NT_PROTSEQ_NOT_FOUND"),
+    (0xc002_002e, "RPC_NT_PROCNUM_OUT_OF_RANGE"),
+    (0xc002_002f, "RPC_NT_BINDING_HAS_NO_AUTH"),
+    (0xc002_0030, "RPC_NT_UNKNOWN_AUTHN_SERVICE"),
+    (0xc002_0031, "RPC_NT_UNKNOWN_AUTHN_LEVEL"),
+    (0xc002_0032, "RPC_NT_INVALID_AUTH_IDENTITY"),
+    (0xc002_0033, "RPC_NT_UNKNOWN_AUTHZ_SERVICE"),
+    (0xc002_0034, "EPT_NT_INVALID_ENTRY"),
+    (0xc002_0035, "EPT_NT_CANT_PERFORM_OP"),
+    (0xc002_0036, "EPT_NT_NOT_REGISTERED"),
+    (0xc002_0037, "RPC_NT_NOTHING_TO_EXPORT"),
+    (0xc002_0038, "RPC_NT_INCOMPLETE_NAME"),
+    (0xc002_0039, "RPC_NT_INVALID_VERS_OPTION"),
+    (0xc002_003a, "RPC_NT_NO_MORE_MEMBERS"),
+    (0xc002_003b, "RPC_NT_NOT_ALL_OBJS_UNEXPORTED"),
+    (0xc002_003c, "RPC_NT_INTERFACE_NOT_FOUND"),
+    (0xc002_003d, "RPC_NT_ENTRY_ALREADY_EXISTS"),
+    (0xc002_003e, "RPC_NT_ENTRY_NOT_FOUND"),
+    (0xc002_003f, "RPC_NT_NAME_SERVICE_UNAVAILABLE"),
+    (0xc002_0040, "RPC_NT_INVALID_NAF_ID"),
+    (0xc002_0041, "RPC_NT_CANNOT_SUPPORT"),
+    (0xc002_0042, "RPC_NT_NO_CONTEXT_AVAILABLE"),
+    (0xc002_0043, "RPC_NT_INTERNAL_ERROR"),
+    (0xc002_0044, "RPC_NT_ZERO_DIVIDE"),
+    (0xc002_0045, "RPC_NT_ADDRESS_ERROR"),
+    (0xc002_0046, "RPC_NT_FP_DIV_ZERO"),
+    (0xc002_0047, "RPC_NT_FP_UNDERFLOW"),
+    (0xc002_0048, "RPC_NT_FP_OVERFLOW"),
+    (0xc002_0049, "RPC_NT_CALL_IN_PROGRESS"),
+    (0xc002_004a, "RPC_NT_NO_MORE_BINDINGS"),
+    (0xc002_004b, "RPC_NT_GROUP_MEMBER_NOT_FOUND"),
+    (0xc002_004c, "EPT_NT_CANT_CREATE"),
+    (0xc002_004d, "RPC_NT_INVALID_OBJECT"),
+    (0xc002_004f, "RPC_NT_NO_INTERFACES"),
+    (0xc002_0050, "RPC_NT_CALL_CANCELLED"),
+    (0xc002_0051, "RPC_NT_BINDING_INCOMPLETE"),
+    (0xc002_0052, "RPC_NT_COMM_FAILURE"),
+    (0xc002_0053, "RPC_NT_UNSUPPORTED_AUTHN_LEVEL"),
+    (0xc002_0054, "RPC_NT_NO_PRINC_NAME"),
+    (0xc002_0055, "RPC_NT_NOT_RPC_ERROR"),
+    (0xc002_0057, "RPC_NT_SEC_PKG_ERROR"),
+    (0xc002_0058, "RPC_NT_NOT_CANCELLED"),
+    (0xc002_0062, "RPC_NT_INVALID_ASYNC_HANDLE"),
+    (0xc002_0063, "RPC_NT_INVALID_ASYNC_CALL"),
+    (0xc002_0064, "RPC_NT_PROXY_ACCESS_DENIED"),
+    (0xc002_0065, "RPC_NT_COOKIE_AUTH_FAILED"),
+    (0xc003_0001, "RPC_NT_NO_MORE_ENTRIES"),
+    (0xc003_0002, "RPC_NT_SS_CHAR_TRANS_OPEN_FAIL"),
+    (0xc003_0003, "RPC_NT_SS_CHAR_TRANS_SHORT_FILE"),
+    (0xc003_0004, "RPC_NT_SS_IN_NULL_CONTEXT"),
+    (0xc003_0005, "RPC_NT_SS_CONTEXT_MISMATCH"),
+    (0xc003_0006, "RPC_NT_SS_CONTEXT_DAMAGED"),
+    (0xc003_0007, "RPC_NT_SS_HANDLES_MISMATCH"),
+    (0xc003_0008, "RPC_NT_SS_CANNOT_GET_CALL_HANDLE"),
+    (0xc003_0009, "RPC_NT_NULL_REF_POINTER"),
+    (0xc003_000a, "RPC_NT_ENUM_VALUE_OUT_OF_RANGE"),
+    (0xc003_000b, "RPC_NT_BYTE_COUNT_TOO_SMALL"),
+    (0xc003_000c, "RPC_NT_BAD_STUB_DATA"),
+    (0xc003_0059, "RPC_NT_INVALID_ES_ACTION"),
+    (0xc003_005a, "RPC_NT_WRONG_ES_VERSION"),
+    (0xc003_005b, "RPC_NT_WRONG_STUB_VERSION"),
+    (0xc003_005c, "RPC_NT_INVALID_PIPE_OBJECT"),
+    (0xc003_005d, "RPC_NT_INVALID_PIPE_OPERATION"),
+    (0xc003_005e, "RPC_NT_WRONG_PIPE_VERSION"),
+    (0xc003_005f, "RPC_NT_PIPE_CLOSED"),
+    (0xc003_0060, "RPC_NT_PIPE_DISCIPLINE_ERROR"),
+    (0xc003_0061, "RPC_NT_PIPE_EMPTY"),
+    (0xc004_0035, "STATUS_PNP_BAD_MPS_TABLE"),
+    (0xc004_0036, "STATUS_PNP_TRANSLATION_FAILED"),
+    (0xc004_0037, "STATUS_PNP_IRQ_TRANSLATION_FAILED"),
+    (0xc004_0038, "STATUS_PNP_INVALID_ID"),
+    (0xc004_0039, "STATUS_IO_REISSUE_AS_CACHED"),
+    (0xc00a_0001, "STATUS_CTX_WINSTATION_NAME_INVALID"),
+    (0xc00a_0002, "STATUS_CTX_INVALID_PD"),
+    (0xc00a_0003, "STATUS_CTX_PD_NOT_FOUND"),
+    (0xc00a_0006, "STATUS_CTX_CLOSE_PENDING"),
+    (0xc00a_0007, "STATUS_CTX_NO_OUTBUF"),
+    (0xc00a_0008, "STATUS_CTX_MODEM_INF_NOT_FOUND"),
+    (0xc00a_0009, "STATUS_CTX_INVALID_MODEMNAME"),
+    (0xc00a_000a, "STATUS_CTX_RESPONSE_ERROR"),
+    (0xc00a_000b, "STATUS_CTX_MODEM_RESPONSE_TIMEOUT"),
+    (0xc00a_000c, "STATUS_CTX_MODEM_RESPONSE_NO_CARRIER"),
+    (0xc00a_000d, "STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE"),
+    (0xc00a_000e, "STATUS_CTX_MODEM_RESPONSE_BUSY"),
+    (0xc00a_000f, "STATUS_CTX_MODEM_RESPONSE_VOICE"),
+    (0xc00a_0010, "STATUS_CTX_TD_ERROR"),
+    (0xc00a_0012, "STATUS_CTX_LICENSE_CLIENT_INVALID"),
+    (0xc00a_0013, "STATUS_CTX_LICENSE_NOT_AVAILABLE"),
+    (0xc00a_0014, "STATUS_CTX_LICENSE_EXPIRED"),
+    (0xc00a_0015, "STATUS_CTX_WINSTATION_NOT_FOUND"),
+    (0xc00a_0016, "STATUS_CTX_WINSTATION_NAME_COLLISION"),
+    (0xc00a_0017, "STATUS_CTX_WINSTATION_BUSY"),
+    (0xc00a_0018, "STATUS_CTX_BAD_VIDEO_MODE"),
+    (0xc00a_0022, "STATUS_CTX_GRAPHICS_INVALID"),
+    (0xc00a_0024, "STATUS_CTX_NOT_CONSOLE"),
+    (0xc00a_0026, "STATUS_CTX_CLIENT_QUERY_TIMEOUT"),
+    (0xc00a_0027, "STATUS_CTX_CONSOLE_DISCONNECT"),
+    (0xc00a_0028, "STATUS_CTX_CONSOLE_CONNECT"),
+    (0xc00a_002a, "STATUS_CTX_SHADOW_DENIED"),
+    (0xc00a_002b, "STATUS_CTX_WINSTATION_ACCESS_DENIED"),
+    (0xc00a_002e, "STATUS_CTX_INVALID_WD"),
+    (0xc00a_002f, "STATUS_CTX_WD_NOT_FOUND"),
+    (0xc00a_0030, "STATUS_CTX_SHADOW_INVALID"),
+    (0xc00a_0031, "STATUS_CTX_SHADOW_DISABLED"),
+    (0xc00a_0032, "STATUS_RDP_PROTOCOL_ERROR"),
+    (0xc00a_0033, "STATUS_CTX_CLIENT_LICENSE_NOT_SET"),
+    (0xc00a_0034, "STATUS_CTX_CLIENT_LICENSE_IN_USE"),
+    (0xc00a_0035, "STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE"),
+    (0xc00a_0036, "STATUS_CTX_SHADOW_NOT_RUNNING"),
+    (0xc00a_0037, "STATUS_CTX_LOGON_DISABLED"),
+    (0xc00a_0038, "STATUS_CTX_SECURITY_LAYER_ERROR"),
+    (0xc00a_0039, "STATUS_TS_INCOMPATIBLE_SESSIONS"),
+    (0xc00a_003a, "STATUS_TS_VIDEO_SUBSYSTEM_ERROR"),
+    (0xc00b_0001, "STATUS_MUI_FILE_NOT_FOUND"),
+    (0xc00b_0002, "STATUS_MUI_INVALID_FILE"),
+    (0xc00b_0003, "STATUS_MUI_INVALID_RC_CONFIG"),
+    (0xc00b_0004, "STATUS_MUI_INVALID_LOCALE_NAME"),
+    (0xc00b_0005, "STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME"),
+    (0xc00b_0006, "STATUS_MUI_FILE_NOT_LOADED"),
+    (0xc00b_0007, "STATUS_RESOURCE_ENUM_USER_STOP"),
+    (0xc013_0001, "STATUS_CLUSTER_INVALID_NODE"),
+    (0xc013_0002, "STATUS_CLUSTER_NODE_EXISTS"),
+    (0xc013_0003, "STATUS_CLUSTER_JOIN_IN_PROGRESS"),
+    (0xc013_0004, "STATUS_CLUSTER_NODE_NOT_FOUND"),
+    (0xc013_0005, "STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND"),
+    (0xc013_0006, "STATUS_CLUSTER_NETWORK_EXISTS"),
+    (0xc013_0007, "STATUS_CLUSTER_NETWORK_NOT_FOUND"),
+    (0xc013_0008, "STATUS_CLUSTER_NETINTERFACE_EXISTS"),
+    (0xc013_0009, "STATUS_CLUSTER_NETINTERFACE_NOT_FOUND"),
+    (0xc013_000a, "STATUS_CLUSTER_INVALID_REQUEST"),
+    (0xc013_000b, "STATUS_CLUSTER_INVALID_NETWORK_PROVIDER"),
+    (0xc013_000c, "STATUS_CLUSTER_NODE_DOWN"),
+    (0xc013_000d, "STATUS_CLUSTER_NODE_UNREACHABLE"),
+    (0xc013_000e, "STATUS_CLUSTER_NODE_NOT_MEMBER"),
+    (0xc013_000f, "STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS"),
+    (0xc013_0010, "STATUS_CLUSTER_INVALID_NETWORK"),
+    (0xc013_0011, "STATUS_CLUSTER_NO_NET_ADAPTERS"),
+    (0xc013_0012, "STATUS_CLUSTER_NODE_UP"),
+    (0xc013_0013, "STATUS_CLUSTER_NODE_PAUSED"),
+    (0xc013_0014, "STATUS_CLUSTER_NODE_NOT_PAUSED"),
+    (0xc013_0015, "STATUS_CLUSTER_NO_SECURITY_CONTEXT"),
+    (0xc013_0016, "STATUS_CLUSTER_NETWORK_NOT_INTERNAL"),
+    (0xc013_0017, "STATUS_CLUSTER_POISONED"),
+    (0xc013_0018, "STATUS_CLUSTER_NON_CSV_PATH"),
+    (0xc013_0019, "STATUS_CLUSTER_CSV_VOLUME_NOT_LOCAL"),
+    (0xc013_0020, "STATUS_CLUSTER_CSV_READ_OPLOCK_BREAK_IN_PROGRESS"),
+    (0xc013_0021, "STATUS_CLUSTER_CSV_AUTO_PAUSE_ERROR"),
+    (0xc013_0022, "STATUS_CLUSTER_CSV_REDIRECTED"),
+    (0xc013_0023, "STATUS_CLUSTER_CSV_NOT_REDIRECTED"),
+    (0xc013_0024, "STATUS_CLUSTER_CSV_VOLUME_DRAINING"),
+    (0xc013_0025, "STATUS_CLUSTER_CSV_SNAPSHOT_CREATION_IN_PROGRESS"),
+    (0xc013_0026, "STATUS_CLUSTER_CSV_VOLUME_DRAINING_SUCCEEDED_DOWNLEVEL"),
+    (0xc013_0027, "STATUS_CLUSTER_CSV_NO_SNAPSHOTS"),
+    (0xc013_0028, "STATUS_CSV_IO_PAUSE_TIMEOUT"),
+    (0xc013_0029, "STATUS_CLUSTER_CSV_INVALID_HANDLE"),
+    (0xc013_0030, "STATUS_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR"),
+    (0xc013_0031, "STATUS_CLUSTER_CAM_TICKET_REPLAY_DETECTED"),
+    (0xc014_0001, "STATUS_ACPI_INVALID_OPCODE"),
+    (0xc014_0002, "STATUS_ACPI_STACK_OVERFLOW"),
+    (0xc014_0003, "STATUS_ACPI_ASSERT_FAILED"),
+    (0xc014_0004, "STATUS_ACPI_INVALID_INDEX"),
+    (0xc014_0005, "STATUS_ACPI_INVALID_ARGUMENT"),
+    (0xc014_0006, "STATUS_ACPI_FATAL"),
+    (0xc014_0007, "STATUS_ACPI_INVALID_SUPERNAME"),
+    (0xc014_0008, "STATUS_ACPI_INVALID_ARGTYPE"),
+    (0xc014_0009, "STATUS_ACPI_INVALID_OBJTYPE"),
+    (0xc014_000a, "STATUS_ACPI_INVALID_TARGETTYPE"),
+    (0xc014_000b, "STATUS_ACPI_INCORRECT_ARGUMENT_COUNT"),
+    (0xc014_000c, "STATUS_ACPI_ADDRESS_NOT_MAPPED"),
+    (0xc014_000d, "STATUS_ACPI_INVALID_EVENTTYPE"),
+    (0xc014_000e, "STATUS_ACPI_HANDLER_COLLISION"),
+    (0xc014_000f, "STATUS_ACPI_INVALID_DATA"),
+    (0xc014_0010, "STATUS_ACPI_INVALID_REGION"),
+    (0xc014_0011, "STATUS_ACPI_INVALID_ACCESS_SIZE"),
+    (0xc014_0012, "STATUS_ACPI_ACQUIRE_GLOBAL_LOCK"),
+    (0xc014_0013, "STATUS_ACPI_ALREADY_INITIALIZED"),
+    (0xc014_0014, "STATUS_ACPI_NOT_INITIALIZED"),
+    (0xc014_0015, "STATUS_ACPI_INVALID_MUTEX_LEVEL"),
+    (0xc014_0016, "STATUS_ACPI_MUTEX_NOT_OWNED"),
+    (0xc014_0017, "STATUS_ACPI_MUTEX_NOT_OWNER"),
+    (0xc014_0018, "STATUS_ACPI_RS_ACCESS"),
+    (0xc014_0019, "STATUS_ACPI_INVALID_TABLE"),
+    (0xc014_0020, "STATUS_ACPI_REG_HANDLER_FAILED"),
+    (0xc014_0021, "STATUS_ACPI_POWER_REQUEST_FAILED"),
+    (0xc015_0001, "STATUS_SXS_SECTION_NOT_FOUND"),
+    (0xc015_0002, "STATUS_SXS_CANT_GEN_ACTCTX"),
+    (0xc015_0003, "STATUS_SXS_INVALID_ACTCTXDATA_FORMAT"),
+    (0xc015_0004, "STATUS_SXS_ASSEMBLY_NOT_FOUND"),
+    (0xc015_0005, "STATUS_SXS_MANIFEST_FORMAT_ERROR"),
+    (0xc015_0006, "STATUS_SXS_MANIFEST_PARSE_ERROR"),
+    (0xc015_0007, "STATUS_SXS_ACTIVATION_CONTEXT_DISABLED"),
+    (0xc015_0008, "STATUS_SXS_KEY_NOT_FOUND"),
+    (0xc015_0009, "STATUS_SXS_VERSION_CONFLICT"),
+    (0xc015_000a, "STATUS_SXS_WRONG_SECTION_TYPE"),
+    (0xc015_000b, "STATUS_SXS_THREAD_QUERIES_DISABLED"),
+    (0xc015_000c, "STATUS_SXS_ASSEMBLY_MISSING"),
+    (0xc015_000e, "STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET"),
+    (0xc015_000f, "STATUS_SXS_EARLY_DEACTIVATION"),
+    (0xc015_0010, "STATUS_SXS_INVALID_DEACTIVATION"),
+    (0xc015_0011, "STATUS_SXS_MULTIPLE_DEACTIVATION"),
+    (0xc015_0012, "STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY"),
+    (0xc015_0013, "STATUS_SXS_PROCESS_TERMINATION_REQUESTED"),
+    (0xc015_0014, "STATUS_SXS_CORRUPT_ACTIVATION_STACK"),
+    (0xc015_0015, "STATUS_SXS_CORRUPTION"),
+    (0xc015_0016, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE"),
+    (0xc015_0017, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME"),
+    (0xc015_0018, "STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE"),
+    (0xc015_0019, "STATUS_SXS_IDENTITY_PARSE_ERROR"),
+    (0xc015_001a, "STATUS_SXS_COMPONENT_STORE_CORRUPT"),
+    (0xc015_001b, "STATUS_SXS_FILE_HASH_MISMATCH"),
+    (0xc015_001c, "STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT"),
+    (0xc015_001d, "STATUS_SXS_IDENTITIES_DIFFERENT"),
+    (0xc015_001e, "STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT"),
+    (0xc015_001f, "STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY"),
+    (0xc015_0020, "STATUS_ADVANCED_INSTALLER_FAILED"),
+    (0xc015_0021, "STATUS_XML_ENCODING_MISMATCH"),
+    (0xc015_0022, "STATUS_SXS_MANIFEST_TOO_BIG"),
+    (0xc015_0023, "STATUS_SXS_SETTING_NOT_REGISTERED"),
+    (0xc015_0024, "STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE"),
+    (0xc015_0025, "STATUS_SMI_PRIMITIVE_INSTALLER_FAILED"),
+    (0xc015_0026, "STATUS_GENERIC_COMMAND_FAILED"),
+    (0xc015_0027, "STATUS_SXS_FILE_HASH_MISSING"),
+    (0xc019_0001, "STATUS_TRANSACTIONAL_CONFLICT"),
+    (0xc019_0002, "STATUS_INVALID_TRANSACTION"),
+    (0xc019_0003, "STATUS_TRANSACTION_NOT_ACTIVE"),
+    (0xc019_0004, "STATUS_TM_INITIALIZATION_FAILED"),
+    (0xc019_0005, "STATUS_RM_NOT_ACTIVE"),
+    (0xc019_0006, "STATUS_RM_METADATA_CORRUPT"),
+    (0xc019_0007, "STATUS_TRANSACTION_NOT_JOINED"),
+    (0xc019_0008, "STATUS_DIRECTORY_NOT_RM"),
+    (0xc019_000a, "STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE"),
+    (0xc019_000b, "STATUS_LOG_RESIZE_INVALID_SIZE"),
+    (0xc019_000c, "STATUS_REMOTE_FILE_VERSION_MISMATCH"),
+    (0xc019_000f, "STATUS_CRM_PROTOCOL_ALREADY_EXISTS"),
+    (0xc019_0010, "STATUS_TRANSACTION_PROPAGATION_FAILED"),
+    (0xc019_0011, "STATUS_CRM_PROTOCOL_NOT_FOUND"),
+    (0xc019_0012, "STATUS_TRANSACTION_SUPERIOR_EXISTS"),
+    (0xc019_0013, "STATUS_TRANSACTION_REQUEST_NOT_VALID"),
+    (0xc019_0014, "STATUS_TRANSACTION_NOT_REQUESTED"),
+    (0xc019_0015, "STATUS_TRANSACTION_ALREADY_ABORTED"),
+    (0xc019_0016, "STATUS_TRANSACTION_ALREADY_COMMITTED"),
+    (0xc019_0017, "STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER"),
+    (0xc019_0018, "STATUS_CURRENT_TRANSACTION_NOT_VALID"),
+    (0xc019_0019, "STATUS_LOG_GROWTH_FAILED"),
+    (0xc019_0021, "STATUS_OBJECT_NO_LONGER_EXISTS"),
+    (0xc019_0022, "STATUS_STREAM_MINIVERSION_NOT_FOUND"),
+    (0xc019_0023, "STATUS_STREAM_MINIVERSION_NOT_VALID"),
+    (0xc019_0024, "STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION"),
+    (0xc019_0025, "STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT"),
+    (0xc019_0026, "STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS"),
+    (0xc019_0028, "STATUS_HANDLE_NO_LONGER_VALID"),
+    (0xc019_0030, "STATUS_LOG_CORRUPTION_DETECTED"),
+    (0xc019_0032, "STATUS_RM_DISCONNECTED"),
+    (0xc019_0033, "STATUS_ENLISTMENT_NOT_SUPERIOR"),
+    (0xc019_0036, "STATUS_FILE_IDENTITY_NOT_PERSISTENT"),
+    (0xc019_0037, "STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY"),
+    (0xc019_0038, "STATUS_CANT_CROSS_RM_BOUNDARY"),
+    (0xc019_0039, "STATUS_TXF_DIR_NOT_EMPTY"),
+    (0xc019_003a, "STATUS_INDOUBT_TRANSACTIONS_EXIST"),
+    (0xc019_003b, "STATUS_TM_VOLATILE"),
+    (0xc019_003c, "STATUS_ROLLBACK_TIMER_EXPIRED"),
+    (0xc019_003d, "STATUS_TXF_ATTRIBUTE_CORRUPT"),
+    (0xc019_003e, "STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xc019_003f, "STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED"),
+    (0xc019_0040, "STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE"),
+    (0xc019_0043, "STATUS_TRANSACTION_REQUIRED_PROMOTION"),
+    (0xc019_0044, "STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION"),
+    (0xc019_0045, "STATUS_TRANSACTIONS_NOT_FROZEN"),
+    (0xc019_0046, "STATUS_TRANSACTION_FREEZE_IN_PROGRESS"),
+    (0xc019_0047, "STATUS_NOT_SNAPSHOT_VOLUME"),
+    (0xc019_0048, "STATUS_NO_SAVEPOINT_WITH_OPEN_FILES"),
+    (0xc019_0049, "STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xc019_004a, "STATUS_TM_IDENTITY_MISMATCH"),
+    (0xc019_004b, "STATUS_FLOATED_SECTION"),
+    (0xc019_004c, "STATUS_CANNOT_ACCEPT_TRANSACTED_WORK"),
+    (0xc019_004d, "STATUS_CANNOT_ABORT_TRANSACTIONS"),
+    (0xc019_004e, "STATUS_TRANSACTION_NOT_FOUND"),
+    (0xc019_004f, "STATUS_RESOURCEMANAGER_NOT_FOUND"),
+    (0xc019_0050, "STATUS_ENLISTMENT_NOT_FOUND"),
+    (0xc019_0051, "STATUS_TRANSACTIONMANAGER_NOT_FOUND"),
+    (0xc019_0052, "STATUS_TRANSACTIONMANAGER_NOT_ONLINE"),
+    (0xc019_0053, "STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION"),
+    (0xc019_0054, "STATUS_TRANSACTION_NOT_ROOT"),
+    (0xc019_0055, "STATUS_TRANSACTION_OBJECT_EXPIRED"),
+    (0xc019_0056, "STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xc019_0057, "STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED"),
+    (0xc019_0058, "STATUS_TRANSACTION_RECORD_TOO_LONG"),
+    (0xc019_0059, "STATUS_NO_LINK_TRACKING_IN_TRANSACTION"),
+    (0xc019_005a, "STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION"),
+    (0xc019_005b, "STATUS_TRANSACTION_INTEGRITY_VIOLATED"),
+    (0xc019_005c, "STATUS_TRANSACTIONMANAGER_IDENTITY_MISMATCH"),
+    (0xc019_005d, "STATUS_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT"),
+    (0xc019_005e, "STATUS_TRANSACTION_MUST_WRITETHROUGH"),
+    (0xc019_005f, "STATUS_TRANSACTION_NO_SUPERIOR"),
+    (0xc019_0060, "STATUS_EXPIRED_HANDLE"),
+    (0xc019_0061, "STATUS_TRANSACTION_NOT_ENLISTED"),
+    (0xc01a_0001, "STATUS_LOG_SECTOR_INVALID"),
+    (0xc01a_0002, "STATUS_LOG_SECTOR_PARITY_INVALID"),
+    (0xc01a_0003, "STATUS_LOG_SECTOR_REMAPPED"),
+    (0xc01a_0004, "STATUS_LOG_BLOCK_INCOMPLETE"),
+    (0xc01a_0005, "STATUS_LOG_INVALID_RANGE"),
+    (0xc01a_0006, "STATUS_LOG_BLOCKS_EXHAUSTED"),
+    (0xc01a_0007, "STATUS_LOG_READ_CONTEXT_INVALID"),
+    (0xc01a_0008, "STATUS_LOG_RESTART_INVALID"),
+    (0xc01a_0009, "STATUS_LOG_BLOCK_VERSION"),
+    (0xc01a_000a, "STATUS_LOG_BLOCK_INVALID"),
+    (0xc01a_000b, "STATUS_LOG_READ_MODE_INVALID"),
+    (0xc01a_000d, "STATUS_LOG_METADATA_CORRUPT"),
+    (0xc01a_000e, "STATUS_LOG_METADATA_INVALID"),
+    (0xc01a_000f, "STATUS_LOG_METADATA_INCONSISTENT"),
+    (0xc01a_0010, "STATUS_LOG_RESERVATION_INVALID"),
+    (0xc01a_0011, "STATUS_LOG_CANT_DELETE"),
+    (0xc01a_0012, "STATUS_LOG_CONTAINER_LIMIT_EXCEEDED"),
+    (0xc01a_0013, "STATUS_LOG_START_OF_LOG"),
+    (0xc01a_0014, "STATUS_LOG_POLICY_ALREADY_INSTALLED"),
+    (0xc01a_0015, "STATUS_LOG_POLICY_NOT_INSTALLED"),
+    (0xc01a_0016, "STATUS_LOG_POLICY_INVALID"),
+    (0xc01a_0017, "STATUS_LOG_POLICY_CONFLICT"),
+    (0xc01a_0018, "STATUS_LOG_PINNED_ARCHIVE_TAIL"),
+    (0xc01a_0019, "STATUS_LOG_RECORD_NONEXISTENT"),
+    (0xc01a_001a, "STATUS_LOG_RECORDS_RESERVED_INVALID"),
+    (0xc01a_001b, "STATUS_LOG_SPACE_RESERVED_INVALID"),
+    (0xc01a_001c, "STATUS_LOG_TAIL_INVALID"),
+    (0xc01a_001d, "STATUS_LOG_FULL"),
+    (0xc01a_001e, "STATUS_LOG_MULTIPLEXED"),
+    (0xc01a_001f, "STATUS_LOG_DEDICATED"),
+    (0xc01a_0020, "STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS"),
+    (0xc01a_0021, "STATUS_LOG_ARCHIVE_IN_PROGRESS"),
+    (0xc01a_0022, "STATUS_LOG_EPHEMERAL"),
+    (0xc01a_0023, "STATUS_LOG_NOT_ENOUGH_CONTAINERS"),
+    (0xc01a_0024, "STATUS_LOG_CLIENT_ALREADY_REGISTERED"),
+    (0xc01a_0025, "STATUS_LOG_CLIENT_NOT_REGISTERED"),
+    (0xc01a_0026, "STATUS_LOG_FULL_HANDLER_IN_PROGRESS"),
+    (0xc01a_0027, "STATUS_LOG_CONTAINER_READ_FAILED"),
+    (0xc01a_0028, "STATUS_LOG_CONTAINER_WRITE_FAILED"),
+    (0xc01a_0029, "STATUS_LOG_CONTAINER_OPEN_FAILED"),
+    (0xc01a_002a, "STATUS_LOG_CONTAINER_STATE_INVALID"),
+    (0xc01a_002b, "STATUS_LOG_STATE_INVALID"),
+    (0xc01a_002c, "STATUS_LOG_PINNED"),
+    (0xc01a_002d, "STATUS_LOG_METADATA_FLUSH_FAILED"),
+    (0xc01a_002e, "STATUS_LOG_INCONSISTENT_SECURITY"),
+    (0xc01a_002f, "STATUS_LOG_APPENDED_FLUSH_FAILED"),
+    (0xc01a_0030, "STATUS_LOG_PINNED_RESERVATION"),
+    (0xc01b_00ea, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD"),
+    (0xc01c_0001, "STATUS_FLT_NO_HANDLER_DEFINED"),
+    (0xc01c_0002, "STATUS_FLT_CONTEXT_ALREADY_DEFINED"),
+    (0xc01c_0003, "STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST"),
+    (0xc01c_0004, "STATUS_FLT_DISALLOW_FAST_IO"),
+    (0xc01c_0005, "STATUS_FLT_INVALID_NAME_REQUEST"),
+    (0xc01c_0006, "STATUS_FLT_NOT_SAFE_TO_POST_OPERATION"),
+    (0xc01c_0007, "STATUS_FLT_NOT_INITIALIZED"),
+    (0xc01c_0008, "STATUS_FLT_FILTER_NOT_READY"),
+    (0xc01c_0009, "STATUS_FLT_POST_OPERATION_CLEANUP"),
+    (0xc01c_000a, "STATUS_FLT_INTERNAL_ERROR"),
+    (0xc01c_000b, "STATUS_FLT_DELETING_OBJECT"),
+    (0xc01c_000c, "STATUS_FLT_MUST_BE_NONPAGED_POOL"),
+    (0xc01c_000d, "STATUS_FLT_DUPLICATE_ENTRY"),
+    (0xc01c_000e, "STATUS_FLT_CBDQ_DISABLED"),
+    (0xc01c_000f, "STATUS_FLT_DO_NOT_ATTACH"),
+    (0xc01c_0010, "STATUS_FLT_DO_NOT_DETACH"),
+    (0xc01c_0011, "STATUS_FLT_INSTANCE_ALTITUDE_COLLISION"),
+    (0xc01c_0012, "STATUS_FLT_INSTANCE_NAME_COLLISION"),
+    (0xc01c_0013, "STATUS_FLT_FILTER_NOT_FOUND"),
+    (0xc01c_0014, "STATUS_FLT_VOLUME_NOT_FOUND"),
+    (0xc01c_0015, "STATUS_FLT_INSTANCE_NOT_FOUND"),
+    (0xc01c_0016, "STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND"),
+    (0xc01c_0017, "STATUS_FLT_INVALID_CONTEXT_REGISTRATION"),
+    (0xc01c_0018, "STATUS_FLT_NAME_CACHE_MISS"),
+    (0xc01c_0019, "STATUS_FLT_NO_DEVICE_OBJECT"),
+    (0xc01c_001a, "STATUS_FLT_VOLUME_ALREADY_MOUNTED"),
+    (0xc01c_001b, "STATUS_FLT_ALREADY_ENLISTED"),
+    (0xc01c_001c, "STATUS_FLT_CONTEXT_ALREADY_LINKED"),
+    (0xc01c_0020, "STATUS_FLT_NO_WAITER_FOR_REPLY"),
+    (0xc01c_0023, "STATUS_FLT_REGISTRATION_BUSY"),
+    (0xc01c_0024, "STATUS_FLT_WCOS_NOT_SUPPORTED"),
+    (0xc01d_0001, "STATUS_MONITOR_NO_DESCRIPTOR"),
+    (0xc01d_0002, "STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT"),
+    (0xc01d_0003, "STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM"),
+    (0xc01d_0004, "STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK"),
+    (0xc01d_0005, "STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED"),
+    (0xc01d_0006, "STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK"),
+    (0xc01d_0007, "STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK"),
+    (0xc01d_0008, "STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA"),
+    (0xc01d_0009, "STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK"),
+    (0xc01d_000a, "STATUS_MONITOR_INVALID_MANUFACTURE_DATE"),
+    (0xc01e_0000, "STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER"),
+    (0xc01e_0001, "STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER"),
+    (0xc01e_0002, "STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER"),
+    (0xc01e_0003, "STATUS_GRAPHICS_ADAPTER_WAS_RESET"),
+    (0xc01e_0004, "STATUS_GRAPHICS_INVALID_DRIVER_MODEL"),
+    (0xc01e_0005, "STATUS_GRAPHICS_PRESENT_MODE_CHANGED"),
+    (0xc01e_0006, "STATUS_GRAPHICS_PRESENT_OCCLUDED"),
+    (0xc01e_0007, "STATUS_GRAPHICS_PRESENT_DENIED"),
+    (0xc01e_0008, "STATUS_GRAPHICS_CANNOTCOLORCONVERT"),
+    (0xc01e_0009, "STATUS_GRAPHICS_DRIVER_MISMATCH"),
+    (0xc01e_000b, "STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED"),
+    (0xc01e_000c, "STATUS_GRAPHICS_PRESENT_UNOCCLUDED"),
+    (0xc01e_000d, "STATUS_GRAPHICS_WINDOWDC_NOT_AVAILABLE"),
+    (0xc01e_000e, "STATUS_GRAPHICS_WINDOWLESS_PRESENT_DISABLED"),
+    (0xc01e_000f, "STATUS_GRAPHICS_PRESENT_INVALID_WINDOW"),
+    (0xc01e_0010, "STATUS_GRAPHICS_PRESENT_BUFFER_NOT_BOUND"),
+    (0xc01e_0011, "STATUS_GRAPHICS_VAIL_STATE_CHANGED"),
+    (0xc01e_0012, "STATUS_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN"),
+    (0xc01e_0013, "STATUS_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED"),
+    (0xc01e_0018, "STATUS_GRAPHICS_MPO_ALLOCATION_UNPINNED"),
+    (0xc01e_0100, "STATUS_GRAPHICS_NO_VIDEO_MEMORY"),
+    (0xc01e_0101, "STATUS_GRAPHICS_CANT_LOCK_MEMORY"),
+    (0xc01e_0102, "STATUS_GRAPHICS_ALLOCATION_BUSY"),
+    (0xc01e_0103, "STATUS_GRAPHICS_TOO_MANY_REFERENCES"),
+    (0xc01e_0104, "STATUS_GRAPHICS_TRY_AGAIN_LATER"),
+    (0xc01e_0105, "STATUS_GRAPHICS_TRY_AGAIN_NOW"),
+    (0xc01e_0106, "STATUS_GRAPHICS_ALLOCATION_INVALID"),
+    (0xc01e_0107, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE"),
+    (0xc01e_0108, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED"),
+    (0xc01e_0109, "STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION"),
+    (0xc01e_0110, "STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE"),
+    (0xc01e_0111, "STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION"),
+    (0xc01e_0112, "STATUS_GRAPHICS_ALLOCATION_CLOSED"),
+    (0xc01e_0113, "STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE"),
+    (0xc01e_0114, "STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE"),
+    (0xc01e_0115, "STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE"),
+    (0xc01e_0116, "STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST"),
+    (0xc01e_0200, "STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE"),
+    (0xc01e_0300, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY"),
+    (0xc01e_0301, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED"),
+    (0xc01e_0302, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED"),
+    (0xc01e_0303, "STATUS_GRAPHICS_INVALID_VIDPN"),
+    (0xc01e_0304, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE"),
+    (0xc01e_0305, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET"),
+    (0xc01e_0306, "STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED"),
+    (0xc01e_0308, "STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET"),
+    (0xc01e_0309, "STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET"),
+    (0xc01e_030a, "STATUS_GRAPHICS_INVALID_FREQUENCY"),
+    (0xc01e_030b, "STATUS_GRAPHICS_INVALID_ACTIVE_REGION"),
+    (0xc01e_030c, "STATUS_GRAPHICS_INVALID_TOTAL_REGION"),
+    (0xc01e_0310, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE"),
+    (0xc01e_0311, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE"),
+    (0xc01e_0312, "STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET"),
+    (0xc01e_0313, "STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY"),
+    (0xc01e_0314, "STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET"),
+    (0xc01e_0315, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET"),
+    (0xc01e_0316, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET"),
+    (0xc01e_0317, "STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET"),
+    (0xc01e_0318, "STATUS_GRAPHICS_TARGET_ALREADY_IN_SET"),
+    (0xc01e_0319, "STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH"),
+    (0xc01e_031a, "STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY"),
+    (0xc01e_031b, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET"),
+    (0xc01e_031c, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE"),
+    (0xc01e_031d, "STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET"),
+    (0xc01e_031f, "STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET"),
+    (0xc01e_0320, "STATUS_GRAPHICS_STALE_MODESET"),
+    (0xc01e_0321, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET"),
+    (0xc01e_0322, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE"),
+    (0xc01e_0323, "STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN"),
+    (0xc01e_0324, "STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE"),
+    (0xc01e_0325, "STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION"),
+    (0xc01e_0326, "STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES"),
+    (0xc01e_0327, "STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY"),
+    (0xc01e_0328, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE"),
+    (0xc01e_0329, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET"),
+    (0xc01e_032a, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET"),
+    (0xc01e_032b, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR"),
+    (0xc01e_032c, "STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET"),
+    (0xc01e_032d, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET"),
+    (0xc01e_032e, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE"),
+    (0xc01e_032f, "STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE"),
+    (0xc01e_0330, "STATUS_GRAPHICS_RESOURCES_NOT_RELATED"),
+    (0xc01e_0331, "STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE"),
+    (0xc01e_0332, "STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE"),
+    (0xc01e_0333, "STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET"),
+    (0xc01e_0334, "STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER"),
+    (0xc01e_0335, "STATUS_GRAPHICS_NO_VIDPNMGR"),
+    (0xc01e_0336, "STATUS_GRAPHICS_NO_ACTIVE_VIDPN"),
+    (0xc01e_0337, "STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY"),
+    (0xc01e_0338, "STATUS_GRAPHICS_MONITOR_NOT_CONNECTED"),
+    (0xc01e_0339, "STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY"),
+    (0xc01e_033a, "STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE"),
+    (0xc01e_033b, "STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE"),
+    (0xc01e_033c, "STATUS_GRAPHICS_INVALID_STRIDE"),
+    (0xc01e_033d, "STATUS_GRAPHICS_INVALID_PIXELFORMAT"),
+    (0xc01e_033e, "STATUS_GRAPHICS_INVALID_COLORBASIS"),
+    (0xc01e_033f, "STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE"),
+    (0xc01e_0340, "STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY"),
+    (0xc01e_0341, "STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT"),
+    (0xc01e_0342, "STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE"),
+    (0xc01e_0343, "STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN"),
+    (0xc01e_0344, "STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL"),
+    (0xc01e_0345, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION"),
+    (0xc01e_0346, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED"),
+    (0xc01e_0347, "STATUS_GRAPHICS_INVALID_GAMMA_RAMP"),
+    (0xc01e_0348, "STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED"),
+    (0xc01e_0349, "STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED"),
+    (0xc01e_034a, "STATUS_GRAPHICS_MODE_NOT_IN_MODESET"),
+    (0xc01e_034d, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON"),
+    (0xc01e_034e, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE"),
+    (0xc01e_034f, "STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE"),
+    (0xc01e_0350, "STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS"),
+    (0xc01e_0352, "STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING"),
+    (0xc01e_0353, "STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED"),
+    (0xc01e_0354, "STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS"),
+    (0xc01e_0355, "STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT"),
+    (0xc01e_0356, "STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM"),
+    (0xc01e_0357, "STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN"),
+    (0xc01e_0358, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT"),
+    (0xc01e_0359, "STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED"),
+    (0xc01e_035a, "STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION"),
+    (0xc01e_035b, "STATUS_GRAPHICS_INVALID_CLIENT_TYPE"),
+    (0xc01e_035c, "STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET"),
+    (0xc01e_0400, "STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED"),
+    (0xc01e_0401, "STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED"),
+    (0xc01e_0430, "STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER"),
+    (0xc01e_0431, "STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED"),
+    (0xc01e_0432, "STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED"),
+    (0xc01e_0433, "STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY"),
+    (0xc01e_0434, "STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED"),
+    (0xc01e_0435, "STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON"),
+    (0xc01e_0436, "STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE"),
+    (0xc01e_0438, "STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER"),
+    (0xc01e_043b, "STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED"),
+    (0xc01e_0500, "STATUS_GRAPHICS_OPM_NOT_SUPPORTED"),
+    (0xc01e_0501, "STATUS_GRAPHICS_COPP_NOT_SUPPORTED"),
+    (0xc01e_0502, "STATUS_GRAPHICS_UAB_NOT_SUPPORTED"),
+    (0xc01e_0503, "STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS"),
+    (0xc01e_0505, "STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST"),
+    (0xc01e_050b, "STATUS_GRAPHICS_OPM_INTERNAL_ERROR"),
+    (0xc01e_050c, "STATUS_GRAPHICS_OPM_INVALID_HANDLE"),
+    (0xc01e_050e, "STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH"),
+    (0xc01e_050f, "STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED"),
+    (0xc01e_0510, "STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED"),
+    (0xc01e_0511, "STATUS_GRAPHICS_PVP_HFS_FAILED"),
+    (0xc01e_0512, "STATUS_GRAPHICS_OPM_INVALID_SRM"),
+    (0xc01e_0513, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP"),
+    (0xc01e_0514, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP"),
+    (0xc01e_0515, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA"),
+    (0xc01e_0516, "STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET"),
+    (0xc01e_0517, "STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH"),
+    (0xc01e_0518, "STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE"),
+    (0xc01e_051a, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS"),
+    (0xc01e_051c, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS"),
+    (0xc01e_051d, "STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST"),
+    (0xc01e_051e, "STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR"),
+    (0xc01e_051f, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS"),
+    (0xc01e_0520, "STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED"),
+    (0xc01e_0521, "STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST"),
+    (0xc01e_0580, "STATUS_GRAPHICS_I2C_NOT_SUPPORTED"),
+    (0xc01e_0581, "STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST"),
+    (0xc01e_0582, "STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA"),
+    (0xc01e_0583, "STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA"),
+    (0xc01e_0584, "STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED"),
+    (0xc01e_0585, "STATUS_GRAPHICS_DDCCI_INVALID_DATA"),
+    (0xc01e_0586, "STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE"),
+    (0xc01e_0587, "STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING"),
+    (0xc01e_0588, "STATUS_GRAPHICS_MCA_INTERNAL_ERROR"),
+    (0xc01e_0589, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND"),
+    (0xc01e_058a, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH"),
+    (0xc01e_058b, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM"),
+    (0xc01e_058c, "STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE"),
+    (0xc01e_058d, "STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS"),
+    (0xc01e_05e0, "STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED"),
+    (0xc01e_05e1, "STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME"),
+    (0xc01e_05e2, "STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP"),
+    (0xc01e_05e3, "STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED"),
+    (0xc01e_05e4, "STATUS_GRAPHICS_INVALID_POINTER"),
+    (0xc01e_05e5, "STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE"),
+    (0xc01e_05e6, "STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL"),
+    (0xc01e_05e7, "STATUS_GRAPHICS_INTERNAL_ERROR"),
+    (0xc01e_05e8, "STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS"),
+    (0xc021_0000, "STATUS_FVE_LOCKED_VOLUME"),
+    (0xc021_0001, "STATUS_FVE_NOT_ENCRYPTED"),
+    (0xc021_0002, "STATUS_FVE_BAD_INFORMATION"),
+    (0xc021_0003, "STATUS_FVE_TOO_SMALL"),
+    (0xc021_0004, "STATUS_FVE_FAILED_WRONG_FS"),
+    (0xc021_0005, "STATUS_FVE_BAD_PARTITION_SIZE"),
+    (0xc021_0006, "STATUS_FVE_FS_NOT_EXTENDED"),
+    (0xc021_0007, "STATUS_FVE_FS_MOUNTED"),
+    (0xc021_0008, "STATUS_FVE_NO_LICENSE"),
+    (0xc021_0009, "STATUS_FVE_ACTION_NOT_ALLOWED"),
+    (0xc021_000a, "STATUS_FVE_BAD_DATA"),
+    (0xc021_000b, "STATUS_FVE_VOLUME_NOT_BOUND"),
+    (0xc021_000c, "STATUS_FVE_NOT_DATA_VOLUME"),
+    (0xc021_000d, "STATUS_FVE_CONV_READ_ERROR"),
+    (0xc021_000e, "STATUS_FVE_CONV_WRITE_ERROR"),
+    (0xc021_000f, "STATUS_FVE_OVERLAPPED_UPDATE"),
+    (0xc021_0010, "STATUS_FVE_FAILED_SECTOR_SIZE"),
+    (0xc021_0011, "STATUS_FVE_FAILED_AUTHENTICATION"),
+    (0xc021_0012, "STATUS_FVE_NOT_OS_VOLUME"),
+    (0xc021_0013, "STATUS_FVE_KEYFILE_NOT_FOUND"),
+    (0xc021_0014, "STATUS_FVE_KEYFILE_INVALID"),
+    (0xc021_0015, "STATUS_FVE_KEYFILE_NO_VMK"),
+    (0xc021_0016, "STATUS_FVE_TPM_DISABLED"),
+    (0xc021_0017, "STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO"),
+    (0xc021_0018, "STATUS_FVE_TPM_INVALID_PCR"),
+    (0xc021_0019, "STATUS_FVE_TPM_NO_VMK"),
+    (0xc021_001a, "STATUS_FVE_PIN_INVALID"),
+    (0xc021_001b, "STATUS_FVE_AUTH_INVALID_APPLICATION"),
+    (0xc021_001c, "STATUS_FVE_AUTH_INVALID_CONFIG"),
+    (0xc021_001d, "STATUS_FVE_DEBUGGER_ENABLED"),
+    (0xc021_001e, "STATUS_FVE_DRY_RUN_FAILED"),
+    (0xc021_001f, "STATUS_FVE_BAD_METADATA_POINTER"),
+    (0xc021_0020, "STATUS_FVE_OLD_METADATA_COPY"),
+    (0xc021_0021, "STATUS_FVE_REBOOT_REQUIRED"),
+    (0xc021_0022, "STATUS_FVE_RAW_ACCESS"),
+    (0xc021_0023, "STATUS_FVE_RAW_BLOCKED"),
+    (0xc021_0024, "STATUS_FVE_NO_AUTOUNLOCK_MASTER_KEY"),
+    (0xc021_0025, "STATUS_FVE_MOR_FAILED"),
+    (0xc021_0026, "STATUS_FVE_NO_FEATURE_LICENSE"),
+    (0xc021_0027, "STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED"),
+    (0xc021_0028, "STATUS_FVE_CONV_RECOVERY_FAILED"),
+    (0xc021_0029, "STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG"),
+    (0xc021_002a, "STATUS_FVE_INVALID_DATUM_TYPE"),
+    (0xc021_0030, "STATUS_FVE_VOLUME_TOO_SMALL"),
+    (0xc021_0031, "STATUS_FVE_ENH_PIN_INVALID"),
+    (0xc021_0032, "STATUS_FVE_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE"),
+    (0xc021_0033, "STATUS_FVE_WIPE_NOT_ALLOWED_ON_TP_STORAGE"),
+    (0xc021_0034, "STATUS_FVE_NOT_ALLOWED_ON_CSV_STACK"),
+    (0xc021_0035, "STATUS_FVE_NOT_ALLOWED_ON_CLUSTER"),
+    (0xc021_0036, "STATUS_FVE_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING"),
+    (0xc021_0037, "STATUS_FVE_WIPE_CANCEL_NOT_APPLICABLE"),
+    (0xc021_0038, "STATUS_FVE_EDRIVE_DRY_RUN_FAILED"),
+    (0xc021_0039, "STATUS_FVE_SECUREBOOT_DISABLED"),
+    (0xc021_003a, "STATUS_FVE_SECUREBOOT_CONFIG_CHANGE"),
+    (0xc021_003b, "STATUS_FVE_DEVICE_LOCKEDOUT"),
+    (0xc021_003c, "STATUS_FVE_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT"),
+    (0xc021_003d, "STATUS_FVE_NOT_DE_VOLUME"),
+    (0xc021_003e, "STATUS_FVE_PROTECTION_DISABLED"),
+    (0xc021_003f, "STATUS_FVE_PROTECTION_CANNOT_BE_DISABLED"),
+    (0xc021_0040, "STATUS_FVE_OSV_KSR_NOT_ALLOWED"),
+    (0xc021_0041, "STATUS_FVE_EDRIVE_BAND_ENUMERATION_FAILED"),
+    (0xc021_0042, "STATUS_FVE_POLICY_ON_RDV_EXCLUSION_LIST"),
+    (0xc021_0043, "STATUS_FVE_DATASET_FULL"),
+    (0xc021_0044, "STATUS_FVE_METADATA_FULL"),
+    (0xc022_0001, "STATUS_FWP_CALLOUT_NOT_FOUND"),
+    (0xc022_0002, "STATUS_FWP_CONDITION_NOT_FOUND"),
+    (0xc022_0003, "STATUS_FWP_FILTER_NOT_FOUND"),
+    (0xc022_0004, "STATUS_FWP_LAYER_NOT_FOUND"),
+    (0xc022_0005, "STATUS_FWP_PROVIDER_NOT_FOUND"),
+    (0xc022_0006, "STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND"),
+    (0xc022_0007, "STATUS_FWP_SUBLAYER_NOT_FOUND"),
+    (0xc022_0008, "STATUS_FWP_NOT_FOUND"),
+    (0xc022_0009, "STATUS_FWP_ALREADY_EXISTS"),
+    (0xc022_000a, "STATUS_FWP_IN_USE"),
+    (0xc022_000b, "STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS"),
+    (0xc022_000c, "STATUS_FWP_WRONG_SESSION"),
+    (0xc022_000d, "STATUS_FWP_NO_TXN_IN_PROGRESS"),
+    (0xc022_000e, "STATUS_FWP_TXN_IN_PROGRESS"),
+    (0xc022_000f, "STATUS_FWP_TXN_ABORTED"),
+    (0xc022_0010, "STATUS_FWP_SESSION_ABORTED"),
+    (0xc022_0011, "STATUS_FWP_INCOMPATIBLE_TXN"),
+    (0xc022_0012, "STATUS_FWP_TIMEOUT"),
+    (0xc022_0013, "STATUS_FWP_NET_EVENTS_DISABLED"),
+    (0xc022_0014, "STATUS_FWP_INCOMPATIBLE_LAYER"),
+    (0xc022_0015, "STATUS_FWP_KM_CLIENTS_ONLY"),
+    (0xc022_0016, "STATUS_FWP_LIFETIME_MISMATCH"),
+    (0xc022_0017, "STATUS_FWP_BUILTIN_OBJECT"),
+    (0xc022_0018, "STATUS_FWP_TOO_MANY_CALLOUTS"),
+    (0xc022_0019, "STATUS_FWP_NOTIFICATION_DROPPED"),
+    (0xc022_001a, "STATUS_FWP_TRAFFIC_MISMATCH"),
+    (0xc022_001b, "STATUS_FWP_INCOMPATIBLE_SA_STATE"),
+    (0xc022_001c, "STATUS_FWP_NULL_POINTER"),
+    (0xc022_001d, "STATUS_FWP_INVALID_ENUMERATOR"),
+    (0xc022_001e, "STATUS_FWP_INVALID_FLAGS"),
+    (0xc022_001f, "STATUS_FWP_INVALID_NET_MASK"),
+    (0xc022_0020, "STATUS_FWP_INVALID_RANGE"),
+    (0xc022_0021, "STATUS_FWP_INVALID_INTERVAL"),
+    (0xc022_0022, "STATUS_FWP_ZERO_LENGTH_ARRAY"),
+    (0xc022_0023, "STATUS_FWP_NULL_DISPLAY_NAME"),
+    (0xc022_0024, "STATUS_FWP_INVALID_ACTION_TYPE"),
+    (0xc022_0025, "STATUS_FWP_INVALID_WEIGHT"),
+    (0xc022_0026, "STATUS_FWP_MATCH_TYPE_MISMATCH"),
+    (0xc022_0027, "STATUS_FWP_TYPE_MISMATCH"),
+    (0xc022_0028, "STATUS_FWP_OUT_OF_BOUNDS"),
+    (0xc022_0029, "STATUS_FWP_RESERVED"),
+    (0xc022_002a, "STATUS_FWP_DUPLICATE_CONDITION"),
+    (0xc022_002b, "STATUS_FWP_DUPLICATE_KEYMOD"),
+    (0xc022_002c, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER"),
+    (0xc022_002d, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER"),
+    (0xc022_002e, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER"),
+    (0xc022_002f, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT"),
+    (0xc022_0030, "STATUS_FWP_INCOMPATIBLE_AUTH_METHOD"),
+    (0xc022_0031, "STATUS_FWP_INCOMPATIBLE_DH_GROUP"),
+    (0xc022_0032, "STATUS_FWP_EM_NOT_SUPPORTED"),
+    (0xc022_0033, "STATUS_FWP_NEVER_MATCH"),
+    (0xc022_0034, "STATUS_FWP_PROVIDER_CONTEXT_MISMATCH"),
+    (0xc022_0035, "STATUS_FWP_INVALID_PARAMETER"),
+    (0xc022_0036, "STATUS_FWP_TOO_MANY_SUBLAYERS"),
+    (0xc022_0037, "STATUS_FWP_CALLOUT_NOTIFICATION_FAILED"),
+    (0xc022_0038, "STATUS_FWP_INVALID_AUTH_TRANSFORM"),
+    (0xc022_0039, "STATUS_FWP_INVALID_CIPHER_TRANSFORM"),
+    (0xc022_003a, "STATUS_FWP_INCOMPATIBLE_CIPHER_TRANSFORM"),
+    (0xc022_003b, "STATUS_FWP_INVALID_TRANSFORM_COMBINATION"),
+    (0xc022_003c, "STATUS_FWP_DUPLICATE_AUTH_METHOD"),
+    (0xc022_003d, "STATUS_FWP_INVALID_TUNNEL_ENDPOINT"),
+    (0xc022_003e, "STATUS_FWP_L2_DRIVER_NOT_READY"),
+    (0xc022_003f, "STATUS_FWP_KEY_DICTATOR_ALREADY_REGISTERED"),
+    (0xc022_0040, "STATUS_FWP_KEY_DICTATION_INVALID_KEYING_MATERIAL"),
+    (0xc022_0041, "STATUS_FWP_CONNECTIONS_DISABLED"),
+    (0xc022_0042, "STATUS_FWP_INVALID_DNS_NAME"),
+    (0xc022_0043, "STATUS_FWP_STILL_ON"),
+    (0xc022_0044, "STATUS_FWP_IKEEXT_NOT_RUNNING"),
+    (0xc022_0100, "STATUS_FWP_TCPIP_NOT_READY"),
+    (0xc022_0101, "STATUS_FWP_INJECT_HANDLE_CLOSING"),
+    (0xc022_0102, "STATUS_FWP_INJECT_HANDLE_STALE"),
+    (0xc022_0103, "STATUS_FWP_CANNOT_PEND"),
+    (0xc022_0104, "STATUS_FWP_DROP_NOICMP"),
+    (0xc023_0002, "STATUS_NDIS_CLOSING"),
+    (0xc023_0004, "STATUS_NDIS_BAD_VERSION"),
+    (0xc023_0005, "STATUS_NDIS_BAD_CHARACTERISTICS"),
+    (0xc023_0006, "STATUS_NDIS_ADAPTER_NOT_FOUND"),
+    (0xc023_0007, "STATUS_NDIS_OPEN_FAILED"),
+    (0xc023_0008, "STATUS_NDIS_DEVICE_FAILED"),
+    (0xc023_0009, "STATUS_NDIS_MULTICAST_FULL"),
+    (0xc023_000a, "STATUS_NDIS_MULTICAST_EXISTS"),
+    (0xc023_000b, "STATUS_NDIS_MULTICAST_NOT_FOUND"),
+    (0xc023_000c, "STATUS_NDIS_REQUEST_ABORTED"),
+    (0xc023_000d, "STATUS_NDIS_RESET_IN_PROGRESS"),
+    (0xc023_000f, "STATUS_NDIS_INVALID_PACKET"),
+    (0xc023_0010, "STATUS_NDIS_INVALID_DEVICE_REQUEST"),
+    (0xc023_0011, "STATUS_NDIS_ADAPTER_NOT_READY"),
+    (0xc023_0014, "STATUS_NDIS_INVALID_LENGTH"),
+    (0xc023_0015, "STATUS_NDIS_INVALID_DATA"),
+    (0xc023_0016, "STATUS_NDIS_BUFFER_TOO_SHORT"),
+    (0xc023_0017, "STATUS_NDIS_INVALID_OID"),
+    (0xc023_0018, "STATUS_NDIS_ADAPTER_REMOVED"),
+    (0xc023_0019, "STATUS_NDIS_UNSUPPORTED_MEDIA"),
+    (0xc023_001a, "STATUS_NDIS_GROUP_ADDRESS_IN_USE"),
+    (0xc023_001b, "STATUS_NDIS_FILE_NOT_FOUND"),
+    (0xc023_001c, "STATUS_NDIS_ERROR_READING_FILE"),
+    (0xc023_001d, "STATUS_NDIS_ALREADY_MAPPED"),
+    (0xc023_001e, "STATUS_NDIS_RESOURCE_CONFLICT"),
+    (0xc023_001f, "STATUS_NDIS_MEDIA_DISCONNECTED"),
+    (0xc023_0022, "STATUS_NDIS_INVALID_ADDRESS"),
+    (0xc023_002a, "STATUS_NDIS_PAUSED"),
+    (0xc023_002b, "STATUS_NDIS_INTERFACE_NOT_FOUND"),
+    (0xc023_002c, "STATUS_NDIS_UNSUPPORTED_REVISION"),
+    (0xc023_002d, "STATUS_NDIS_INVALID_PORT"),
+    (0xc023_002e, "STATUS_NDIS_INVALID_PORT_STATE"),
+    (0xc023_002f, "STATUS_NDIS_LOW_POWER_STATE"),
+    (0xc023_0030, "STATUS_NDIS_REINIT_REQUIRED"),
+    (0xc023_0031, "STATUS_NDIS_NO_QUEUES"),
+    (0xc023_00bb, "STATUS_NDIS_NOT_SUPPORTED"),
+    (0xc023_100f, "STATUS_NDIS_OFFLOAD_POLICY"),
+    (0xc023_1012, "STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED"),
+    (0xc023_1013, "STATUS_NDIS_OFFLOAD_PATH_REJECTED"),
+    (0xc023_2000, "STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED"),
+    (0xc023_2001, "STATUS_NDIS_DOT11_MEDIA_IN_USE"),
+    (0xc023_2002, "STATUS_NDIS_DOT11_POWER_STATE_INVALID"),
+    (0xc023_2003, "STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL"),
+    (0xc023_2004, "STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL"),
+    (0xc023_2005, "STATUS_NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE"),
+    (0xc023_2006, "STATUS_NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE"),
+    (0xc023_2007, "STATUS_NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED"),
+    (0xc023_2008, "STATUS_NDIS_DOT11_AP_BAND_NOT_ALLOWED"),
+    (0xc024_0000, "STATUS_QUIC_HANDSHAKE_FAILURE"),
+    (0xc024_0001, "STATUS_QUIC_VER_NEG_FAILURE"),
+    (0xc024_0002, "STATUS_QUIC_USER_CANCELED"),
+    (0xc024_0003, "STATUS_QUIC_INTERNAL_ERROR"),
+    (0xc024_0004, "STATUS_QUIC_PROTOCOL_VIOLATION"),
+    (0xc024_0005, "STATUS_QUIC_CONNECTION_IDLE"),
+    (0xc024_0006, "STATUS_QUIC_CONNECTION_TIMEOUT"),
+    (0xc024_0007, "STATUS_QUIC_ALPN_NEG_FAILURE"),
+    (0xc029_0000, "STATUS_TPM_ERROR_MASK"),
+    (0xc029_0001, "STATUS_TPM_AUTHFAIL"),
+    (0xc029_0002, "STATUS_TPM_BADINDEX"),
+    (0xc029_0003, "STATUS_TPM_BAD_PARAMETER"),
+    (0xc029_0004, "STATUS_TPM_AUDITFAILURE"),
+    (0xc029_0005, "STATUS_TPM_CLEAR_DISABLED"),
+    (0xc029_0006, "STATUS_TPM_DEACTIVATED"),
+    (0xc029_0007, "STATUS_TPM_DISABLED"),
+    (0xc029_0008, "STATUS_TPM_DISABLED_CMD"),
+    (0xc029_0009, "STATUS_TPM_FAIL"),
+    (0xc029_000a, "STATUS_TPM_BAD_ORDINAL"),
+    (0xc029_000b, "STATUS_TPM_INSTALL_DISABLED"),
+    (0xc029_000c, "STATUS_TPM_INVALID_KEYHANDLE"),
+    (0xc029_000d, "STATUS_TPM_KEYNOTFOUND"),
+    (0xc029_000e, "STATUS_TPM_INAPPROPRIATE_ENC"),
+    (0xc029_000f, "STATUS_TPM_MIGRATEFAIL"),
+    (0xc029_0010, "STATUS_TPM_INVALID_PCR_INFO"),
+    (0xc029_0011, "STATUS_TPM_NOSPACE"),
+    (0xc029_0012, "STATUS_TPM_NOSRK"),
+    (0xc029_0013, "STATUS_TPM_NOTSEALED_BLOB"),
+    (0xc029_0014, "STATUS_TPM_OWNER_SET"),
+    (0xc029_0015, "STATUS_TPM_RESOURCES"),
+    (0xc029_0016, "STATUS_TPM_SHORTRANDOM"),
+    (0xc029_0017, "STATUS_TPM_SIZE"),
+    (0xc029_0018, "STATUS_TPM_WRONGPCRVAL"),
+    (0xc029_0019, "STATUS_TPM_BAD_PARAM_SIZE"),
+    (0xc029_001a, "STATUS_TPM_SHA_THREAD"),
+    (0xc029_001b, "STATUS_TPM_SHA_ERROR"),
+    (0xc029_001c, "STATUS_TPM_FAILEDSELFTEST"),
+    (0xc029_001d, "STATUS_TPM_AUTH2FAIL"),
+    (0xc029_001e, "STATUS_TPM_BADTAG"),
+    (0xc029_001f, "STATUS_TPM_IOERROR"),
+    (0xc029_0020, "STATUS_TPM_ENCRYPT_ERROR"),
+    (0xc029_0021, "STATUS_TPM_DECRYPT_ERROR"),
+    (0xc029_0022, "STATUS_TPM_INVALID_AUTHHANDLE"),
+    (0xc029_0023, "STATUS_TPM_NO_ENDORSEMENT"),
+    (0xc029_0024, "STATUS_TPM_INVALID_KEYUSAGE"),
+    (0xc029_0025, "STATUS_TPM_WRONG_ENTITYTYPE"),
+    (0xc029_0026, "STATUS_TPM_INVALID_POSTINIT"),
+    (0xc029_0027, "STATUS_TPM_INAPPROPRIATE_SIG"),
+    (0xc029_0028, "STATUS_TPM_BAD_KEY_PROPERTY"),
+    (0xc029_0029, "STATUS_TPM_BAD_MIGRATION"),
+    (0xc029_002a, "STATUS_TPM_BAD_SCHEME"),
+    (0xc029_002b, "STATUS_TPM_BAD_DATASIZE"),
+    (0xc029_002c, "STATUS_TPM_BAD_MODE"),
+    (0xc029_002d, "STATUS_TPM_BAD_PRESENCE"),
+    (0xc029_002e, "STATUS_TPM_BAD_VERSION"),
+    (0xc029_002f, "STATUS_TPM_NO_WRAP_TRANSPORT"),
+    (0xc029_0030, "STATUS_TPM_AUDITFAIL_UNSUCCESSFUL"),
+    (0xc029_0031, "STATUS_TPM_AUDITFAIL_SUCCESSFUL"),
+    (0xc029_0032, "STATUS_TPM_NOTRESETABLE"),
+    (0xc029_0033, "STATUS_TPM_NOTLOCAL"),
+    (0xc029_0034, "STATUS_TPM_BAD_TYPE"),
+    (0xc029_0035, "STATUS_TPM_INVALID_RESOURCE"),
+    (0xc029_0036, "STATUS_TPM_NOTFIPS"),
+    (0xc029_0037, "STATUS_TPM_INVALID_FAMILY"),
+    (0xc029_0038, "STATUS_TPM_NO_NV_PERMISSION"),
+    (0xc029_0039, "STATUS_TPM_REQUIRES_SIGN"),
+    (0xc029_003a, "STATUS_TPM_KEY_NOTSUPPORTED"),
+    (0xc029_003b, "STATUS_TPM_AUTH_CONFLICT"),
+    (0xc029_003c, "STATUS_TPM_AREA_LOCKED"),
+    (0xc029_003d, "STATUS_TPM_BAD_LOCALITY"),
+    (0xc029_003e, "STATUS_TPM_READ_ONLY"),
+    (0xc029_003f, "STATUS_TPM_PER_NOWRITE"),
+    (0xc029_0040, "STATUS_TPM_FAMILYCOUNT"),
+    (0xc029_0041, "STATUS_TPM_WRITE_LOCKED"),
+    (0xc029_0042, "STATUS_TPM_BAD_ATTRIBUTES"),
+    (0xc029_0043, "STATUS_TPM_INVALID_STRUCTURE"),
+    (0xc029_0044, "STATUS_TPM_KEY_OWNER_CONTROL"),
+    (0xc029_0045, "STATUS_TPM_BAD_COUNTER"),
+    (0xc029_0046, "STATUS_TPM_NOT_FULLWRITE"),
+    (0xc029_0047, "STATUS_TPM_CONTEXT_GAP"),
+    (0xc029_0048, "STATUS_TPM_MAXNVWRITES"),
+    (0xc029_0049, "STATUS_TPM_NOOPERATOR"),
+    (0xc029_004a, "STATUS_TPM_RESOURCEMISSING"),
+    (0xc029_004b, "STATUS_TPM_DELEGATE_LOCK"),
+    (0xc029_004c, "STATUS_TPM_DELEGATE_FAMILY"),
+    (0xc029_004d, "STATUS_TPM_DELEGATE_ADMIN"),
+    (0xc029_004e, "STATUS_TPM_TRANSPORT_NOTEXCLUSIVE"),
+    (0xc029_004f, "STATUS_TPM_OWNER_CONTROL"),
+    (0xc029_0050, "STATUS_TPM_DAA_RESOURCES"),
+    (0xc029_0051, "STATUS_TPM_DAA_INPUT_DATA0"),
+    (0xc029_0052, "STATUS_TPM_DAA_INPUT_DATA1"),
+    (0xc029_0053, "STATUS_TPM_DAA_ISSUER_SETTINGS"),
+    (0xc029_0054, "STATUS_TPM_DAA_TPM_SETTINGS"),
+    (0xc029_0055, "STATUS_TPM_DAA_STAGE"),
+    (0xc029_0056, "STATUS_TPM_DAA_ISSUER_VALIDITY"),
+    (0xc029_0057, "STATUS_TPM_DAA_WRONG_W"),
+    (0xc029_0058, "STATUS_TPM_BAD_HANDLE"),
+    (0xc029_0059, "STATUS_TPM_BAD_DELEGATE"),
+    (0xc029_005a, "STATUS_TPM_BADCONTEXT"),
+    (0xc029_005b, "STATUS_TPM_TOOMANYCONTEXTS"),
+    (0xc029_005c, "STATUS_TPM_MA_TICKET_SIGNATURE"),
+    (0xc029_005d, "STATUS_TPM_MA_DESTINATION"),
+    (0xc029_005e, "STATUS_TPM_MA_SOURCE"),
+    (0xc029_005f, "STATUS_TPM_MA_AUTHORITY"),
+    (0xc029_0061, "STATUS_TPM_PERMANENTEK"),
+    (0xc029_0062, "STATUS_TPM_BAD_SIGNATURE"),
+    (0xc029_0063, "STATUS_TPM_NOCONTEXTSPACE"),
+    (0xc029_0081, "STATUS_TPM_20_E_ASYMMETRIC"),
+    (0xc029_0082, "STATUS_TPM_20_E_ATTRIBUTES"),
+    (0xc029_0083, "STATUS_TPM_20_E_HASH"),
+    (0xc029_0084, "STATUS_TPM_20_E_VALUE"),
+    (0xc029_0085, "STATUS_TPM_20_E_HIERARCHY"),
+    (0xc029_0087, "STATUS_TPM_20_E_KEY_SIZE"),
+    (0xc029_0088, "STATUS_TPM_20_E_MGF"),
+    (0xc029_0089, "STATUS_TPM_20_E_MODE"),
+    (0xc029_008a, "STATUS_TPM_20_E_TYPE"),
+    (0xc029_008b, "STATUS_TPM_20_E_HANDLE"),
+    (0xc029_008c, "STATUS_TPM_20_E_KDF"),
+    (0xc029_008d, "STATUS_TPM_20_E_RANGE"),
+    (0xc029_008e, "STATUS_TPM_20_E_AUTH_FAIL"),
+    (0xc029_008f, "STATUS_TPM_20_E_NONCE"),
+    (0xc029_0090, "STATUS_TPM_20_E_PP"),
+    (0xc029_0092, "STATUS_TPM_20_E_SCHEME"),
+    (0xc029_0095, "STATUS_TPM_20_E_SIZE"),
+    (0xc029_0096, "STATUS_TPM_20_E_SYMMETRIC"),
+    (0xc029_0097, "STATUS_TPM_20_E_TAG"),
+    (0xc029_0098, "STATUS_TPM_20_E_SELECTOR"),
+    (0xc029_009a, "STATUS_TPM_20_E_INSUFFICIENT"),
+    (0xc029_009b, "STATUS_TPM_20_E_SIGNATURE"),
+    (0xc029_009c, "STATUS_TPM_20_E_KEY"),
+    (0xc029_009d, "STATUS_TPM_20_E_POLICY_FAIL"),
+    (0xc029_009f, "STATUS_TPM_20_E_INTEGRITY"),
+    (0xc029_00a0, "STATUS_TPM_20_E_TICKET"),
+    (0xc029_00a1, "STATUS_TPM_20_E_RESERVED_BITS"),
+    (0xc029_00a2, "STATUS_TPM_20_E_BAD_AUTH"),
+    (0xc029_00a3, "STATUS_TPM_20_E_EXPIRED"),
+    (0xc029_00a4, "STATUS_TPM_20_E_POLICY_CC"),
+    (0xc029_00a5, "STATUS_TPM_20_E_BINDING"),
+    (0xc029_00a6, "STATUS_TPM_20_E_CURVE"),
+    (0xc029_00a7, "STATUS_TPM_20_E_ECC_POINT"),
+    (0xc029_0100, "STATUS_TPM_20_E_INITIALIZE"),
+    (0xc029_0101, "STATUS_TPM_20_E_FAILURE"),
+    (0xc029_0103, "STATUS_TPM_20_E_SEQUENCE"),
+    (0xc029_010b, "STATUS_TPM_20_E_PRIVATE"),
+    (0xc029_0119, "STATUS_TPM_20_E_HMAC"),
+    (0xc029_0120, "STATUS_TPM_20_E_DISABLED"),
+    (0xc029_0121, "STATUS_TPM_20_E_EXCLUSIVE"),
+    (0xc029_0123, "STATUS_TPM_20_E_ECC_CURVE"),
+    (0xc029_0124, "STATUS_TPM_20_E_AUTH_TYPE"),
+    (0xc029_0125, "STATUS_TPM_20_E_AUTH_MISSING"),
+    (0xc029_0126, "STATUS_TPM_20_E_POLICY"),
+    (0xc029_0127, "STATUS_TPM_20_E_PCR"),
+    (0xc029_0128, "STATUS_TPM_20_E_PCR_CHANGED"),
+    (0xc029_012d, "STATUS_TPM_20_E_UPGRADE"),
+    (0xc029_012e, "STATUS_TPM_20_E_TOO_MANY_CONTEXTS"),
+    (0xc029_012f, "STATUS_TPM_20_E_AUTH_UNAVAILABLE"),
+    (0xc029_0130, "STATUS_TPM_20_E_REBOOT"),
+    (0xc029_0131, "STATUS_TPM_20_E_UNBALANCED"),
+    (0xc029_0142, "STATUS_TPM_20_E_COMMAND_SIZE"),
+    (0xc029_0143, "STATUS_TPM_20_E_COMMAND_CODE"),
+    (0xc029_0144, "STATUS_TPM_20_E_AUTHSIZE"),
+    (0xc029_0145, "STATUS_TPM_20_E_AUTH_CONTEXT"),
+    (0xc029_0146, "STATUS_TPM_20_E_NV_RANGE"),
+    (0xc029_0147, "STATUS_TPM_20_E_NV_SIZE"),
+    (0xc029_0148, "STATUS_TPM_20_E_NV_LOCKED"),
+    (0xc029_0149, "STATUS_TPM_20_E_NV_AUTHORIZATION"),
+    (0xc029_014a, "STATUS_TPM_20_E_NV_UNINITIALIZED"),
+    (0xc029_014b, "STATUS_TPM_20_E_NV_SPACE"),
+    (0xc029_014c, "STATUS_TPM_20_E_NV_DEFINED"),
+    (0xc029_0150, "STATUS_TPM_20_E_BAD_CONTEXT"),
+    (0xc029_0151, "STATUS_TPM_20_E_CPHASH"),
+    (0xc029_0152, "STATUS_TPM_20_E_PARENT"),
+    (0xc029_0153, "STATUS_TPM_20_E_NEEDS_TEST"),
+    (0xc029_0154, "STATUS_TPM_20_E_NO_RESULT"),
+    (0xc029_0155, "STATUS_TPM_20_E_SENSITIVE"),
+    (0xc029_0400, "STATUS_TPM_COMMAND_BLOCKED"),
+    (0xc029_0401, "STATUS_TPM_INVALID_HANDLE"),
+    (0xc029_0402, "STATUS_TPM_DUPLICATE_VHANDLE"),
+    (0xc029_0403, "STATUS_TPM_EMBEDDED_COMMAND_BLOCKED"),
+    (0xc029_0404, "STATUS_TPM_EMBEDDED_COMMAND_UNSUPPORTED"),
+    (0xc029_0800, "STATUS_TPM_RETRY"),
+    (0xc029_0801, "STATUS_TPM_NEEDS_SELFTEST"),
+    (0xc029_0802, "STATUS_TPM_DOING_SELFTEST"),
+    (0xc029_0803, "STATUS_TPM_DEFEND_LOCK_RUNNING"),
+    (0xc029_1001, "STATUS_TPM_COMMAND_CANCELED"),
+    (0xc029_1002, "STATUS_TPM_TOO_MANY_CONTEXTS"),
+    (0xc029_1003, "STATUS_TPM_NOT_FOUND"),
+    (0xc029_1004, "STATUS_TPM_ACCESS_DENIED"),
+    (0xc029_1005, "STATUS_TPM_INSUFFICIENT_BUFFER"),
+    (0xc029_1006, "STATUS_TPM_PPI_FUNCTION_UNSUPPORTED"),
+    (0xc029_2000, "STATUS_PCP_ERROR_MASK"),
+    (0xc029_2001, "STATUS_PCP_DEVICE_NOT_READY"),
+    (0xc029_2002, "STATUS_PCP_INVALID_HANDLE"),
+    (0xc029_2003, "STATUS_PCP_INVALID_PARAMETER"),
+    (0xc029_2004, "STATUS_PCP_FLAG_NOT_SUPPORTED"),
+    (0xc029_2005, "STATUS_PCP_NOT_SUPPORTED"),
+    (0xc029_2006, "STATUS_PCP_BUFFER_TOO_SMALL"),
+    (0xc029_2007, "STATUS_PCP_INTERNAL_ERROR"),
+    (0xc029_2008, "STATUS_PCP_AUTHENTICATION_FAILED"),
+    (0xc029_2009, "STATUS_PCP_AUTHENTICATION_IGNORED"),
+    (0xc029_200a, "STATUS_PCP_POLICY_NOT_FOUND"),
+    (0xc029_200b, "STATUS_PCP_PROFILE_NOT_FOUND"),
+    (0xc029_200c, "STATUS_PCP_VALIDATION_FAILED"),
+    (0xc029_200d, "STATUS_PCP_DEVICE_NOT_FOUND"),
+    (0xc029_200e, "STATUS_PCP_WRONG_PARENT"),
+    (0xc029_200f, "STATUS_PCP_KEY_NOT_LOADED"),
+    (0xc029_2010, "STATUS_PCP_NO_KEY_CERTIFICATION"),
+    (0xc029_2011, "STATUS_PCP_KEY_NOT_FINALIZED"),
+    (0xc029_2012, "STATUS_PCP_ATTESTATION_CHALLENGE_NOT_SET"),
+    (0xc029_2013, "STATUS_PCP_NOT_PCR_BOUND"),
+    (0xc029_2014, "STATUS_PCP_KEY_ALREADY_FINALIZED"),
+    (0xc029_2015, "STATUS_PCP_KEY_USAGE_POLICY_NOT_SUPPORTED"),
+    (0xc029_2016, "STATUS_PCP_KEY_USAGE_POLICY_INVALID"),
+    (0xc029_2017, "STATUS_PCP_SOFT_KEY_ERROR"),
+    (0xc029_2018, "STATUS_PCP_KEY_NOT_AUTHENTICATED"),
+    (0xc029_2019, "STATUS_PCP_KEY_NOT_AIK"),
+    (0xc029_201a, "STATUS_PCP_KEY_NOT_SIGNING_KEY"),
+    (0xc029_201b, "STATUS_PCP_LOCKED_OUT"),
+    (0xc029_201c, "STATUS_PCP_CLAIM_TYPE_NOT_SUPPORTED"),
+    (0xc029_201d, "STATUS_PCP_TPM_VERSION_NOT_SUPPORTED"),
+    (0xc029_201e, "STATUS_PCP_BUFFER_LENGTH_MISMATCH"),
+    (0xc029_201f, "STATUS_PCP_IFX_RSA_KEY_CREATION_BLOCKED"),
+    (0xc029_2020, "STATUS_PCP_TICKET_MISSING"),
+    (0xc029_2021, "STATUS_PCP_RAW_POLICY_NOT_SUPPORTED"),
+    (0xc029_2022, "STATUS_PCP_KEY_HANDLE_INVALIDATED"),
+    (0xc029_3002, "STATUS_RTPM_NO_RESULT"),
+    (0xc029_3003, "STATUS_RTPM_PCR_READ_INCOMPLETE"),
+    (0xc029_3004, "STATUS_RTPM_INVALID_CONTEXT"),
+    (0xc029_3005, "STATUS_RTPM_UNSUPPORTED_CMD"),
+    (0xc029_4000, "STATUS_TPM_ZERO_EXHAUST_ENABLED"),
+    (0xc035_0002, "STATUS_HV_INVALID_HYPERCALL_CODE"),
+    (0xc035_0003, "STATUS_HV_INVALID_HYPERCALL_INPUT"),
+    (0xc035_0004, "STATUS_HV_INVALID_ALIGNMENT"),
+    (0xc035_0005, "STATUS_HV_INVALID_PARAMETER"),
+    (0xc035_0006, "STATUS_HV_ACCESS_DENIED"),
+    (0xc035_0007, "STATUS_HV_INVALID_PARTITION_STATE"),
+    (0xc035_0008, "STATUS_HV_OPERATION_DENIED"),
+    (0xc035_0009, "STATUS_HV_UNKNOWN_PROPERTY"),
+    (0xc035_000a, "STATUS_HV_PROPERTY_VALUE_OUT_OF_RANGE"),
+    (0xc035_000b, "STATUS_HV_INSUFFICIENT_MEMORY"),
+    (0xc035_000c, "STATUS_HV_PARTITION_TOO_DEEP"),
+    (0xc035_000d, "STATUS_HV_INVALID_PARTITION_ID"),
+    (0xc035_000e, "STATUS_HV_INVALID_VP_INDEX"),
+    (0xc035_0011, "STATUS_HV_INVALID_PORT_ID"),
+    (0xc035_0012, "STATUS_HV_INVALID_CONNECTION_ID"),
+    (0xc035_0013, "STATUS_HV_INSUFFICIENT_BUFFERS"),
+    (0xc035_0014, "STATUS_HV_NOT_ACKNOWLEDGED"),
+    (0xc035_0015, "STATUS_HV_INVALID_VP_STATE"),
+    (0xc035_0016, "STATUS_HV_ACKNOWLEDGED"),
+    (0xc035_0017, "STATUS_HV_INVALID_SAVE_RESTORE_STATE"),
+    (0xc035_0018, "STATUS_HV_INVALID_SYNIC_STATE"),
+    (0xc035_0019, "STATUS_HV_OBJECT_IN_USE"),
+    (0xc035_001a, "STATUS_HV_INVALID_PROXIMITY_DOMAIN_INFO"),
+    (0xc035_001b, "STATUS_HV_NO_DATA"),
+    (0xc035_001c, "STATUS_HV_INACTIVE"),
+    (0xc035_001d, "STATUS_HV_NO_RESOURCES"),
+    (0xc035_001e, "STATUS_HV_FEATURE_UNAVAILABLE"),
+    (0xc035_0033, "STATUS_HV_INSUFFICIENT_BUFFER"),
+    (0xc035_0038, "STATUS_HV_INSUFFICIENT_DEVICE_DOMAINS"),
+    (0xc035_003c, "STATUS_HV_CPUID_FEATURE_VALIDATION_ERROR"),
+    (0xc035_003d, "STATUS_HV_CPUID_XSAVE_FEATURE_VALIDATION_ERROR"),
+    (0xc035_003e, "STATUS_HV_PROCESSOR_STARTUP_TIMEOUT"),
+    (0xc035_003f, "STATUS_HV_SMX_ENABLED"),
+    (0xc035_0041, "STATUS_HV_INVALID_LP_INDEX"),
+    (0xc035_0050, "STATUS_HV_INVALID_REGISTER_VALUE"),
+    (0xc035_0051, "STATUS_HV_INVALID_VTL_STATE"),
+    (0xc035_0055, "STATUS_HV_NX_NOT_DETECTED"),
+    (0xc035_0057, "STATUS_HV_INVALID_DEVICE_ID"),
+    (0xc035_0058, "STATUS_HV_INVALID_DEVICE_STATE"),
+    (0xc035_0060, "STATUS_HV_PAGE_REQUEST_INVALID"),
+    (0xc035_006f, "STATUS_HV_INVALID_CPU_GROUP_ID"),
+    (0xc035_0070, "STATUS_HV_INVALID_CPU_GROUP_STATE"),
+    (0xc035_0071, "STATUS_HV_OPERATION_FAILED"),
+    (0xc035_0072, "STATUS_HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE"),
+    (0xc035_0073, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY"),
+    (0xc035_0074, "STATUS_HV_EVENT_BUFFER_ALREADY_FREED"),
+    (0xc035_0075, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY"),
+    (0xc035_0076, "STATUS_HV_DEVICE_NOT_IN_DOMAIN"),
+    (0xc035_0077, "STATUS_HV_NESTED_VM_EXIT"),
+    (0xc035_0079, "STATUS_HV_CALL_PENDING"),
+    (0xc035_0080, "STATUS_HV_MSR_ACCESS_FAILED"),
+    (0xc035_0081, "STATUS_HV_INSUFFICIENT_MEMORY_MIRRORING"),
+    (0xc035_0082, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY_MIRRORING"),
+    (0xc035_0083, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY"),
+    (0xc035_0084, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY_MIRRORING"),
+    (0xc035_0085, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY_MIRRORING"),
+    (0xc035_1000, "STATUS_HV_NOT_PRESENT"),
+    (0xc036_0001, "STATUS_IPSEC_BAD_SPI"),
+    (0xc036_0002, "STATUS_IPSEC_SA_LIFETIME_EXPIRED"),
+    (0xc036_0003, "STATUS_IPSEC_WRONG_SA"),
+    (0xc036_0004, "STATUS_IPSEC_REPLAY_CHECK_FAILED"),
+    (0xc036_0005, "STATUS_IPSEC_INVALID_PACKET"),
+    (0xc036_0006, "STATUS_IPSEC_INTEGRITY_CHECK_FAILED"),
+    (0xc036_0007, "STATUS_IPSEC_CLEAR_TEXT_DROP"),
+    (0xc036_0008, "STATUS_IPSEC_AUTH_FIREWALL_DROP"),
+    (0xc036_0009, "STATUS_IPSEC_THROTTLE_DROP"),
+    (0xc036_8000, "STATUS_IPSEC_DOSP_BLOCK"),
+    (0xc036_8001, "STATUS_IPSEC_DOSP_RECEIVED_MULTICAST"),
+    (0xc036_8002, "STATUS_IPSEC_DOSP_INVALID_PACKET"),
+    (0xc036_8003, "STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED"),
+    (0xc036_8004, "STATUS_IPSEC_DOSP_MAX_ENTRIES"),
+    (0xc036_8005, "STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED"),
+    (0xc036_8006, "STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES"),
+    (0xc037_0001, "STATUS_VID_DUPLICATE_HANDLER"),
+    (0xc037_0002, "STATUS_VID_TOO_MANY_HANDLERS"),
+    (0xc037_0003, "STATUS_VID_QUEUE_FULL"),
+    (0xc037_0004, "STATUS_VID_HANDLER_NOT_PRESENT"),
+    (0xc037_0005, "STATUS_VID_INVALID_OBJECT_NAME"),
+    (0xc037_0006, "STATUS_VID_PARTITION_NAME_TOO_LONG"),
+    (0xc037_0007, "STATUS_VID_MESSAGE_QUEUE_NAME_TOO_LONG"),
+    (0xc037_0008, "STATUS_VID_PARTITION_ALREADY_EXISTS"),
+    (0xc037_0009, "STATUS_VID_PARTITION_DOES_NOT_EXIST"),
+    (0xc037_000a, "STATUS_VID_PARTITION_NAME_NOT_FOUND"),
+    (0xc037_000b, "STATUS_VID_MESSAGE_QUEUE_ALREADY_EXISTS"),
+    (0xc037_000c, "STATUS_VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT"),
+    (0xc037_000d, "STATUS_VID_MB_STILL_REFERENCED"),
+    (0xc037_000e, "STATUS_VID_CHILD_GPA_PAGE_SET_CORRUPTED"),
+    (0xc037_000f, "STATUS_VID_INVALID_NUMA_SETTINGS"),
+    (0xc037_0010, "STATUS_VID_INVALID_NUMA_NODE_INDEX"),
+    (0xc037_0011, "STATUS_VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED"),
+    (0xc037_0012, "STATUS_VID_INVALID_MEMORY_BLOCK_HANDLE"),
+    (0xc037_0013, "STATUS_VID_PAGE_RANGE_OVERFLOW"),
+    (0xc037_0014, "STATUS_VID_INVALID_MESSAGE_QUEUE_HANDLE"),
+    (0xc037_0015, "STATUS_VID_INVALID_GPA_RANGE_HANDLE"),
+    (0xc037_0016, "STATUS_VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE"),
+    (0xc037_0017, "STATUS_VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED"),
+    (0xc037_0018, "STATUS_VID_INVALID_PPM_HANDLE"),
+    (0xc037_0019, "STATUS_VID_MBPS_ARE_LOCKED"),
+    (0xc037_001a, "STATUS_VID_MESSAGE_QUEUE_CLOSED"),
+    (0xc037_001b, "STATUS_VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED"),
+    (0xc037_001c, "STATUS_VID_STOP_PENDING"),
+    (0xc037_001d, "STATUS_VID_INVALID_PROCESSOR_STATE"),
+    (0xc037_001e, "STATUS_VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT"),
+    (0xc037_001f, "STATUS_VID_KM_INTERFACE_ALREADY_INITIALIZED"),
+    (0xc037_0020, "STATUS_VID_MB_PROPERTY_ALREADY_SET_RESET"),
+    (0xc037_0021, "STATUS_VID_MMIO_RANGE_DESTROYED"),
+    (0xc037_0022, "STATUS_VID_INVALID_CHILD_GPA_PAGE_SET"),
+    (0xc037_0023, "STATUS_VID_RESERVE_PAGE_SET_IS_BEING_USED"),
+    (0xc037_0024, "STATUS_VID_RESERVE_PAGE_SET_TOO_SMALL"),
+    (0xc037_0025, "STATUS_VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE"),
+    (0xc037_0026, "STATUS_VID_MBP_COUNT_EXCEEDED_LIMIT"),
+    (0xc037_0027, "STATUS_VID_SAVED_STATE_CORRUPT"),
+    (0xc037_0028, "STATUS_VID_SAVED_STATE_UNRECOGNIZED_ITEM"),
+    (0xc037_0029, "STATUS_VID_SAVED_STATE_INCOMPATIBLE"),
+    (0xc037_002a, "STATUS_VID_VTL_ACCESS_DENIED"),
+    (0xc037_002b, "STATUS_VID_INSUFFICIENT_RESOURCES_RESERVE"),
+    (0xc037_002c, "STATUS_VID_INSUFFICIENT_RESOURCES_PHYSICAL_BUFFER"),
+    (0xc037_002d, "STATUS_VID_INSUFFICIENT_RESOURCES_HV_DEPOSIT"),
+    (0xc037_002e, "STATUS_VID_MEMORY_TYPE_NOT_SUPPORTED"),
+    (0xc037_002f, "STATUS_VID_INSUFFICIENT_RESOURCES_WITHDRAW"),
+    (0xc037_0030, "STATUS_VID_PROCESS_ALREADY_SET"),
+    (0xc037_0600, "STATUS_DM_OPERATION_LIMIT_EXCEEDED"),
+    (0xc038_0001, "STATUS_VOLMGR_DATABASE_FULL"),
+    (0xc038_0002, "STATUS_VOLMGR_DISK_CONFIGURATION_CORRUPTED"),
+    (0xc038_0003, "STATUS_VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC"),
+    (0xc038_0004, "STATUS_VOLMGR_PACK_CONFIG_UPDATE_FAILED"),
+    (0xc038_0005, "STATUS_VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME"),
+    (0xc038_0006, "STATUS_VOLMGR_DISK_DUPLICATE"),
+    (0xc038_0007, "STATUS_VOLMGR_DISK_DYNAMIC"),
+    (0xc038_0008, "STATUS_VOLMGR_DISK_ID_INVALID"),
+    (0xc038_0009, "STATUS_VOLMGR_DISK_INVALID"),
+    (0xc038_000a, "STATUS_VOLMGR_DISK_LAST_VOTER"),
+    (0xc038_000b, "STATUS_VOLMGR_DISK_LAYOUT_INVALID"),
+    (0xc038_000c, "STATUS_VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS"),
+    (0xc038_000d, "STATUS_VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED"),
+    (0xc038_000e, "STATUS_VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL"),
+    (0xc038_000f, "STATUS_VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS"),
+    (0xc038_0010, "STATUS_VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS"),
+    (0xc038_0011, "STATUS_VOLMGR_DISK_MISSING"),
+    (0xc038_0012, "STATUS_VOLMGR_DISK_NOT_EMPTY"),
+    (0xc038_0013, "STATUS_VOLMGR_DISK_NOT_ENOUGH_SPACE"),
+    (0xc038_0014, "STATUS_VOLMGR_DISK_REVECTORING_FAILED"),
+    (0xc038_0015, "STATUS_VOLMGR_DISK_SECTOR_SIZE_INVALID"),
+    (0xc038_0016, "STATUS_VOLMGR_DISK_SET_NOT_CONTAINED"),
+    (0xc038_0017, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS"),
+    (0xc038_0018, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES"),
+    (0xc038_0019, "STATUS_VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED"),
+    (0xc038_001a, "STATUS_VOLMGR_EXTENT_ALREADY_USED"),
+    (0xc038_001b, "STATUS_VOLMGR_EXTENT_NOT_CONTIGUOUS"),
+    (0xc038_001c, "STATUS_VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION"),
+    (0xc038_001d, "STATUS_VOLMGR_EXTENT_NOT_SECTOR_ALIGNED"),
+    (0xc038_001e, "STATUS_VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION"),
+    (0xc038_001f, "STATUS_VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH"),
+    (0xc038_0020, "STATUS_VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED"),
+    (0xc038_0021, "STATUS_VOLMGR_INTERLEAVE_LENGTH_INVALID"),
+    (0xc038_0022, "STATUS_VOLMGR_MAXIMUM_REGISTERED_USERS"),
+    (0xc038_0023, "STATUS_VOLMGR_MEMBER_IN_SYNC"),
+    (0xc038_0024, "STATUS_VOLMGR_MEMBER_INDEX_DUPLICATE"),
+    (0xc038_0025, "STATUS_VOLMGR_MEMBER_INDEX_INVALID"),
+    (0xc038_0026, "STATUS_VOLMGR_MEMBER_MISSING"),
+    (0xc038_0027, "STATUS_VOLMGR_MEMBER_NOT_DETACHED"),
+    (0xc038_0028, "STATUS_VOLMGR_MEMBER_REGENERATING"),
+    (0xc038_0029, "STATUS_VOLMGR_ALL_DISKS_FAILED"),
+    (0xc038_002a, "STATUS_VOLMGR_NO_REGISTERED_USERS"),
+    (0xc038_002b, "STATUS_VOLMGR_NO_SUCH_USER"),
+    (0xc038_002c, "STATUS_VOLMGR_NOTIFICATION_RESET"),
+    (0xc038_002d, "STATUS_VOLMGR_NUMBER_OF_MEMBERS_INVALID"),
+    (0xc038_002e, "STATUS_VOLMGR_NUMBER_OF_PLEXES_INVALID"),
+    (0xc038_002f, "STATUS_VOLMGR_PACK_DUPLICATE"),
+    (0xc038_0030, "STATUS_VOLMGR_PACK_ID_INVALID"),
+    (0xc038_0031, "STATUS_VOLMGR_PACK_INVALID"),
+    (0xc038_0032, "STATUS_VOLMGR_PACK_NAME_INVALID"),
+    (0xc038_0033, "STATUS_VOLMGR_PACK_OFFLINE"),
+    (0xc038_0034, "STATUS_VOLMGR_PACK_HAS_QUORUM"),
+    (0xc038_0035, "STATUS_VOLMGR_PACK_WITHOUT_QUORUM"),
+    (0xc038_0036, "STATUS_VOLMGR_PARTITION_STYLE_INVALID"),
+    (0xc038_0037, "STATUS_VOLMGR_PARTITION_UPDATE_FAILED"),
+    (0xc038_0038, "STATUS_VOLMGR_PLEX_IN_SYNC"),
+    (0xc038_0039, "STATUS_VOLMGR_PLEX_INDEX_DUPLICATE"),
+    (0xc038_003a, "STATUS_VOLMGR_PLEX_INDEX_INVALID"),
+    (0xc038_003b, "STATUS_VOLMGR_PLEX_LAST_ACTIVE"),
+    (0xc038_003c, "STATUS_VOLMGR_PLEX_MISSING"),
+    (0xc038_003d, "STATUS_VOLMGR_PLEX_REGENERATING"),
+    (0xc038_003e, "STATUS_VOLMGR_PLEX_TYPE_INVALID"),
+    (0xc038_003f, "STATUS_VOLMGR_PLEX_NOT_RAID5"),
+    (0xc038_0040, "STATUS_VOLMGR_PLEX_NOT_SIMPLE"),
+    (0xc038_0041, "STATUS_VOLMGR_STRUCTURE_SIZE_INVALID"),
+    (0xc038_0042, "STATUS_VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS"),
+    (0xc038_0043, "STATUS_VOLMGR_TRANSACTION_IN_PROGRESS"),
+    (0xc038_0044, "STATUS_VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE"),
+    (0xc038_0045, "STATUS_VOLMGR_VOLUME_CONTAINS_MISSING_DISK"),
+    (0xc038_0046, "STATUS_VOLMGR_VOLUME_ID_INVALID"),
+    (0xc038_0047, "STATUS_VOLMGR_VOLUME_LENGTH_INVALID"),
+    (0xc038_0048, "STATUS_VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE"),
+    (0xc038_0049, "STATUS_VOLMGR_VOLUME_NOT_MIRRORED"),
+    (0xc038_004a, "STATUS_VOLMGR_VOLUME_NOT_RETAINED"),
+    (0xc038_004b, "STATUS_VOLMGR_VOLUME_OFFLINE"),
+    (0xc038_004c, "STATUS_VOLMGR_VOLUME_RETAINED"),
+    (0xc038_004d, "STATUS_VOLMGR_NUMBER_OF_EXTENTS_INVALID"),
+    (0xc038_004e, "STATUS_VOLMGR_DIFFERENT_SECTOR_SIZE"),
+    (0xc038_004f, "STATUS_VOLMGR_BAD_BOOT_DISK"),
+    (0xc038_0050, "STATUS_VOLMGR_PACK_CONFIG_OFFLINE"),
+    (0xc038_0051, "STATUS_VOLMGR_PACK_CONFIG_ONLINE"),
+    (0xc038_0052, "STATUS_VOLMGR_NOT_PRIMARY_PACK"),
+    (0xc038_0053, "STATUS_VOLMGR_PACK_LOG_UPDATE_FAILED"),
+    (0xc038_0054, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID"),
+    (0xc038_0055, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID"),
+    (0xc038_0056, "STATUS_VOLMGR_VOLUME_MIRRORED"),
+    (0xc038_0057, "STATUS_VOLMGR_PLEX_NOT_SIMPLE_SPANNED"),
+    (0xc038_0058, "STATUS_VOLMGR_NO_VALID_LOG_COPIES"),
+    (0xc038_0059, "STATUS_VOLMGR_PRIMARY_PACK_PRESENT"),
+    (0xc038_005a, "STATUS_VOLMGR_NUMBER_OF_DISKS_INVALID"),
+    (0xc038_005b, "STATUS_VOLMGR_MIRROR_NOT_SUPPORTED"),
+    (0xc038_005c, "STATUS_VOLMGR_RAID5_NOT_SUPPORTED"),
+    (0xc039_0002, "STATUS_BCD_TOO_MANY_ELEMENTS"),
+    (0xc03a_0001, "STATUS_VHD_DRIVE_FOOTER_MISSING"),
+    (0xc03a_0002, "STATUS_VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH"),
+    (0xc03a_0003, "STATUS_VHD_DRIVE_FOOTER_CORRUPT"),
+    (0xc03a_0004, "STATUS_VHD_FORMAT_UNKNOWN"),
+    (0xc03a_0005, "STATUS_VHD_FORMAT_UNSUPPORTED_VERSION"),
+    (0xc03a_0006, "STATUS_VHD_SPARSE_HEADER_CHECKSUM_MISMATCH"),
+    (0xc03a_0007, "STATUS_VHD_SPARSE_HEADER_UNSUPPORTED_VERSION"),
+    (0xc03a_0008, "STATUS_VHD_SPARSE_HEADER_CORRUPT"),
+    (0xc03a_0009, "STATUS_VHD_BLOCK_ALLOCATION_FAILURE"),
+    (0xc03a_000a, "STATUS_VHD_BLOCK_ALLOCATION_TABLE_CORRUPT"),
+    (0xc03a_000b, "STATUS_VHD_INVALID_BLOCK_SIZE"),
+    (0xc03a_000c, "STATUS_VHD_BITMAP_MISMATCH"),
+    (0xc03a_000d, "STATUS_VHD_PARENT_VHD_NOT_FOUND"),
+    (0xc03a_000e, "STATUS_VHD_CHILD_PARENT_ID_MISMATCH"),
+    (0xc03a_000f, "STATUS_VHD_CHILD_PARENT_TIMESTAMP_MISMATCH"),
+    (0xc03a_0010, "STATUS_VHD_METADATA_READ_FAILURE"),
+    (0xc03a_0011, "STATUS_VHD_METADATA_WRITE_FAILURE"),
+    (0xc03a_0012, "STATUS_VHD_INVALID_SIZE"),
+    (0xc03a_0013, "STATUS_VHD_INVALID_FILE_SIZE"),
+    (0xc03a_0014, "STATUS_VIRTDISK_PROVIDER_NOT_FOUND"),
+    (0xc03a_0015, "STATUS_VIRTDISK_NOT_VIRTUAL_DISK"),
+    (0xc03a_0016, "STATUS_VHD_PARENT_VHD_ACCESS_DENIED"),
+    (0xc03a_0017, "STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH"),
+    (0xc03a_0018, "STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED"),
+    (0xc03a_0019, "STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT"),
+    (0xc03a_001a, "STATUS_VIRTUAL_DISK_LIMITATION"),
+    (0xc03a_001b, "STATUS_VHD_INVALID_TYPE"),
+    (0xc03a_001c, "STATUS_VHD_INVALID_STATE"),
+    (0xc03a_001d, "STATUS_VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE"),
+    (0xc03a_001e, "STATUS_VIRTDISK_DISK_ALREADY_OWNED"),
+    (0xc03a_001f, "STATUS_VIRTDISK_DISK_ONLINE_AND_WRITABLE"),
+    (0xc03a_0020, "STATUS_CTLOG_TRACKING_NOT_INITIALIZED"),
+    (0xc03a_0021, "STATUS_CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE"),
+    (0xc03a_0022, "STATUS_CTLOG_VHD_CHANGED_OFFLINE"),
+    (0xc03a_0023, "STATUS_CTLOG_INVALID_TRACKING_STATE"),
+    (0xc03a_0024, "STATUS_CTLOG_INCONSISTENT_TRACKING_FILE"),
+    (0xc03a_0028, "STATUS_VHD_METADATA_FULL"),
+    (0xc03a_0029, "STATUS_VHD_INVALID_CHANGE_TRACKING_ID"),
+    (0xc03a_002a, "STATUS_VHD_CHANGE_TRACKING_DISABLED"),
+    (0xc03a_0030, "STATUS_VHD_MISSING_CHANGE_TRACKING_INFORMATION"),
+    (0xc03a_0031, "STATUS_VHD_RESIZE_WOULD_TRUNCATE_DATA"),
+    (0xc03a_0032, "STATUS_VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE"),
+    (0xc03a_0033, "STATUS_VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE"),
+    (0xc03a_0034, "STATUS_VHD_UNEXPECTED_ID"),
+    (0xc040_0001, "STATUS_RKF_KEY_NOT_FOUND"),
+    (0xc040_0002, "STATUS_RKF_DUPLICATE_KEY"),
+    (0xc040_0003, "STATUS_RKF_BLOB_FULL"),
+    (0xc040_0004, "STATUS_RKF_STORE_FULL"),
+    (0xc040_0005, "STATUS_RKF_FILE_BLOCKED"),
+    (0xc040_0006, "STATUS_RKF_ACTIVE_KEY"),
+    (0xc041_0001, "STATUS_RDBSS_RESTART_OPERATION"),
+    (0xc041_0002, "STATUS_RDBSS_CONTINUE_OPERATION"),
+    (0xc041_0003, "STATUS_RDBSS_POST_OPERATION"),
+    (0xc041_0004, "STATUS_RDBSS_RETRY_LOOKUP"),
+    (0xc042_0001, "STATUS_BTH_ATT_INVALID_HANDLE"),
+    (0xc042_0002, "STATUS_BTH_ATT_READ_NOT_PERMITTED"),
+    (0xc042_0003, "STATUS_BTH_ATT_WRITE_NOT_PERMITTED"),
+    (0xc042_0004, "STATUS_BTH_ATT_INVALID_PDU"),
+    (0xc042_0005, "STATUS_BTH_ATT_INSUFFICIENT_AUTHENTICATION"),
+    (0xc042_0006, "STATUS_BTH_ATT_REQUEST_NOT_SUPPORTED"),
+    (0xc042_0007, "STATUS_BTH_ATT_INVALID_OFFSET"),
+    (0xc042_0008, "STATUS_BTH_ATT_INSUFFICIENT_AUTHORIZATION"),
+    (0xc042_0009, "STATUS_BTH_ATT_PREPARE_QUEUE_FULL"),
+    (0xc042_000a, "STATUS_BTH_ATT_ATTRIBUTE_NOT_FOUND"),
+    (0xc042_000b, "STATUS_BTH_ATT_ATTRIBUTE_NOT_LONG"),
+    (0xc042_000c, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE"),
+    (0xc042_000d, "STATUS_BTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH"),
+    (0xc042_000e, "STATUS_BTH_ATT_UNLIKELY"),
+    (0xc042_000f, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION"),
+    (0xc042_0010, "STATUS_BTH_ATT_UNSUPPORTED_GROUP_TYPE"),
+    (0xc042_0011, "STATUS_BTH_ATT_INSUFFICIENT_RESOURCES"),
+    (0xc042_1000, "STATUS_BTH_ATT_UNKNOWN_ERROR"),
+    (0xc043_0001, "STATUS_SECUREBOOT_ROLLBACK_DETECTED"),
+    (0xc043_0002, "STATUS_SECUREBOOT_POLICY_VIOLATION"),
+    (0xc043_0003, "STATUS_SECUREBOOT_INVALID_POLICY"),
+    (0xc043_0004, "STATUS_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND"),
+    (0xc043_0005, "STATUS_SECUREBOOT_POLICY_NOT_SIGNED"),
+    (0xc043_0007, "STATUS_SECUREBOOT_FILE_REPLACED"),
+    (0xc043_0008, "STATUS_SECUREBOOT_POLICY_NOT_AUTHORIZED"),
+    (0xc043_0009, "STATUS_SECUREBOOT_POLICY_UNKNOWN"),
+    (0xc043_000a, "STATUS_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION"),
+    (0xc043_000b, "STATUS_SECUREBOOT_PLATFORM_ID_MISMATCH"),
+    (0xc043_000c, "STATUS_SECUREBOOT_POLICY_ROLLBACK_DETECTED"),
+    (0xc043_000d, "STATUS_SECUREBOOT_POLICY_UPGRADE_MISMATCH"),
+    (0xc043_000e, "STATUS_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING"),
+    (0xc043_000f, "STATUS_SECUREBOOT_NOT_BASE_POLICY"),
+    (0xc043_0010, "STATUS_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY"),
+    (0xc044_0001, "STATUS_AUDIO_ENGINE_NODE_NOT_FOUND"),
+    (0xc044_0002, "STATUS_HDAUDIO_EMPTY_CONNECTION_LIST"),
+    (0xc044_0003, "STATUS_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED"),
+    (0xc044_0004, "STATUS_HDAUDIO_NO_LOGICAL_DEVICES_CREATED"),
+    (0xc044_0005, "STATUS_HDAUDIO_NULL_LINKED_LIST_ENTRY"),
+    (0xc045_0000, "STATUS_VSM_NOT_INITIALIZED"),
+    (0xc045_0001, "STATUS_VSM_DMA_PROTECTION_NOT_IN_USE"),
+    (0xc046_0001, "STATUS_IORING_REQUIRED_FLAG_NOT_SUPPORTED"),
+    (0xc046_0002, "STATUS_IORING_SUBMISSION_QUEUE_FULL"),
+    (0xc046_0003, "STATUS_IORING_VERSION_NOT_SUPPORTED"),
+    (0xc046_0004, "STATUS_IORING_SUBMISSION_QUEUE_TOO_BIG"),
+    (0xc046_0005, "STATUS_IORING_COMPLETION_QUEUE_TOO_BIG"),
+    (0xc046_0006, "STATUS_IORING_SUBMIT_IN_PROGRESS"),
+    (0xc046_0007, "STATUS_IORING_CORRUPT"),
+    (0xc046_0008, "STATUS_IORING_COMPLETION_QUEUE_TOO_FULL"),
+    (0xc050_0003, "STATUS_VOLSNAP_BOOTFILE_NOT_VALID"),
+    (0xc050_0004, "STATUS_VOLSNAP_ACTIVATION_TIMEOUT"),
+    (0xc050_0005, "STATUS_VOLSNAP_NO_BYPASSIO_WITH_SNAPSHOT"),
+    (0xc051_0001, "STATUS_IO_PREEMPTED"),
+    (0xc05c_0000, "STATUS_SVHDX_ERROR_STORED"),
+    (0xc05c_ff00, "STATUS_SVHDX_ERROR_NOT_AVAILABLE"),
+    (0xc05c_ff01, "STATUS_SVHDX_UNIT_ATTENTION_AVAILABLE"),
+    (0xc05c_ff02, "STATUS_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED"),
+    (0xc05c_ff03, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED"),
+    (0xc05c_ff04, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED"),
+    (0xc05c_ff05, "STATUS_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED"),
+    (0xc05c_ff06, "STATUS_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED"),
+    (0xc05c_ff07, "STATUS_SVHDX_RESERVATION_CONFLICT"),
+    (0xc05c_ff08, "STATUS_SVHDX_WRONG_FILE_TYPE"),
+    (0xc05c_ff09, "STATUS_SVHDX_VERSION_MISMATCH"),
+    (0xc05c_ff0a, "STATUS_VHD_SHARED"),
+    (0xc05c_ff0b, "STATUS_SVHDX_NO_INITIATOR"),
+    (0xc05c_ff0c, "STATUS_VHDSET_BACKING_STORAGE_NOT_FOUND"),
+    (0xc05d_0000, "STATUS_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP"),
+    (0xc05d_0001, "STATUS_SMB_BAD_CLUSTER_DIALECT"),
+    (0xc05d_0002, "STATUS_SMB_GUEST_LOGON_BLOCKED"),
+    (0xc05d_0003, "STATUS_SMB_NO_SIGNING_ALGORITHM_OVERLAP"),
+    (0xc05d_0004, "STATUS_NETWORK_AUTHENTICATION_PROMPT_CANCELED"),
+    (0xc0e7_0001, "STATUS_SPACES_FAULT_DOMAIN_TYPE_INVALID"),
+    (0xc0e7_0003, "STATUS_SPACES_RESILIENCY_TYPE_INVALID"),
+    (0xc0e7_0004, "STATUS_SPACES_DRIVE_SECTOR_SIZE_INVALID"),
+    (0xc0e7_0006, "STATUS_SPACES_DRIVE_REDUNDANCY_INVALID"),
+    (0xc0e7_0007, "STATUS_SPACES_NUMBER_OF_DATA_COPIES_INVALID"),
+    (0xc0e7_0009, "STATUS_SPACES_INTERLEAVE_LENGTH_INVALID"),
+    (0xc0e7_000a, "STATUS_SPACES_NUMBER_OF_COLUMNS_INVALID"),
+    (0xc0e7_000b, "STATUS_SPACES_NOT_ENOUGH_DRIVES"),
+    (0xc0e7_000c, "STATUS_SPACES_EXTENDED_ERROR"),
+    (0xc0e7_000d, "STATUS_SPACES_PROVISIONING_TYPE_INVALID"),
+    (0xc0e7_000e, "STATUS_SPACES_ALLOCATION_SIZE_INVALID"),
+    (0xc0e7_000f, "STATUS_SPACES_ENCLOSURE_AWARE_INVALID"),
+    (0xc0e7_0010, "STATUS_SPACES_WRITE_CACHE_SIZE_INVALID"),
+    (0xc0e7_0011, "STATUS_SPACES_NUMBER_OF_GROUPS_INVALID"),
+    (0xc0e7_0012, "STATUS_SPACES_DRIVE_OPERATIONAL_STATE_INVALID"),
+    (0xc0e7_0013, "STATUS_SPACES_UPDATE_COLUMN_STATE"),
+    (0xc0e7_0014, "STATUS_SPACES_MAP_REQUIRED"),
+    (0xc0e7_0015, "STATUS_SPACES_UNSUPPORTED_VERSION"),
+    (0xc0e7_0016, "STATUS_SPACES_CORRUPT_METADATA"),
+    (0xc0e7_0017, "STATUS_SPACES_DRT_FULL"),
+    (0xc0e7_0018, "STATUS_SPACES_INCONSISTENCY"),
+    (0xc0e7_0019, "STATUS_SPACES_LOG_NOT_READY"),
+    (0xc0e7_001a, "STATUS_SPACES_NO_REDUNDANCY"),
+    (0xc0e7_001b, "STATUS_SPACES_DRIVE_NOT_READY"),
+    (0xc0e7_001c, "STATUS_SPACES_DRIVE_SPLIT"),
+    (0xc0e7_001d, "STATUS_SPACES_DRIVE_LOST_DATA"),
+    (0xc0e7_001e, "STATUS_SPACES_ENTRY_INCOMPLETE"),
+    (0xc0e7_001f, "STATUS_SPACES_ENTRY_INVALID"),
+    (0xc0e7_0020, "STATUS_SPACES_MARK_DIRTY"),
+    (0xc0e7_0021, "STATUS_SPACES_PD_NOT_FOUND"),
+    (0xc0e7_0022, "STATUS_SPACES_PD_LENGTH_MISMATCH"),
+    (0xc0e7_0023, "STATUS_SPACES_PD_UNSUPPORTED_VERSION"),
+    (0xc0e7_0024, "STATUS_SPACES_PD_INVALID_DATA"),
+    (0xc0e7_0025, "STATUS_SPACES_FLUSH_METADATA"),
+    (0xc0e7_0026, "STATUS_SPACES_CACHE_FULL"),
+    (0xc0e7_0027, "STATUS_SPACES_REPAIR_IN_PROGRESS"),
+    (0xc0e8_0000, "STATUS_SECCORE_INVALID_COMMAND"),
+    (0xc0e9_0001, "STATUS_SYSTEM_INTEGRITY_ROLLBACK_DETECTED"),
+    (0xc0e9_0002, "STATUS_SYSTEM_INTEGRITY_POLICY_VIOLATION"),
+    (0xc0e9_0003, "STATUS_SYSTEM_INTEGRITY_INVALID_POLICY"),
+    (0xc0e9_0004, "STATUS_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED"),
+    (0xc0e9_0005, "STATUS_SYSTEM_INTEGRITY_TOO_MANY_POLICIES"),
+    (0xc0e9_0006, "STATUS_SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED"),
+    (0xc0e9_0007, "STATUS_SYSTEM_INTEGRITY_REPUTATION_MALICIOUS"),
+    (0xc0e9_0008, "STATUS_SYSTEM_INTEGRITY_REPUTATION_PUA"),
+    (0xc0e9_0009, "STATUS_SYSTEM_INTEGRITY_REPUTATION_DANGEROUS_EXT"),
+    (0xc0e9_000a, "STATUS_SYSTEM_INTEGRITY_REPUTATION_OFFLINE"),
+    (0xc0e9_000b, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNFRIENDLY_FILE"),
+    (0xc0e9_000c, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNATTAINABLE"),
+    (0xc0e9_000d, "STATUS_SYSTEM_INTEGRITY_REPUTATION_EXPLICIT_DENY_FILE"),
+    (0xc0ea_0001, "STATUS_NO_APPLICABLE_APP_LICENSES_FOUND"),
+    (0xc0ea_0002, "STATUS_CLIP_LICENSE_NOT_FOUND"),
+    (0xc0ea_0003, "STATUS_CLIP_DEVICE_LICENSE_MISSING"),
+    (0xc0ea_0004, "STATUS_CLIP_LICENSE_INVALID_SIGNATURE"),
+    (0xc0ea_0005, "STATUS_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID"),
+    (0xc0ea_0006, "STATUS_CLIP_LICENSE_EXPIRED"),
+    (0xc0ea_0007, "STATUS_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE"),
+    (0xc0ea_0008, "STATUS_CLIP_LICENSE_NOT_SIGNED"),
+    (0xc0ea_0009, "STATUS_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE"),
+    (0xc0ea_000a, "STATUS_CLIP_LICENSE_DEVICE_ID_MISMATCH"),
+    (0xc0eb_0001, "STATUS_PLATFORM_MANIFEST_NOT_AUTHORIZED"),
+    (0xc0eb_0002, "STATUS_PLATFORM_MANIFEST_INVALID"),
+    (0xc0eb_0003, "STATUS_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED"),
+    (0xc0eb_0004, "STATUS_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED"),
+    (0xc0eb_0005, "STATUS_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND"),
+    (0xc0eb_0006, "STATUS_PLATFORM_MANIFEST_NOT_ACTIVE"),
+    (0xc0eb_0007, "STATUS_PLATFORM_MANIFEST_NOT_SIGNED"),
+    (0xc0ec_0000, "STATUS_APPEXEC_CONDITION_NOT_SATISFIED"),
+    (0xc0ec_0001, "STATUS_APPEXEC_HANDLE_INVALIDATED"),
+    (0xc0ec_0002, "STATUS_APPEXEC_INVALID_HOST_GENERATION"),
+    (0xc0ec_0003, "STATUS_APPEXEC_UNEXPECTED_PROCESS_REGISTRATION"),
+    (0xc0ec_0004, "STATUS_APPEXEC_INVALID_HOST_STATE"),
+    (0xc0ec_0005, "STATUS_APPEXEC_NO_DONOR"),
+    (0xc0ec_0006, "STATUS_APPEXEC_HOST_ID_MISMATCH"),
+    (0xc0ec_0007, "STATUS_APPEXEC_UNKNOWN_USER"),
+    (0xc0ec_0008, "STATUS_APPEXEC_APP_COMPAT_BLOCK"),
+    (0xc0ec_0009, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT"),
+    (0xc0ec_000a, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_TERMINATION"),
+    (0xc0ec_000b, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_LICENSING"),
+    (0xc0ec_000c, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_RESOURCES"),
+];
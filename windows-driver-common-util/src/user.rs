@@ -0,0 +1,45 @@
+//! User-mode side of the driver protocols: sending device control requests.
+
+use core::ffi::c_void;
+use core::mem::{MaybeUninit, size_of};
+
+use windows::Win32::Foundation::ERROR_INVALID_DATA;
+pub use windows::Win32::Foundation::HANDLE;
+use windows::Win32::System::IO::DeviceIoControl;
+pub use windows::core::Result;
+
+use crate::ioctl::{Ioctl, check};
+
+/// Sends `I` to `device` with `input` as the input buffer and returns the
+/// output buffer, failing with `ERROR_INVALID_DATA` if the driver returns
+/// fewer bytes than `I::Output` occupies.
+///
+/// Zero-sized inputs and outputs such as `()` are passed as no buffer at all.
+pub fn device_io_control<I: Ioctl>(device: HANDLE, input: &I::Input) -> Result<I::Output> {
+    const { check::<I>() };
+    let input_size = size_of::<I::Input>();
+    let output_size = size_of::<I::Output>();
+    let mut output = MaybeUninit::<I::Output>::zeroed();
+    let mut bytes_returned = 0;
+    let input_buffer = (input_size != 0).then_some(input as *const I::Input as *const c_void);
+    let output_buffer = (output_size != 0).then_some(output.as_mut_ptr() as *mut c_void);
+    // SAFETY: The buffers are valid for their sizes for the duration of the
+    // synchronous call.
+    unsafe {
+        DeviceIoControl(
+            device,
+            I::CODE.as_u32(),
+            input_buffer,
+            input_size as u32,
+            output_buffer,
+            output_size as u32,
+            Some(&mut bytes_returned),
+            None,
+        )?;
+    }
+    if (bytes_returned as usize) < output_size {
+        return Err(ERROR_INVALID_DATA.into());
+    }
+    // SAFETY: Zeroed memory is a valid `Pod`, whatever the driver wrote.
+    Ok(unsafe { output.assume_init() })
+}
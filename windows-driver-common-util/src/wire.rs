@@ -0,0 +1,202 @@
+//! Structs crossing the user/kernel boundary, whose layout must be the same
+//! for 64-bit clients and 32-bit clients running under WOW64.
+//!
+//! `#[derive(WireStruct)]`, with the `derive` feature, checks at compile time
+//! that a `#[repr(C)]` struct consists of fixed-size fields only, that its
+//! layout is the one both pointer widths agree on, and that it has no
+//! implicit padding. It also implements [`Pod`].
+//!
+//! [`WireStruct::FINGERPRINT`] hashes the layout, so a test can snapshot it
+//! and catch accidental ABI breaks:
+//!
+//! ```
+//! use windows_driver_common_util::wire::WireStruct;
+//!
+//! #[derive(WireStruct, Clone, Copy)]
+//! #[repr(C)]
+//! pub struct ThreadData {
+//!     pub thread_id: u32,
+//!     pub priority: i32,
+//! }
+//!
+//! assert_eq!(ThreadData::FINGERPRINT, 0x0e50_a1b8_ae17_456f);
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "derive")]
+pub use windows_driver_common_util_derive::WireStruct;
+
+use crate::pod::Pod;
+
+/// A type with the same size and alignment in 32-bit and 64-bit processes.
+///
+/// # Safety
+///
+/// [`WIRE_ALIGN`](Self::WIRE_ALIGN) must be the alignment of the type on every
+/// Windows target.
+pub unsafe trait WireType: Pod {
+    /// The alignment in the wire layout.
+    const WIRE_ALIGN: usize;
+
+    /// Hash of the type's layout; see [`WireStruct::FINGERPRINT`].
+    const WIRE_FINGERPRINT: u64;
+}
+
+/// A struct whose layout was verified by `#[derive(WireStruct)]`.
+pub trait WireStruct: WireType {
+    /// The fields in declaration order.
+    const FIELDS: &'static [WireField];
+
+    /// Hash of the field types, offsets, size and alignment. Field and struct
+    /// names do not contribute, since renaming does not break the ABI.
+    const FINGERPRINT: u64 = Self::WIRE_FINGERPRINT;
+
+    /// A description of the layout, e.g. for a failed snapshot assertion.
+    fn layout() -> Layout {
+        Layout {
+            fields: Self::FIELDS,
+            size: core::mem::size_of::<Self>(),
+            align: Self::WIRE_ALIGN,
+        }
+    }
+}
+
+/// A field of a [`WireStruct`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireField {
+    pub name: &'static str,
+    /// The type as written in the declaration.
+    pub ty: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+/// The layout of a [`WireStruct`], written as
+/// `{ thread_id: u32 @ 0, priority: i32 @ 4 } size 8 align 4`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub fields: &'static [WireField],
+    pub size: usize,
+    pub align: usize,
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, field) in self.fields.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(
+                f,
+                "{separator}{}: {} @ {}",
+                field.name, field.ty, field.offset
+            )?;
+        }
+        write!(f, " }} size {} align {}", self.size, self.align)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+const fn fnv1a_u64(hash: u64, value: u64) -> u64 {
+    fnv1a(hash, &value.to_le_bytes())
+}
+
+macro_rules! impl_wire_type {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: Fixed-size primitives are naturally aligned on Windows.
+            unsafe impl WireType for $ty {
+                const WIRE_ALIGN: usize = core::mem::size_of::<$ty>();
+                const WIRE_FINGERPRINT: u64 = fnv1a(FNV_OFFSET_BASIS, stringify!($ty).as_bytes());
+            }
+        )*
+    };
+}
+
+impl_wire_type!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+// SAFETY: Arrays are aligned like their elements.
+unsafe impl<T: WireType, const N: usize> WireType for [T; N] {
+    const WIRE_ALIGN: usize = T::WIRE_ALIGN;
+    const WIRE_FINGERPRINT: u64 = fnv1a_u64(
+        fnv1a_u64(fnv1a(FNV_OFFSET_BASIS, b"[]"), T::WIRE_FINGERPRINT),
+        N as u64,
+    );
+}
+
+/// The `repr(C)` layout of fields with the given `(size, align)` pairs:
+/// their offsets, the size and the alignment. Used by the derive.
+#[doc(hidden)]
+pub const fn layout<const N: usize>(fields: [(usize, usize); N]) -> ([usize; N], usize, usize) {
+    let mut offsets = [0; N];
+    let mut offset: usize = 0;
+    let mut align = 1;
+    let mut i = 0;
+    while i < N {
+        let (field_size, field_align) = fields[i];
+        offset = offset.next_multiple_of(field_align);
+        offsets[i] = offset;
+        offset += field_size;
+        if field_align > align {
+            align = field_align;
+        }
+        i += 1;
+    }
+    (offsets, offset.next_multiple_of(align), align)
+}
+
+/// Panics, failing compilation, unless the compiled layout matches the wire
+/// layout and has no padding. Used by the derive.
+#[doc(hidden)]
+pub const fn assert_layout<const N: usize>(
+    offsets: [usize; N],
+    size: usize,
+    align: usize,
+    fields: [(usize, usize); N],
+) {
+    let (wire_offsets, wire_size, wire_align) = layout(fields);
+    let mut unpadded = 0;
+    let mut i = 0;
+    while i < N {
+        if offsets[i] != wire_offsets[i] {
+            panic!("field offset differs between 32-bit and 64-bit layouts");
+        }
+        unpadded += fields[i].0;
+        i += 1;
+    }
+    if size != wire_size || align != wire_align {
+        panic!("struct size or alignment differs between 32-bit and 64-bit layouts");
+    }
+    if size != unpadded {
+        panic!("wire structs must not contain implicit padding; add explicit reserved fields");
+    }
+}
+
+/// The fingerprint of a struct with fields of the given fingerprints and
+/// offsets. Used by the derive.
+#[doc(hidden)]
+pub const fn fingerprint<const N: usize>(
+    fields: [(u64, usize); N],
+    size: usize,
+    align: usize,
+) -> u64 {
+    let mut hash = fnv1a(FNV_OFFSET_BASIS, b"{}");
+    let mut i = 0;
+    while i < N {
+        hash = fnv1a_u64(hash, fields[i].0);
+        hash = fnv1a_u64(hash, fields[i].1 as u64);
+        i += 1;
+    }
+    fnv1a_u64(fnv1a_u64(hash, size as u64), align as u64)
+}
@@ -0,0 +1,205 @@
+use windows_driver_common_util::define_ioctls;
+use windows_driver_common_util::ioctl::{
+    FILE_ANY_ACCESS, FILE_WRITE_ACCESS, InputSource, Ioctl, IoctlCode, IoctlInfo, METHOD_BUFFERED,
+    METHOD_NEITHER, read_input, write_output,
+};
+use windows_driver_common_util::pod::Pod;
+use windows_driver_common_util::status::NTSTATUS;
+use windows_driver_common_util::status::{STATUS_BUFFER_TOO_SMALL, STATUS_INVALID_DEVICE_REQUEST};
+use windows_driver_common_util::wire::WireStruct;
+
+#[derive(WireStruct, Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Stats {
+    pub total_read: u64,
+    pub total_written: u64,
+}
+
+#[derive(WireStruct, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Priority {
+    pub thread_id: u32,
+    pub priority: i32,
+}
+
+define_ioctls! {
+    device_type = 0x8022;
+    #[derive(Debug, PartialEq)]
+    pub enum Request {
+        /// Returns the counters.
+        IOCTL_GET_STATS = (0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)
+            => GetStats -> Stats, fn get_stats;
+        IOCTL_CLEAR_STATS = (0x801, METHOD_NEITHER, FILE_ANY_ACCESS)
+            => ClearStats, fn clear_stats, user_pointers = false;
+        IOCTL_SET_PRIORITY = (0x802, METHOD_BUFFERED, FILE_WRITE_ACCESS)
+            => SetPriority(Priority), fn set_priority;
+    }
+}
+
+#[test]
+fn generates_codes() {
+    assert_eq!(IOCTL_GET_STATS, 0x8022_2000);
+    assert_eq!(IOCTL_CLEAR_STATS, 0x8022_2007);
+    assert_eq!(
+        IoctlCode::from_raw(IOCTL_SET_PRIORITY).to_string(),
+        "CTL_CODE(0x8022, 0x802, METHOD_BUFFERED, FILE_WRITE_ACCESS)"
+    );
+    assert_eq!(Request::ClearStats.code(), IOCTL_CLEAR_STATS);
+    assert_eq!(Request::GetStats.output_size(), 16);
+    assert_eq!(Request::ClearStats.output_size(), 0);
+}
+
+#[test]
+fn lists_metadata() {
+    assert_eq!(
+        Request::IOCTLS,
+        [
+            IoctlInfo {
+                name: "IOCTL_GET_STATS",
+                code: IoctlCode::from_raw(IOCTL_GET_STATS),
+                input_size: 0,
+                output_size: 16,
+                user_pointers: None,
+            },
+            IoctlInfo {
+                name: "IOCTL_CLEAR_STATS",
+                code: IoctlCode::from_raw(IOCTL_CLEAR_STATS),
+                input_size: 0,
+                output_size: 0,
+                user_pointers: Some(false),
+            },
+            IoctlInfo {
+                name: "IOCTL_SET_PRIORITY",
+                code: IoctlCode::from_raw(IOCTL_SET_PRIORITY),
+                input_size: 8,
+                output_size: 0,
+                user_pointers: None,
+            },
+        ]
+    );
+    assert_eq!(
+        IoctlInfo::of::<GetStats>("IOCTL_GET_STATS"),
+        Request::IOCTLS[0]
+    );
+}
+
+#[test]
+fn decodes_requests() {
+    let priority = Priority {
+        thread_id: 42,
+        priority: 31,
+    };
+    let input = &priority as *const Priority as *const _;
+    unsafe {
+        assert_eq!(
+            Request::decode(IOCTL_GET_STATS, core::ptr::null(), 0, 16),
+            Ok(Request::GetStats)
+        );
+        assert_eq!(
+            Request::decode(IOCTL_CLEAR_STATS, core::ptr::null(), 0, 0),
+            Ok(Request::ClearStats)
+        );
+        assert_eq!(
+            Request::decode(IOCTL_SET_PRIORITY, input, 8, 0),
+            Ok(Request::SetPriority(priority))
+        );
+    }
+}
+
+/// A caller-controlled buffer that records how much of it is read.
+struct Recorded<'a> {
+    buffer: &'a [u8],
+    reads: core::cell::RefCell<Vec<usize>>,
+}
+
+impl InputSource for Recorded<'_> {
+    fn read<T: Pod>(&self) -> Result<T, NTSTATUS> {
+        self.reads.borrow_mut().push(size_of::<T>());
+        self.buffer.read()
+    }
+}
+
+#[test]
+fn decode_from_reads_only_the_declared_input() {
+    let buffer = [0x2a, 0, 0, 0, 0x1f, 0, 0, 0, 0xff, 0xff];
+    let input = Recorded {
+        buffer: &buffer,
+        reads: Default::default(),
+    };
+    assert_eq!(
+        Request::decode_from(IOCTL_CLEAR_STATS, &input, 0),
+        Ok(Request::ClearStats)
+    );
+    assert_eq!(
+        Request::decode_from(IOCTL_GET_STATS, &input, 15),
+        Err(STATUS_BUFFER_TOO_SMALL)
+    );
+    assert!(input.reads.borrow().is_empty());
+
+    assert_eq!(
+        Request::decode_from(IOCTL_SET_PRIORITY, &input, 0),
+        Ok(Request::SetPriority(Priority {
+            thread_id: 42,
+            priority: 31
+        }))
+    );
+    assert_eq!(*input.reads.borrow(), [8]);
+    assert_eq!(
+        Request::decode_from(IOCTL_SET_PRIORITY, &buffer[..7], 0),
+        Err(STATUS_BUFFER_TOO_SMALL)
+    );
+}
+
+#[test]
+fn rejects_short_buffers_and_unknown_codes() {
+    let priority = [0u8; 7];
+    unsafe {
+        assert_eq!(
+            Request::decode(IOCTL_GET_STATS, core::ptr::null(), 0, 15),
+            Err(STATUS_BUFFER_TOO_SMALL)
+        );
+        assert_eq!(
+            Request::decode(IOCTL_SET_PRIORITY, priority.as_ptr().cast(), 7, 0),
+            Err(STATUS_BUFFER_TOO_SMALL)
+        );
+        assert_eq!(
+            Request::decode(0x8022_2010, core::ptr::null(), 0, 0),
+            Err(STATUS_INVALID_DEVICE_REQUEST)
+        );
+    }
+}
+
+#[test]
+fn declares_typed_ioctls() {
+    assert_eq!(GetStats::CODE.as_u32(), IOCTL_GET_STATS);
+    assert_eq!(GetStats::USER_POINTERS, None);
+    assert_eq!(ClearStats::USER_POINTERS, Some(false));
+
+    let mut buffer = [0u8; 16];
+    let stats = Stats {
+        total_read: 1,
+        total_written: 2,
+    };
+    unsafe {
+        assert_eq!(
+            write_output::<GetStats>(buffer.as_mut_ptr().cast(), 16, stats),
+            Ok(16)
+        );
+        assert_eq!(
+            write_output::<GetStats>(buffer.as_mut_ptr().cast(), 8, stats),
+            Err(STATUS_BUFFER_TOO_SMALL)
+        );
+        assert_eq!(
+            read_input::<SetPriority>(buffer.as_ptr().cast(), 16),
+            Ok(Priority {
+                thread_id: 1,
+                priority: 0
+            })
+        );
+        assert_eq!(read_input::<ClearStats>(core::ptr::null(), 0), Ok(()));
+        assert_eq!(
+            write_output::<ClearStats>(core::ptr::null_mut(), 0, ()),
+            Ok(0)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use windows_driver_common_util::guid;
+use windows_driver_common_util::guid::{GUID, Guid, ParseGuidError};
+
+const BOOSTER_PROVIDER: GUID = guid!("b2723ad5-1678-446d-a577-8599d3e85ecb");
+
+#[test]
+fn literal_produces_sys_guid() {
+    assert_eq!(
+        BOOSTER_PROVIDER,
+        GUID {
+            Data1: 0xb2723ad5,
+            Data2: 0x1678,
+            Data3: 0x446d,
+            Data4: [0xa5, 0x77, 0x85, 0x99, 0xd3, 0xe8, 0x5e, 0xcb],
+        }
+    );
+    assert_eq!(guid!("{B2723AD5-1678-446D-A577-8599D3E85ECB}"), BOOSTER_PROVIDER);
+}
+
+#[test]
+fn round_trips_through_registry_form() {
+    let guid: Guid = "b2723ad5-1678-446d-a577-8599d3e85ecb".parse().unwrap();
+    let formatted = guid.to_string();
+    assert_eq!(formatted, "{B2723AD5-1678-446D-A577-8599D3E85ECB}");
+    assert_eq!(formatted.parse::<Guid>(), Ok(guid));
+    assert_eq!(Guid::from(BOOSTER_PROVIDER), guid);
+    assert_eq!(GUID::from(guid), BOOSTER_PROVIDER);
+    assert_eq!(guid.to_u128(), 0xb2723ad5_1678_446d_a577_8599d3e85ecb);
+    assert_eq!(Guid::from_u128(guid.to_u128()), guid);
+    assert_eq!(Guid::default().to_string(), "{00000000-0000-0000-0000-000000000000}");
+}
+
+#[test]
+fn rejects_malformed_strings() {
+    let cases = [
+        ("", ParseGuidError::InvalidLength),
+        ("b2723ad5-1678-446d-a577-8599d3e85ec", ParseGuidError::InvalidLength),
+        ("{b2723ad5-1678-446d-a577-8599d3e85ecb", ParseGuidError::UnmatchedBrace),
+        ("b2723ad5-1678-446d-a577-8599d3e85ecb}", ParseGuidError::UnmatchedBrace),
+        ("b2723ad5f1678-446d-a577-8599d3e85ecb", ParseGuidError::MisplacedHyphen),
+        ("b2723ad-51678-446d-a577-8599d3e85ecb", ParseGuidError::MisplacedHyphen),
+        ("b2723ad5-1678-446d-a577-8599d3e85ecg", ParseGuidError::InvalidDigit),
+        ("+2723ad5-1678-446d-a577-8599d3e85ecb", ParseGuidError::InvalidDigit),
+    ];
+    for (s, error) in cases {
+        assert_eq!(Guid::parse(s), Err(error), "{s:?}");
+    }
+}
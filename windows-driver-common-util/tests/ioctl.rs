@@ -0,0 +1,65 @@
+use windows_driver_common_util::ctl_code;
+use windows_driver_common_util::ioctl::{
+    FILE_ANY_ACCESS, FILE_DEVICE_UNKNOWN, FILE_READ_ACCESS, FILE_WRITE_ACCESS, IoctlCode,
+    METHOD_BUFFERED, METHOD_NEITHER, METHOD_OUT_DIRECT, RequiredAccess, TransferMethod,
+};
+
+const GET_STATS: IoctlCode = IoctlCode::new(0x8022, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS);
+
+#[test]
+fn encodes_like_ctl_code() {
+    assert_eq!(GET_STATS.as_u32(), 0x8022_2000);
+    assert_eq!(
+        ctl_code!(0x8022, 0x801, METHOD_NEITHER, FILE_ANY_ACCESS),
+        0x8022_2007
+    );
+    assert_eq!(
+        IoctlCode::new(
+            FILE_DEVICE_UNKNOWN,
+            0x1,
+            METHOD_OUT_DIRECT,
+            FILE_READ_ACCESS | FILE_WRITE_ACCESS
+        )
+        .as_u32(),
+        0x0022_c006
+    );
+}
+
+#[test]
+fn decodes_raw_codes() {
+    let code = IoctlCode::from_raw(0x8022_e006);
+    assert_eq!(code.device_type(), 0x8022);
+    assert_eq!(code.function(), 0x801);
+    assert_eq!(code.method(), TransferMethod::OutDirect);
+    assert_eq!(code.access(), RequiredAccess::ReadWrite);
+    assert_eq!(IoctlCode::new(0x8022, 0x801, 2, 3), code);
+    assert_eq!(u32::from(IoctlCode::from(0x1234_5678)), 0x1234_5678);
+}
+
+#[test]
+fn displays_as_ctl_code() {
+    assert_eq!(
+        GET_STATS.to_string(),
+        "CTL_CODE(0x8022, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS)"
+    );
+    assert_eq!(
+        IoctlCode::from_raw(0x0022_e007).to_string(),
+        "CTL_CODE(0x22, 0x801, METHOD_NEITHER, FILE_READ_ACCESS | FILE_WRITE_ACCESS)"
+    );
+    assert_eq!(
+        format!("{GET_STATS:?}"),
+        "IoctlCode(0x80222000 = CTL_CODE(0x8022, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS))"
+    );
+}
+
+#[test]
+#[should_panic(expected = "function must fit into 12 bits")]
+fn rejects_out_of_range_function() {
+    IoctlCode::new(0x8022, 0x1000, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "method must be one of")]
+fn rejects_out_of_range_method() {
+    IoctlCode::new(0x8022, 0x800, 4, 0);
+}
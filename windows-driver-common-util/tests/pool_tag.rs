@@ -0,0 +1,30 @@
+use windows_driver_common_util::pool_tag;
+use windows_driver_common_util::pool_tag::PoolTag;
+
+const SAMPLE_TAG: PoolTag = pool_tag!("Samp");
+
+#[test]
+fn tag_is_stored_in_poolmon_order() {
+    // The first character is the least significant byte, like the C
+    // multi-character constant 'pmaS'.
+    assert_eq!(SAMPLE_TAG.as_u32(), 0x706d_6153);
+    assert_eq!(u32::from(SAMPLE_TAG), u32::from_le_bytes(*b"Samp"));
+    assert_eq!(SAMPLE_TAG.to_bytes(), *b"Samp");
+    assert_eq!(SAMPLE_TAG.to_string(), "Samp");
+    assert_eq!(format!("{SAMPLE_TAG:?}"), "PoolTag(\"Samp\")");
+    assert_eq!(PoolTag::from_u32(SAMPLE_TAG.as_u32()), SAMPLE_TAG);
+    assert_eq!(PoolTag::from_u32(0x0061_6201).to_string(), ".ba.");
+    assert_eq!(pool_tag!("Ab 1").to_string(), "Ab 1");
+}
+
+#[test]
+#[should_panic(expected = "exactly four characters")]
+fn rejects_wrong_length() {
+    PoolTag::new("Sam");
+}
+
+#[test]
+#[should_panic(expected = "printable ASCII")]
+fn rejects_unprintable_characters() {
+    PoolTag::new("Sa\tp");
+}
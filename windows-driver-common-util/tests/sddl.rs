@@ -0,0 +1,137 @@
+use windows_driver_common_util::sddl;
+use windows_driver_common_util::sddl::{GenericMapping, SddlError, SecurityDescriptor, compile_into};
+
+const DEVICE_SDDL: &str = "D:P(A;;GA;;;SY)(A;;GA;;;BA)";
+const DEVICE_DESCRIPTOR: SecurityDescriptor<'static> = sddl!(DEVICE_SDDL);
+
+fn round_trip(sddl: &str) -> String {
+    let mut buffer = [0u8; 1024];
+    let len = compile_into(sddl, None, &mut buffer).unwrap();
+    SecurityDescriptor::from_bytes(&buffer[..len]).unwrap().to_string()
+}
+
+/// The `Security` strings of the repository's INF files.
+fn inf_security_strings() -> Vec<&'static str> {
+    let infs = [
+        include_str!("../../chapter_02/chapter_02.inx"),
+        include_str!("../../chapter_04/booster/booster.inx"),
+        include_str!("../../chapter_05/booster/booster.inx"),
+        include_str!("../../chapter_05/booster2/booster.inx"),
+        include_str!("../../chapter_07/zero/zero.inx"),
+    ];
+    infs.iter()
+        .flat_map(|inf| inf.lines())
+        .filter_map(|line| line.strip_prefix("HKR,,Security,,\""))
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect()
+}
+
+#[test]
+fn compiles_device_acl() {
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        // Revision, control (self-relative, DACL protected and present)
+        0x01, 0x00, 0x04, 0x90,
+        // Owner, group, SACL, DACL offsets
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x14, 0, 0, 0,
+        // ACL: revision, size, ACE count
+        0x02, 0x00, 0x34, 0x00, 0x02, 0x00, 0x00, 0x00,
+        // ACCESS_ALLOWED_ACE with GENERIC_ALL for S-1-5-18
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x01, 0x01, 0, 0, 0, 0, 0, 0x05, 0x12, 0, 0, 0,
+        // ACCESS_ALLOWED_ACE with GENERIC_ALL for S-1-5-32-544
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x01, 0x02, 0, 0, 0, 0, 0, 0x05, 0x20, 0, 0, 0, 0x20, 0x02, 0, 0,
+    ];
+    assert_eq!(DEVICE_DESCRIPTOR.as_bytes(), expected);
+    assert!(DEVICE_DESCRIPTOR.as_ptr().cast::<u32>().is_aligned());
+    assert_eq!(DEVICE_DESCRIPTOR.to_string(), DEVICE_SDDL);
+}
+
+#[test]
+fn round_trips_inf_strings() {
+    let strings = inf_security_strings();
+    assert!(strings.len() >= 4);
+    for sddl in strings {
+        assert_eq!(round_trip(sddl), sddl);
+    }
+}
+
+#[test]
+fn round_trips_all_components() {
+    for sddl in [
+        "O:BAG:SYD:PAI(A;OICI;FA;;;SY)(D;;0x1234;;;S-1-5-21-1-2-3-500)S:(ML;;NW;;;LW)",
+        "O:S-1-5-21-3623811015-3361044348-30300820-1013D:NO_ACCESS_CONTROL",
+        "D:(A;;RCSDWDWO;;;WD)(A;CIIO;GRGX;;;AU)",
+        "S:PAR(AU;SAFA;KA;;;BU)",
+        "D:",
+        "",
+    ] {
+        assert_eq!(round_trip(sddl), sddl);
+    }
+    // Components are written in canonical order; numbers in hexadecimal.
+    assert_eq!(round_trip("D:(A;;1179785;;;IU)G:BUO:BA"), "O:BAG:BUD:(A;;FR;;;IU)");
+    assert_eq!(round_trip("D:(A;;0x0;;;S-1-0x100000000-1)"), "D:(A;;0x0;;;S-1-0x100000000-1)");
+}
+
+#[test]
+fn maps_generic_rights() {
+    let mapped = sddl!(DEVICE_SDDL, GenericMapping::FILE);
+    assert_eq!(mapped.to_string(), "D:P(A;;FA;;;SY)(A;;FA;;;BA)");
+    assert_eq!(GenericMapping::FILE.map(sddl::GENERIC_READ | 0x1), 0x0012_0089);
+}
+
+#[test]
+fn rejects_malformed_sddl() {
+    let cases = [
+        ("X:", SddlError::InvalidComponent),
+        ("(A;;GA;;;SY)", SddlError::InvalidComponent),
+        ("D:(A;;GA;;;SY)D:", SddlError::DuplicateComponent),
+        ("D:Q(A;;GA;;;SY)", SddlError::InvalidAclFlags),
+        ("D:(A;;GA;;;SY", SddlError::InvalidAce),
+        ("D:(A;;GA;;SY)", SddlError::InvalidAce),
+        ("D:(A;;GA;;;;SY)", SddlError::InvalidAce),
+        ("D:(A;;GA;;;SY))", SddlError::InvalidAce),
+        ("D:(OA;;GA;;;SY)", SddlError::UnsupportedAceType),
+        ("D:(AU;;GA;;;SY)", SddlError::UnsupportedAceType),
+        ("D:(A;;GA;00000000-0000-0000-0000-000000000000;;SY)", SddlError::UnsupportedAceType),
+        ("D:(A;XX;GA;;;SY)", SddlError::InvalidAceFlags),
+        ("D:(A;;GZ;;;SY)", SddlError::InvalidRights),
+        ("D:(A;;0x1FFFFFFFF;;;SY)", SddlError::InvalidRights),
+        ("D:(A;;GA;;;XY)", SddlError::InvalidSid),
+        ("D:(A;;GA;;;S-2-5-18)", SddlError::InvalidSid),
+        ("D:(A;;GA;;;S-1-5)", SddlError::InvalidSid),
+        ("D:(A;;GA;;;DA)", SddlError::UnsupportedSid),
+        ("D:NO_ACCESS_CONTROL(A;;GA;;;SY)", SddlError::NullAclWithAces),
+        ("O:", SddlError::InvalidSid),
+    ];
+    let mut buffer = [0u8; 256];
+    for (sddl, error) in cases {
+        assert_eq!(compile_into(sddl, None, &mut buffer), Err(error), "{sddl:?}");
+    }
+    assert_eq!(
+        compile_into(DEVICE_SDDL, None, &mut buffer[..71]),
+        Err(SddlError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn rejects_invalid_descriptors() {
+    let bytes = DEVICE_DESCRIPTOR.as_bytes();
+    assert_eq!(SecurityDescriptor::from_bytes(&bytes[..19]), Err(SddlError::InvalidDescriptor));
+    assert_eq!(SecurityDescriptor::from_bytes(&bytes[..71]), Err(SddlError::InvalidDescriptor));
+
+    let mut not_self_relative = bytes.to_vec();
+    not_self_relative[3] &= !0x80;
+    assert_eq!(
+        SecurityDescriptor::from_bytes(&not_self_relative),
+        Err(SddlError::InvalidDescriptor)
+    );
+
+    let mut object_ace = bytes.to_vec();
+    object_ace[28] = 0x05;
+    assert_eq!(
+        SecurityDescriptor::from_bytes(&object_ace),
+        Err(SddlError::UnsupportedAceType)
+    );
+}
@@ -0,0 +1,84 @@
+use windows_driver_common_util::status::{
+    ERROR_MR_MID_NOT_FOUND, NtStatus, STATUS_ACCESS_DENIED, STATUS_BUFFER_OVERFLOW, STATUS_PENDING,
+    STATUS_SUCCESS, Severity,
+};
+
+#[test]
+fn decodes_fields() {
+    let denied = NtStatus::from_raw(STATUS_ACCESS_DENIED);
+    assert_eq!(denied.severity(), Severity::Error);
+    assert_eq!(denied.facility(), 0);
+    assert_eq!(denied.code(), 0x22);
+    assert!(!denied.is_customer());
+    assert!(denied.is_error() && !denied.is_success() && !denied.is_warning());
+
+    let overflow = NtStatus::from(STATUS_BUFFER_OVERFLOW);
+    assert!(overflow.is_warning() && !overflow.is_success());
+    assert!(NtStatus::from(STATUS_PENDING).is_success());
+    assert_eq!(NtStatus::from(STATUS_PENDING).severity(), Severity::Success);
+
+    let custom = NtStatus::from_raw(0xe00a_0001_u32 as i32);
+    assert!(custom.is_customer());
+    assert_eq!(custom.facility(), 0xa);
+    assert_eq!(i32::from(custom), 0xe00a_0001_u32 as i32);
+}
+
+#[test]
+fn displays_names() {
+    assert_eq!(
+        NtStatus::from(STATUS_ACCESS_DENIED).to_string(),
+        "STATUS_ACCESS_DENIED"
+    );
+    assert_eq!(
+        NtStatus::from(STATUS_SUCCESS).name(),
+        Some("STATUS_SUCCESS")
+    );
+    assert_eq!(
+        NtStatus::from_raw(0x4000_0000).name(),
+        Some("STATUS_OBJECT_NAME_EXISTS")
+    );
+    assert_eq!(
+        NtStatus::from_raw(0xc000_00c0_u32 as i32).to_string(),
+        "STATUS_DEVICE_DOES_NOT_EXIST"
+    );
+    assert_eq!(NtStatus::from_raw(0x0001_0002).name(), Some("DBG_CONTINUE"));
+    assert_eq!(
+        NtStatus::from_raw(0x0000_0080).name(),
+        Some("STATUS_ABANDONED")
+    );
+    assert_eq!(
+        NtStatus::from_raw(0xc0ff_ee00_u32 as i32).to_string(),
+        "0xC0FFEE00"
+    );
+    assert_eq!(
+        format!("{:?}", NtStatus::from(STATUS_PENDING)),
+        "NtStatus(STATUS_PENDING)"
+    );
+}
+
+#[test]
+fn maps_to_win32_errors() {
+    assert_eq!(NtStatus::from(STATUS_SUCCESS).to_win32_error(), 0);
+    assert_eq!(NtStatus::from(STATUS_ACCESS_DENIED).to_win32_error(), 5);
+    assert_eq!(NtStatus::from(STATUS_PENDING).to_win32_error(), 997);
+    // NTSTATUS_FROM_WIN32(ERROR_FILE_EXISTS): FACILITY_NTWIN32 with the error
+    // as the code.
+    assert_eq!(
+        NtStatus::from_raw(0xc007_0050_u32 as i32).to_win32_error(),
+        80
+    );
+    assert_eq!(
+        NtStatus::from_raw(0xc0ff_ee00_u32 as i32).to_win32_error(),
+        ERROR_MR_MID_NOT_FOUND
+    );
+    // Customer values are passed through, even with the facility of a
+    // wrapped Win32 error.
+    assert_eq!(
+        NtStatus::from_raw(0xe00a_0001_u32 as i32).to_win32_error(),
+        0xe00a_0001
+    );
+    assert_eq!(
+        NtStatus::from_raw(0xe007_0050_u32 as i32).to_win32_error(),
+        0xe007_0050
+    );
+}
@@ -0,0 +1,8 @@
+use windows_driver_common_util::guid;
+use windows_driver_common_util::guid::GUID;
+
+const PROVIDER: GUID = guid!("b2723ad5-1678-446d-a577-8599d3e85ecx");
+
+fn main() {
+    let _ = PROVIDER;
+}
@@ -0,0 +1,9 @@
+use windows_driver_common_util::ctl_code;
+use windows_driver_common_util::ioctl::IoctlCode;
+
+const GET_STATS: IoctlCode = IoctlCode::new(0x1_8022, 0x800, 0, 0);
+const CLEAR_STATS: u32 = ctl_code!(0x8022, 0x801, 3, 4);
+
+fn main() {
+    let _ = (GET_STATS, CLEAR_STATS);
+}
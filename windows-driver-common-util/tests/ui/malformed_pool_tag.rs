@@ -0,0 +1,8 @@
+use windows_driver_common_util::pool_tag;
+use windows_driver_common_util::pool_tag::PoolTag;
+
+const DRIVER_TAG: PoolTag = pool_tag!("Sample");
+
+fn main() {
+    let _ = DRIVER_TAG;
+}
@@ -0,0 +1,8 @@
+use windows_driver_common_util::sddl;
+use windows_driver_common_util::sddl::SecurityDescriptor;
+
+const DEVICE: SecurityDescriptor<'static> = sddl!("D:P(A;;GA;;;SY)(A;;GA;;;DA)");
+
+fn main() {
+    let _ = DEVICE;
+}
@@ -0,0 +1,34 @@
+use windows_driver_common_util::wire::WireStruct;
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Request {
+    pub buffer: *const u8,
+    pub length: usize,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Length {
+    pub length: usize,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Padded {
+    pub kind: u32,
+    pub value: u64,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+pub struct NotReprC {
+    pub value: u32,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Flags {
+    pub enabled: bool,
+}
+
+fn main() {}
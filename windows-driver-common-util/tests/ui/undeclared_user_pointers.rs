@@ -0,0 +1,13 @@
+use windows_driver_common_util::define_ioctls;
+use windows_driver_common_util::ioctl::{FILE_ANY_ACCESS, METHOD_NEITHER};
+
+define_ioctls! {
+    device_type = 0x8022;
+    pub enum Request {
+        IOCTL_CLEAR_STATS = (0x801, METHOD_NEITHER, FILE_ANY_ACCESS) => ClearStats, fn clear_stats;
+    }
+}
+
+fn main() {
+    let _ = Request::ClearStats;
+}
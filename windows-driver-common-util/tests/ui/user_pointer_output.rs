@@ -0,0 +1,14 @@
+use windows_driver_common_util::define_ioctls;
+use windows_driver_common_util::ioctl::{FILE_ANY_ACCESS, METHOD_NEITHER};
+
+define_ioctls! {
+    device_type = 0x8022;
+    pub enum Request {
+        IOCTL_GET_STATS = (0x800, METHOD_NEITHER, FILE_ANY_ACCESS)
+            => GetStats -> u64, fn get_stats, user_pointers = true;
+    }
+}
+
+fn main() {
+    let _ = Request::GetStats;
+}
@@ -0,0 +1,67 @@
+use windows_driver_common_util::pod::Pod;
+use windows_driver_common_util::wire::{WireField, WireStruct};
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Stats {
+    pub total_read: u64,
+    pub total_written: u64,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Record {
+    pub kind: u16,
+    pub flags: [u8; 2],
+    pub id: u32,
+    pub stats: Stats,
+}
+
+#[derive(WireStruct, Clone, Copy)]
+#[repr(C)]
+pub struct Tuple(u32, i32);
+
+fn assert_pod<T: Pod>() {}
+
+#[test]
+fn describes_the_layout() {
+    assert_pod::<Record>();
+    assert_eq!(
+        Record::layout().to_string(),
+        "{ kind: u16 @ 0, flags: [u8;2] @ 2, id: u32 @ 4, stats: Stats @ 8 } size 24 align 8"
+    );
+    assert_eq!(
+        Record::FIELDS[3],
+        WireField {
+            name: "stats",
+            ty: "Stats",
+            offset: 8,
+            size: 16
+        }
+    );
+    assert_eq!(
+        Tuple::layout().to_string(),
+        "{ 0: u32 @ 0, 1: i32 @ 4 } size 8 align 4"
+    );
+}
+
+#[test]
+fn fingerprint_depends_on_layout_only() {
+    #[derive(WireStruct, Clone, Copy)]
+    #[repr(C)]
+    struct Renamed {
+        a: u32,
+        b: i32,
+    }
+
+    #[derive(WireStruct, Clone, Copy)]
+    #[repr(C)]
+    struct Swapped {
+        b: i32,
+        a: u32,
+    }
+
+    assert_eq!(Renamed::FINGERPRINT, Tuple::FINGERPRINT);
+    assert_ne!(Swapped::FINGERPRINT, Tuple::FINGERPRINT);
+    assert_ne!(Stats::FINGERPRINT, Record::FINGERPRINT);
+}
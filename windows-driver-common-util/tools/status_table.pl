@@ -0,0 +1,41 @@
+#!/usr/bin/env perl
+# Generates src/status_table.rs, the names of the NTSTATUS values defined in
+# ntstatus.h of the Windows SDK or mingw-w64:
+#
+#     perl tools/status_table.pl ntstatus.h > src/status_table.rs
+#
+# The Win32::Foundation module of the `windows` crate, which is generated
+# from the same header, is accepted as input too.
+#
+# Where several names share a value, e.g. STATUS_SUCCESS and STATUS_WAIT_0,
+# the first definition is kept.
+
+use strict;
+use warnings;
+
+my $name_re = qr/((?:STATUS|DBG|RPC_NT|EPT_NT)_\w+)/;
+
+my %names;
+my $source;
+while (my $line = <>) {
+    $source //= $ARGV =~ m{(windows-[\d.]+)/} ? "the $1 crate" : $ARGV =~ s{.*/}{}r;
+    my ($name, $value);
+    if ($line =~ /^#define\s+$name_re\s+\(\(NTSTATUS\)\s*(0x[0-9A-Fa-f]+)L?\)/) {
+        ($name, $value) = ($1, $2);
+    } elsif ($line =~ /^pub const $name_re: NTSTATUS = NTSTATUS\((0x[0-9A-Fa-f_]+?)(?:_u32)? as _\);/) {
+        ($name, $value) = ($1, $2 =~ s/_//gr);
+    } else {
+        next;
+    }
+    $names{hex $value} //= $name;
+}
+die "no NTSTATUS definitions found\n" unless %names;
+
+print "// Generated by tools/status_table.pl from $source, do not edit.\n\n";
+print "/// Value and name of each status, sorted by value.\n";
+print "#[rustfmt::skip]\n";
+print "pub(crate) const STATUS_NAMES: &[(u32, &str)] = &[\n";
+for my $value (sort { $a <=> $b } keys %names) {
+    printf "    (0x%04x_%04x, \"%s\"),\n", $value >> 16, $value & 0xffff, $names{$value};
+}
+print "];\n";
@@ -0,0 +1,317 @@
+//! The buffers of read, write and device control requests, wherever the I/O
+//! manager put them.
+//!
+//! Reads and writes follow the device's `DO_BUFFERED_IO`/`DO_DIRECT_IO`
+//! flags, device control requests the transfer method of their code:
+//!
+//! - buffered I/O passes a copy of the caller's buffer in the system buffer,
+//! - direct I/O locks the caller's pages and describes them with an MDL,
+//!   which is mapped into system space,
+//! - neither passes the caller's user-mode addresses, valid only in the
+//!   caller's process context.
+//!
+//! [`InputBuffer`] borrows the first two and copies user-mode input only when
+//! it is read, and only as much as is read, e.g. `size_of::<T>()` bytes for
+//! [`read_struct`](InputBuffer::read_struct). [`OutputBuffer`] borrows the
+//! first two and refuses user-mode output with `STATUS_NOT_SUPPORTED`.
+//! Requests from kernel mode are trusted like buffered ones.
+//!
+//! Rust cannot install a structured exception handler, so the checks of
+//! `ProbeForRead` are done without raising, failing with
+//! `STATUS_DATATYPE_MISALIGNMENT` or `STATUS_ACCESS_VIOLATION`, and input is
+//! copied with `MmCopyMemory`, which reports an invalid address as a status.
+//! There is no such routine for writing, and writing to user memory without
+//! a guard lets the caller crash the system by unmapping its buffer, so
+//! output is only supported with buffered and direct I/O. `define_ioctls!`
+//! rejects METHOD_NEITHER requests with output at compile time, which
+//! leaves reads on devices with neither I/O flag as the only requests
+//! refused here.
+
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+
+use wdk_sys::{
+    _MM_PAGE_PRIORITY::NormalPagePriority,
+    _MODE::KernelMode,
+    DO_BUFFERED_IO, DO_DIRECT_IO, MM_COPY_ADDRESS, MM_COPY_MEMORY_VIRTUAL, NTSTATUS,
+    STATUS_ACCESS_VIOLATION, STATUS_BUFFER_TOO_SMALL, STATUS_DATATYPE_MISALIGNMENT,
+    STATUS_INSUFFICIENT_RESOURCES, STATUS_INVALID_DEVICE_REQUEST, STATUS_INVALID_PARAMETER,
+    STATUS_NOT_SUPPORTED,
+    ntddk::{MmCopyMemory, MmUserProbeAddress},
+};
+use windows_driver_common_util::ioctl::{InputSource, IoctlCode, TransferMethod};
+use windows_driver_common_util::pod::Pod;
+
+use crate::{IntoNtResult, Irp, MmGetSystemAddressForMdlSafe, NtResult, Parameters};
+
+/// The input of a write or device control request.
+pub struct InputBuffer<'a>(Input<'a>);
+
+enum Input<'a> {
+    Borrowed(&'a [u8]),
+    /// A user-mode buffer, which is copied when read since its contents can
+    /// change or vanish at any time.
+    User(*const c_void, u32),
+}
+
+impl<'a> InputBuffer<'a> {
+    pub(crate) fn of(irp: &'a Irp) -> NtResult<Self> {
+        let (location, len) = locate(irp, false)?;
+        let input = match location {
+            Location::System(address) => Input::Borrowed(unsafe { slice(address, len) }?),
+            Location::Mdl => Input::Borrowed(unsafe { slice(map_mdl(irp, len)?, len) }?),
+            Location::User(address) => Input::User(address, len),
+        };
+        Ok(Self(input))
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            Input::Borrowed(buffer) => buffer.len(),
+            Input::User(_, len) => len as usize,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The buffer as a slice, unless it is a user-mode buffer.
+    pub fn as_slice(&self) -> Option<&[u8]> {
+        match self.0 {
+            Input::Borrowed(buffer) => Some(buffer),
+            Input::User(..) => None,
+        }
+    }
+
+    /// Reads a `T` from the start of the buffer, failing with
+    /// `STATUS_BUFFER_TOO_SMALL` if it is shorter. A user-mode buffer must
+    /// be aligned for `T`, like for `ProbeForRead`.
+    pub fn read_struct<T: Pod>(&self) -> NtResult<T> {
+        match self.0 {
+            Input::Borrowed(buffer) => buffer.read(),
+            Input::User(address, len) => {
+                if (len as usize) < size_of::<T>() {
+                    return Err(STATUS_BUFFER_TOO_SMALL);
+                }
+                probe(address, size_of::<T>(), align_of::<T>())?;
+                let mut value = MaybeUninit::<T>::uninit();
+                copy_from_user(value.as_mut_ptr().cast(), address, size_of::<T>())?;
+                // SAFETY: All bytes were copied, and any bit pattern is a
+                // valid `Pod`.
+                Ok(unsafe { value.assume_init() })
+            }
+        }
+    }
+
+    /// Copies the whole buffer into nonpaged pool.
+    pub fn to_vec(&self) -> NtResult<Vec<u8>> {
+        let mut copy = Vec::new();
+        copy.try_reserve_exact(self.len())
+            .map_err(|_| STATUS_INSUFFICIENT_RESOURCES)?;
+        match self.0 {
+            Input::Borrowed(buffer) => copy.extend_from_slice(buffer),
+            Input::User(address, len) => {
+                probe(address, len as usize, 1)?;
+                copy.resize(len as usize, 0);
+                copy_from_user(copy.as_mut_ptr(), address, len as usize)?;
+            }
+        }
+        Ok(copy)
+    }
+}
+
+/// For decoding requests with `define_ioctls!`, which reads only the
+/// declared input.
+impl InputSource for InputBuffer<'_> {
+    fn read<T: Pod>(&self) -> Result<T, NTSTATUS> {
+        self.read_struct()
+    }
+}
+
+/// The output of a read or device control request, in system memory.
+pub struct OutputBuffer<'a>(&'a mut [u8]);
+
+impl<'a> OutputBuffer<'a> {
+    pub(crate) fn of(irp: &'a mut Irp) -> NtResult<Self> {
+        let (location, len) = locate(irp, true)?;
+        let output = match location {
+            Location::System(address) => unsafe { slice(address, len) }?,
+            Location::Mdl => unsafe { slice(map_mdl(irp, len)?, len) }?,
+            // See the module documentation.
+            Location::User(_) => return Err(STATUS_NOT_SUPPORTED),
+        };
+        Ok(Self(output))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut *self.0
+    }
+
+    /// Copies `data` to the start of the buffer and returns its length, the
+    /// `Information` to complete the request with. Fails with
+    /// `STATUS_BUFFER_TOO_SMALL` if the buffer is shorter.
+    pub fn write(&mut self, data: &[u8]) -> NtResult<usize> {
+        let target = self
+            .0
+            .get_mut(..data.len())
+            .ok_or(STATUS_BUFFER_TOO_SMALL)?;
+        target.copy_from_slice(data);
+        Ok(data.len())
+    }
+
+    /// Writes `value` to the start of the buffer, like [`write`](Self::write).
+    pub fn write_struct<T: Pod>(&mut self, value: &T) -> NtResult<usize> {
+        // SAFETY: `Pod` types have no padding, so all their bytes are
+        // initialized.
+        let bytes = unsafe {
+            core::slice::from_raw_parts((value as *const T).cast::<u8>(), size_of::<T>())
+        };
+        self.write(bytes)
+    }
+
+    /// Fills the whole buffer with `value` and returns its length.
+    pub fn fill(&mut self, value: u8) -> usize {
+        self.0.fill(value);
+        self.len()
+    }
+}
+
+enum Location {
+    System(*mut c_void),
+    Mdl,
+    User(*mut c_void),
+}
+
+/// Where the input or output of the request is, and its length.
+fn locate(irp: &Irp, output: bool) -> NtResult<(Location, u32)> {
+    let raw = irp.as_ptr();
+    let stack = irp.stack_location();
+    // SAFETY: The IRP is owned, and each arm reads the fields the I/O
+    // manager fills in for the request.
+    unsafe {
+        let system = Location::System((*raw).AssociatedIrp.SystemBuffer);
+        let user = Location::User((*raw).UserBuffer);
+        let (location, len) = match (stack.parameters(), output) {
+            (Parameters::Read { length, .. }, true) | (Parameters::Write { length, .. }, false) => {
+                let flags = (*stack.as_raw().DeviceObject).Flags;
+                if flags & DO_BUFFERED_IO != 0 {
+                    (system, length)
+                } else if flags & DO_DIRECT_IO != 0 {
+                    (Location::Mdl, length)
+                } else {
+                    (user, length)
+                }
+            }
+            (Parameters::DeviceControl { code, in_len, .. }, false) => {
+                match IoctlCode::from_raw(code).method() {
+                    TransferMethod::Neither => (
+                        Location::User(stack.as_raw().Parameters.DeviceIoControl.Type3InputBuffer),
+                        in_len,
+                    ),
+                    _ => (system, in_len),
+                }
+            }
+            (Parameters::DeviceControl { code, out_len, .. }, true) => {
+                match IoctlCode::from_raw(code).method() {
+                    TransferMethod::Buffered => (system, out_len),
+                    TransferMethod::InDirect | TransferMethod::OutDirect => {
+                        (Location::Mdl, out_len)
+                    }
+                    TransferMethod::Neither => (user, out_len),
+                }
+            }
+            _ => return Err(STATUS_INVALID_DEVICE_REQUEST),
+        };
+        match location {
+            Location::User(address) if (*raw).RequestorMode == KernelMode as i8 => {
+                Ok((Location::System(address), len))
+            }
+            location => Ok((location, len)),
+        }
+    }
+}
+
+/// # Safety
+/// `address` must be null or valid for `len` bytes for the lifetime `'a`.
+unsafe fn slice<'a>(address: *mut c_void, len: u32) -> NtResult<&'a mut [u8]> {
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    if address.is_null() {
+        return Err(STATUS_INVALID_PARAMETER);
+    }
+    Ok(unsafe { core::slice::from_raw_parts_mut(address.cast(), len as usize) })
+}
+
+fn map_mdl(irp: &Irp, len: u32) -> NtResult<*mut c_void> {
+    if len == 0 {
+        return Ok(core::ptr::null_mut());
+    }
+    // SAFETY: The IRP is owned.
+    let mdl = unsafe { (*irp.as_ptr()).MdlAddress };
+    if mdl.is_null() {
+        return Err(STATUS_INVALID_PARAMETER);
+    }
+    let address = MmGetSystemAddressForMdlSafe(mdl, NormalPagePriority as u32);
+    if address.is_null() {
+        return Err(STATUS_INSUFFICIENT_RESOURCES);
+    }
+    Ok(address)
+}
+
+/// The checks of `ProbeForRead`, without raising: the buffer must be aligned
+/// to `align` and lie in user space.
+fn probe(address: *const c_void, len: usize, align: usize) -> NtResult {
+    if len == 0 {
+        return Ok(());
+    }
+    if !(address as usize).is_multiple_of(align) {
+        return Err(STATUS_DATATYPE_MISALIGNMENT);
+    }
+    let end = (address as usize)
+        .checked_add(len)
+        .ok_or(STATUS_ACCESS_VIOLATION)?;
+    // SAFETY: Set once by the kernel at boot.
+    if end > unsafe { MmUserProbeAddress } as usize {
+        return Err(STATUS_ACCESS_VIOLATION);
+    }
+    Ok(())
+}
+
+/// Copies `len` bytes of a probed user-mode buffer to `target`.
+fn copy_from_user(target: *mut u8, address: *const c_void, len: usize) -> NtResult {
+    if len == 0 {
+        return Ok(());
+    }
+    // SAFETY: An all-zero MM_COPY_ADDRESS is valid; the virtual address is
+    // set before it is used.
+    let mut source: MM_COPY_ADDRESS = unsafe { core::mem::zeroed() };
+    source.__bindgen_anon_1.VirtualAddress = address.cast_mut();
+    let mut copied = 0;
+    // SAFETY: The caller provides `len` writable bytes at `target`; an
+    // invalid source address makes MmCopyMemory fail instead of fault.
+    unsafe {
+        MmCopyMemory(
+            target.cast(),
+            source,
+            len as _,
+            MM_COPY_MEMORY_VIRTUAL,
+            &mut copied,
+        )
+    }
+    .into_nt_result()?;
+    if copied != len as _ {
+        return Err(STATUS_ACCESS_VIOLATION);
+    }
+    Ok(())
+}
@@ -0,0 +1,316 @@
+//! Device objects that delete themselves, together with their symbolic
+//! link, when dropped.
+//!
+//! ```ignore
+//! let device = DeviceBuilder::new()
+//!     .name(&DEVICE_NAME)
+//!     .symlink(&DEVICE_SYMLINK)
+//!     .direct_io()
+//!     .build(driver)?;
+//! ```
+//!
+//! The names need not be static, e.g. for numbered instances:
+//!
+//! ```ignore
+//! let name = UnicodeStringBuf::<32>::from_fmt(format_args!(r"\Device\Zero{index}"))?;
+//! let device = DeviceBuilder::new().name(&name.to_raw()).build(driver)?;
+//! ```
+//!
+//! The device keeps its own copy of the symbolic link name, to delete the
+//! link with. If creating the symbolic link fails, the device object is
+//! deleted again on the way out. A driver keeps the device until unload by
+//! handing it to the driver object with [`Device::into_raw`] and taking it
+//! back with [`Device::from_raw`] in its unload routine.
+
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use wdk_strings::{Error, Pool, UnicodeString};
+use wdk_sys::{
+    DEVICE_OBJECT, DO_BUFFERED_IO, DO_DIRECT_IO, DRIVER_OBJECT, FILE_DEVICE_UNKNOWN,
+    PDEVICE_OBJECT, STATUS_INSUFFICIENT_RESOURCES, STATUS_INVALID_DEVICE_STATE,
+    STATUS_INVALID_PARAMETER, UNICODE_STRING,
+    ntddk::{IoCreateDevice, IoCreateSymbolicLink, IoDeleteDevice, IoDeleteSymbolicLink},
+};
+
+use crate::{IntoNtResult, NtResult};
+
+/// What the device extension holds: the symbolic link to delete with the
+/// device, followed by the driver's extension.
+#[repr(C)]
+struct Extension<T> {
+    symlink: Option<UnicodeString>,
+    value: T,
+}
+
+/// Creates a [`Device`]. Without further settings, the device is an
+/// unnamed, non-exclusive `FILE_DEVICE_UNKNOWN` with neither buffered nor
+/// direct I/O and no extension.
+pub struct DeviceBuilder<'a, T = ()> {
+    name: Option<&'a UNICODE_STRING>,
+    symlink: Option<&'a UNICODE_STRING>,
+    device_type: u32,
+    characteristics: u32,
+    exclusive: bool,
+    flags: u32,
+    extension: PhantomData<T>,
+}
+
+impl DeviceBuilder<'_> {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            symlink: None,
+            device_type: FILE_DEVICE_UNKNOWN,
+            characteristics: 0,
+            exclusive: false,
+            flags: 0,
+            extension: PhantomData,
+        }
+    }
+}
+
+impl Default for DeviceBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: Default + Send + Sync> DeviceBuilder<'a, T> {
+    /// The device name, e.g. `\Device\Zero`.
+    pub fn name(mut self, name: &'a UNICODE_STRING) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// A symbolic link to the device, e.g. `\??\Zero`. Requires a name.
+    pub fn symlink(mut self, symlink: &'a UNICODE_STRING) -> Self {
+        self.symlink = Some(symlink);
+        self
+    }
+
+    /// One of the `FILE_DEVICE_*` types.
+    pub fn device_type(mut self, device_type: u32) -> Self {
+        self.device_type = device_type;
+        self
+    }
+
+    /// A combination of the `FILE_*` device characteristics, e.g.
+    /// `FILE_DEVICE_SECURE_OPEN`.
+    pub fn characteristics(mut self, characteristics: u32) -> Self {
+        self.characteristics = characteristics;
+        self
+    }
+
+    /// Whether only one handle to the device can be open at a time.
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Reads and writes pass the caller's buffer as an MDL.
+    pub fn direct_io(mut self) -> Self {
+        self.flags = (self.flags & !DO_BUFFERED_IO) | DO_DIRECT_IO;
+        self
+    }
+
+    /// Reads and writes pass a copy of the caller's buffer.
+    pub fn buffered_io(mut self) -> Self {
+        self.flags = (self.flags & !DO_DIRECT_IO) | DO_BUFFERED_IO;
+        self
+    }
+
+    /// Gives the device an extension holding a `U`, initialized with its
+    /// default value and dropped with the device.
+    pub fn extension<U: Default + Send + Sync>(self) -> DeviceBuilder<'a, U> {
+        DeviceBuilder {
+            name: self.name,
+            symlink: self.symlink,
+            device_type: self.device_type,
+            characteristics: self.characteristics,
+            exclusive: self.exclusive,
+            flags: self.flags,
+            extension: PhantomData,
+        }
+    }
+
+    /// Creates the device object and the symbolic link.
+    pub fn build(self, driver: &mut DRIVER_OBJECT) -> NtResult<Device<T>> {
+        const {
+            assert!(
+                align_of::<Extension<T>>() <= 8,
+                "device extensions cannot be aligned to more than 8 bytes"
+            )
+        };
+        if self.symlink.is_some() && self.name.is_none() {
+            return Err(STATUS_INVALID_PARAMETER);
+        }
+
+        let mut device = PDEVICE_OBJECT::default();
+        unsafe {
+            IoCreateDevice(
+                driver,
+                size_of::<Extension<T>>() as u32,
+                self.name
+                    .map_or(core::ptr::null_mut(), |name| name as *const _ as *mut _),
+                self.device_type,
+                self.characteristics,
+                self.exclusive.into(),
+                &mut device,
+            )
+        }
+        .into_nt_result()?;
+
+        // SAFETY: IoCreateDevice succeeded, so `device` is a device object
+        // with an extension of the requested size, which is pool memory and
+        // therefore aligned to at least 8 bytes.
+        let mut device = unsafe {
+            (*device).Flags |= self.flags;
+            (*device)
+                .DeviceExtension
+                .cast::<Extension<T>>()
+                .write(Extension {
+                    symlink: None,
+                    value: T::default(),
+                });
+            Device::from_raw(device)
+        };
+
+        if let (Some(symlink), Some(name)) = (self.symlink, self.name) {
+            // On failure, dropping `device` deletes the device object.
+            device.create_symlink(symlink, name)?;
+        }
+        Ok(device)
+    }
+}
+
+/// A device object created by [`DeviceBuilder`]. Dropping it deletes the
+/// symbolic link, drops the extension and deletes the device object.
+pub struct Device<T = ()> {
+    device: NonNull<DEVICE_OBJECT>,
+    extension: PhantomData<T>,
+}
+
+// SAFETY: Device objects can be used and deleted from any thread, and the
+// builder only creates extensions that are `Send` and `Sync`.
+unsafe impl<T: Send + Sync> Send for Device<T> {}
+unsafe impl<T: Send + Sync> Sync for Device<T> {}
+
+impl<T> Device<T> {
+    /// Takes back ownership of a device from [`into_raw`](Self::into_raw),
+    /// e.g. `driver.DeviceObject` in the unload routine.
+    ///
+    /// # Safety
+    /// `device` must come from `into_raw` of a `Device<T>` with the same `T`
+    /// and must not be used afterwards.
+    pub unsafe fn from_raw(device: PDEVICE_OBJECT) -> Self {
+        Self {
+            device: NonNull::new(device).expect("device pointer is null"),
+            extension: PhantomData,
+        }
+    }
+
+    /// Releases the device, which stays linked to the driver object until
+    /// it is taken back with [`from_raw`](Self::from_raw).
+    pub fn into_raw(self) -> PDEVICE_OBJECT {
+        let device = self.as_ptr();
+        core::mem::forget(self);
+        device
+    }
+
+    pub fn as_ptr(&self) -> PDEVICE_OBJECT {
+        self.device.as_ptr()
+    }
+
+    pub fn extension(&self) -> &T {
+        // SAFETY: The extension was initialized by `build`.
+        unsafe { &(*self.extension_ptr()).value }
+    }
+
+    /// The extension of a device passed to a dispatch routine.
+    ///
+    /// # Safety
+    /// `device` must have been built with an extension of type `T` and must
+    /// outlive `'a`.
+    pub unsafe fn extension_of<'a>(device: PDEVICE_OBJECT) -> &'a T {
+        unsafe { &(*(*device).DeviceExtension.cast::<Extension<T>>()).value }
+    }
+
+    /// Creates a symbolic link to the device named `name`, deleted together
+    /// with the device. [`DeviceBuilder::symlink`] does this on `build`; a
+    /// device has at most one link.
+    pub fn create_symlink(&mut self, symlink: &UNICODE_STRING, name: &UNICODE_STRING) -> NtResult {
+        // SAFETY: The extension was initialized by `build`.
+        let extension = unsafe { &mut *self.extension_ptr() };
+        if extension.symlink.is_some() {
+            return Err(STATUS_INVALID_DEVICE_STATE);
+        }
+        let symlink = unsafe { UnicodeString::copy_from_raw_in(symlink, Pool::default()) }
+            .map_err(|error| match error {
+                Error::AllocationFailed => STATUS_INSUFFICIENT_RESOURCES,
+                _ => STATUS_INVALID_PARAMETER,
+            })?;
+        unsafe {
+            IoCreateSymbolicLink(
+                symlink.as_raw() as *const _ as *mut _,
+                name as *const _ as *mut _,
+            )
+        }
+        .into_nt_result()?;
+        extension.symlink = Some(symlink);
+        Ok(())
+    }
+
+    fn extension_ptr(&self) -> *mut Extension<T> {
+        // SAFETY: The device object is valid while owned.
+        unsafe { (*self.as_ptr()).DeviceExtension.cast() }
+    }
+}
+
+/// A device a request was sent to, borrowed for the duration of the
+/// dispatch routine. A driver with several devices tells them apart by
+/// [`as_ptr`](Self::as_ptr), e.g. against [`Device::as_ptr`].
+#[derive(Clone, Copy)]
+pub struct DeviceRef<'a> {
+    device: NonNull<DEVICE_OBJECT>,
+    lifetime: PhantomData<&'a DEVICE_OBJECT>,
+}
+
+impl<'a> DeviceRef<'a> {
+    /// # Safety
+    /// `device` must be a valid device object that outlives `'a`.
+    pub unsafe fn from_raw(device: PDEVICE_OBJECT) -> Self {
+        Self {
+            device: NonNull::new(device).expect("device pointer is null"),
+            lifetime: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> PDEVICE_OBJECT {
+        self.device.as_ptr()
+    }
+
+    /// The extension of the device.
+    ///
+    /// # Safety
+    /// The device must have been built by [`DeviceBuilder`] with an
+    /// extension of type `T`.
+    pub unsafe fn extension<T>(&self) -> &'a T {
+        unsafe { Device::<T>::extension_of(self.as_ptr()) }
+    }
+}
+
+impl<T> Drop for Device<T> {
+    fn drop(&mut self) {
+        let extension = self.extension_ptr();
+        // SAFETY: The device is owned, and its extension was initialized by
+        // `build`; nothing uses either once the device object is deleted.
+        unsafe {
+            if let Some(symlink) = &(*extension).symlink {
+                let _ = IoDeleteSymbolicLink(symlink.as_raw() as *const _ as *mut _);
+            }
+            core::ptr::drop_in_place(extension);
+            IoDeleteDevice(self.as_ptr());
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! The WDM entry point and dispatch wiring, generated from a [`Driver`]
+//! implementation by [`driver_entry!`](crate::driver_entry).
+//!
+//! ```ignore
+//! struct Zero {
+//!     _device: Device,
+//!     total_read: AtomicU64,
+//! }
+//!
+//! impl Driver for Zero {
+//!     fn init(driver: &mut DRIVER_OBJECT, _registry_path: &UNICODE_STRING) -> NtResult<Self> {
+//!         let device = DeviceBuilder::new().name(&DEVICE_NAME).symlink(&DEVICE_SYMLINK).build(driver)?;
+//!         Ok(Self { _device: device, total_read: AtomicU64::new(0) })
+//!     }
+//!
+//!     const CREATE: Option<Handler<Self>> = Some(|_, _, irp| irp.complete(STATUS_SUCCESS, 0));
+//!     const READ: Option<Handler<Self>> = Some(Self::read);
+//! }
+//!
+//! driver_entry!(Zero);
+//! ```
+//!
+//! The state returned by `init` lives in the driver object extension until
+//! unload, so dispatch routines can reach it without `static mut`.
+
+use core::ffi::c_void;
+
+use wdk_sys::{
+    DRIVER_OBJECT, NTSTATUS, PCUNICODE_STRING, PDEVICE_OBJECT, PIRP, STATUS_INVALID_DEVICE_REQUEST,
+    STATUS_INVALID_PARAMETER, STATUS_SUCCESS, UNICODE_STRING,
+    ntddk::{IoAllocateDriverObjectExtension, IoGetDriverObjectExtension},
+};
+use windows_driver_common_util::irp::{
+    IRP_MJ_CLEANUP, IRP_MJ_CLOSE, IRP_MJ_CREATE, IRP_MJ_DEVICE_CONTROL,
+    IRP_MJ_INTERNAL_DEVICE_CONTROL, IRP_MJ_READ, IRP_MJ_SHUTDOWN, IRP_MJ_WRITE,
+};
+
+use crate::{DeviceRef, IntoNtResult, Irp, NtResult};
+
+/// A dispatch routine: given the device the request was sent to, it
+/// completes the IRP or marks it pending and returns what that returned.
+pub type Handler<D> = fn(&D, DeviceRef<'_>, Irp) -> NTSTATUS;
+
+/// A WDM driver and its state.
+///
+/// Handlers are associated constants rather than methods so that
+/// `driver_entry!` knows at compile time which major functions a driver
+/// handles: a method with a default body cannot be told apart from an
+/// override. Implement one by pointing it at a method, e.g.
+/// `const WRITE: Option<Handler<Self>> = Some(Self::write);`.
+///
+/// Each handler left at `None` is not installed, so the I/O manager fails
+/// those requests with `STATUS_INVALID_DEVICE_REQUEST`. Handlers run
+/// concurrently, hence `Sync`.
+pub trait Driver: Sized + Send + Sync + 'static {
+    /// Called from `DriverEntry`, typically to create the devices with
+    /// [`DeviceBuilder`](crate::DeviceBuilder). On failure the driver is not
+    /// loaded.
+    fn init(driver: &mut DRIVER_OBJECT, registry_path: &UNICODE_STRING) -> NtResult<Self>;
+
+    /// Called when the driver is unloaded; dropping the state, e.g. its
+    /// devices, is all the cleanup most drivers need.
+    fn unload(self) {}
+
+    const CREATE: Option<Handler<Self>> = None;
+    const CLOSE: Option<Handler<Self>> = None;
+    const CLEANUP: Option<Handler<Self>> = None;
+    const READ: Option<Handler<Self>> = None;
+    const WRITE: Option<Handler<Self>> = None;
+    const DEVICE_CONTROL: Option<Handler<Self>> = None;
+    const INTERNAL_DEVICE_CONTROL: Option<Handler<Self>> = None;
+    /// Only sent to devices registered with `IoRegisterShutdownNotification`.
+    const SHUTDOWN: Option<Handler<Self>> = None;
+}
+
+/// Identifies the driver object extension holding the state.
+static STATE_ID: u8 = 0;
+
+fn state_id() -> *mut c_void {
+    &STATE_ID as *const u8 as *mut c_void
+}
+
+/// The body of the `DriverEntry` generated by `driver_entry!`.
+///
+/// # Safety
+/// Must only be called as `DriverEntry`, with its arguments.
+#[doc(hidden)]
+pub unsafe fn entry<D: Driver>(
+    driver: &mut DRIVER_OBJECT,
+    registry_path: PCUNICODE_STRING,
+) -> NTSTATUS {
+    const {
+        assert!(
+            align_of::<D>() <= 8,
+            "driver state cannot be aligned to more than 8 bytes"
+        )
+    };
+    let Some(registry_path) = (unsafe { registry_path.as_ref() }) else {
+        return STATUS_INVALID_PARAMETER;
+    };
+    let state = match D::init(driver, registry_path) {
+        Ok(state) => state,
+        Err(status) => return status,
+    };
+
+    let mut extension = core::ptr::null_mut();
+    let allocated = unsafe {
+        IoAllocateDriverObjectExtension(driver, state_id(), size_of::<D>() as u32, &mut extension)
+    }
+    .into_nt_result();
+    if let Err(status) = allocated {
+        state.unload();
+        return status;
+    }
+    // SAFETY: The extension is pool memory of the size of `D`, aligned to
+    // at least 8 bytes, and owned by the driver object until it is freed
+    // after unload.
+    unsafe { extension.cast::<D>().write(state) };
+
+    driver.DriverUnload = Some(unload::<D>);
+    let handlers = [
+        (IRP_MJ_CREATE, D::CREATE.is_some()),
+        (IRP_MJ_CLOSE, D::CLOSE.is_some()),
+        (IRP_MJ_CLEANUP, D::CLEANUP.is_some()),
+        (IRP_MJ_READ, D::READ.is_some()),
+        (IRP_MJ_WRITE, D::WRITE.is_some()),
+        (IRP_MJ_DEVICE_CONTROL, D::DEVICE_CONTROL.is_some()),
+        (
+            IRP_MJ_INTERNAL_DEVICE_CONTROL,
+            D::INTERNAL_DEVICE_CONTROL.is_some(),
+        ),
+        (IRP_MJ_SHUTDOWN, D::SHUTDOWN.is_some()),
+    ];
+    for (major, implemented) in handlers {
+        if implemented {
+            driver.MajorFunction[major as usize] = Some(dispatch::<D>);
+        }
+    }
+    STATUS_SUCCESS
+}
+
+/// # Safety
+/// `driver` must be the driver object `entry::<D>` stored the state in.
+unsafe fn state<D: Driver>(driver: *mut DRIVER_OBJECT) -> *mut D {
+    unsafe { IoGetDriverObjectExtension(driver, state_id()).cast() }
+}
+
+unsafe extern "C" fn dispatch<D: Driver>(device: PDEVICE_OBJECT, irp: PIRP) -> NTSTATUS {
+    // SAFETY: The I/O manager passes an IRP the driver owns, for one of its
+    // devices, whose driver object holds the state until unload.
+    let (irp, state, device) = unsafe {
+        (
+            Irp::from_raw(irp),
+            &*state::<D>((*device).DriverObject),
+            DeviceRef::from_raw(device),
+        )
+    };
+    let handler = match irp.stack_location().major_function() {
+        IRP_MJ_CREATE => D::CREATE,
+        IRP_MJ_CLOSE => D::CLOSE,
+        IRP_MJ_CLEANUP => D::CLEANUP,
+        IRP_MJ_READ => D::READ,
+        IRP_MJ_WRITE => D::WRITE,
+        IRP_MJ_DEVICE_CONTROL => D::DEVICE_CONTROL,
+        IRP_MJ_INTERNAL_DEVICE_CONTROL => D::INTERNAL_DEVICE_CONTROL,
+        IRP_MJ_SHUTDOWN => D::SHUTDOWN,
+        _ => None,
+    };
+    match handler {
+        Some(handler) => handler(state, device, irp),
+        None => irp.complete(STATUS_INVALID_DEVICE_REQUEST, 0),
+    }
+}
+
+unsafe extern "C" fn unload<D: Driver>(driver: *mut DRIVER_OBJECT) {
+    // SAFETY: No request is dispatched anymore, so the state can be moved
+    // out; the I/O manager frees the extension afterwards.
+    let state = unsafe { state::<D>(driver).read() };
+    state.unload();
+}
+
+/// Generates the `DriverEntry` of a WDM driver from its [`Driver`]
+/// implementation, together with the panic handler of `wdk-panic` and the
+/// `wdk-alloc` global allocator, which the driver crate must depend on.
+///
+/// ```ignore
+/// driver_entry!(Zero);
+/// ```
+#[macro_export]
+macro_rules! driver_entry {
+    ($driver:ty) => {
+        #[cfg(not(test))]
+        extern crate wdk_panic;
+
+        #[cfg(not(test))]
+        #[global_allocator]
+        static GLOBAL_ALLOCATOR: ::wdk_alloc::WdkAllocator = ::wdk_alloc::WdkAllocator;
+
+        // SAFETY: "DriverEntry" is the required symbol name for Windows driver entry points.
+        // No other function in this compilation unit exports this name, preventing symbol conflicts.
+        #[unsafe(export_name = "DriverEntry")]
+        pub unsafe extern "system" fn driver_entry(
+            driver: &mut ::wdk_sys::DRIVER_OBJECT,
+            registry_path: ::wdk_sys::PCUNICODE_STRING,
+        ) -> ::wdk_sys::NTSTATUS {
+            unsafe { $crate::driver::entry::<$driver>(driver, registry_path) }
+        }
+    };
+}
@@ -0,0 +1,156 @@
+//! An owned handle to an IRP, which must be completed exactly once.
+//!
+//! A dispatch routine wraps its IRP in an [`Irp`] and returns what
+//! [`Irp::complete`] or [`Irp::mark_pending`] returns, so the dispatch status
+//! always agrees with `IoStatus.Status`:
+//!
+//! ```ignore
+//! unsafe extern "C" fn dispatch_create_close(_device: PDEVICE_OBJECT, irp: PIRP) -> NTSTATUS {
+//!     unsafe { Irp::from_raw(irp) }.complete(STATUS_SUCCESS, 0)
+//! }
+//! ```
+
+use core::ptr::NonNull;
+
+use wdk_sys::{
+    IO_NO_INCREMENT, IRP, NTSTATUS, PIRP, SL_PENDING_RETURNED, STATUS_PENDING,
+    ntddk::IofCompleteRequest,
+};
+
+use crate::buffer::{InputBuffer, OutputBuffer};
+use crate::stack::StackLocation;
+use crate::{IoGetCurrentIrpStackLocation, NtError, NtResult, completion};
+
+/// An IRP the driver owns, until it completes it or marks it pending.
+///
+/// Dropping it without doing either fails a debug assertion: the I/O
+/// manager would wait for the request forever.
+pub struct Irp {
+    irp: NonNull<IRP>,
+}
+
+impl Irp {
+    /// Takes ownership of the IRP passed to a dispatch routine.
+    ///
+    /// # Safety
+    /// `irp` must point to an IRP the caller owns and has neither completed
+    /// nor passed on.
+    pub unsafe fn from_raw(irp: PIRP) -> Self {
+        Self {
+            irp: NonNull::new(irp).expect("irp pointer is null"),
+        }
+    }
+
+    pub fn as_ptr(&self) -> PIRP {
+        self.irp.as_ptr()
+    }
+
+    /// The driver's stack location, with typed parameters.
+    pub fn stack_location(&self) -> StackLocation<'_> {
+        StackLocation::current(self)
+    }
+
+    /// The input of a write or device control request, see
+    /// [`buffer`](crate::buffer).
+    pub fn input(&self) -> NtResult<InputBuffer<'_>> {
+        InputBuffer::of(self)
+    }
+
+    /// The output of a read or device control request, see
+    /// [`buffer`](crate::buffer). It borrows the IRP mutably since buffered
+    /// device control requests share one buffer for input and output.
+    pub fn output(&mut self) -> NtResult<OutputBuffer<'_>> {
+        OutputBuffer::of(self)
+    }
+
+    /// Completes the IRP with `status` and `information` and returns
+    /// `status`, the value for the dispatch routine to return.
+    #[must_use = "the dispatch routine must return the completion status"]
+    pub fn complete(self, status: NTSTATUS, information: usize) -> NTSTATUS {
+        debug_assert!(
+            status != STATUS_PENDING,
+            "IRPs cannot be completed as pending"
+        );
+        let irp = self.into_raw();
+        unsafe {
+            (*irp).IoStatus.__bindgen_anon_1.Status = status;
+            (*irp).IoStatus.Information = information as _;
+            IofCompleteRequest(irp, IO_NO_INCREMENT as i8);
+        }
+        status
+    }
+
+    /// Completes the IRP with the status and information derived from
+    /// `result`, see [`completion`](crate::completion).
+    #[must_use = "the dispatch routine must return the completion status"]
+    pub fn complete_with<E: Into<NtError>>(self, result: Result<usize, E>) -> NTSTATUS {
+        let (status, information) = completion(result);
+        self.complete(status, information)
+    }
+
+    /// Marks the IRP pending and hands it to `queue`, e.g. to complete it
+    /// from a DPC or worker thread. Returns `STATUS_PENDING`, the value for
+    /// the dispatch routine to return.
+    #[must_use = "the dispatch routine must return STATUS_PENDING"]
+    pub fn mark_pending(self, queue: impl FnOnce(PendingIrp)) -> NTSTATUS {
+        // IoMarkIrpPending, which must happen before another thread can
+        // complete the IRP.
+        unsafe {
+            (*IoGetCurrentIrpStackLocation(self.as_ptr())).Control |= SL_PENDING_RETURNED as u8;
+        }
+        queue(PendingIrp(self));
+        STATUS_PENDING
+    }
+
+    fn into_raw(self) -> PIRP {
+        let irp = self.as_ptr();
+        core::mem::forget(self);
+        irp
+    }
+}
+
+// SAFETY: IRPs can be completed from any thread, e.g. a worker thread
+// processing a queue of pending requests.
+unsafe impl Send for Irp {}
+
+impl Drop for Irp {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) {
+            panic!("IRP dropped without being completed or marked pending");
+        }
+    }
+}
+
+/// An IRP marked pending, to be completed later, typically outside the
+/// dispatch routine. Dropping it without completion fails a debug assertion
+/// like dropping an [`Irp`].
+pub struct PendingIrp(Irp);
+
+impl PendingIrp {
+    /// Takes back ownership of an IRP from [`into_raw`](Self::into_raw).
+    ///
+    /// # Safety
+    /// `irp` must come from `into_raw` and must not be used afterwards.
+    pub unsafe fn from_raw(irp: PIRP) -> Self {
+        Self(unsafe { Irp::from_raw(irp) })
+    }
+
+    /// Releases the IRP, e.g. to link it into a queue through its
+    /// `Tail.Overlay.ListEntry`. It must be taken back with
+    /// [`from_raw`](Self::from_raw) to be completed.
+    pub fn into_raw(self) -> PIRP {
+        self.0.into_raw()
+    }
+
+    pub fn as_ptr(&self) -> PIRP {
+        self.0.as_ptr()
+    }
+
+    pub fn complete(self, status: NTSTATUS, information: usize) {
+        let _ = self.0.complete(status, information);
+    }
+
+    pub fn complete_with<E: Into<NtError>>(self, result: Result<usize, E>) {
+        let _ = self.0.complete_with(result);
+    }
+}
@@ -1,5 +1,21 @@
 #![no_std]
 
+extern crate alloc;
+
+pub mod buffer;
+pub mod device;
+pub mod driver;
+pub mod irp;
+pub mod result;
+pub mod stack;
+
+pub use buffer::{InputBuffer, OutputBuffer};
+pub use device::{Device, DeviceBuilder, DeviceRef};
+pub use driver::{Driver, Handler};
+pub use irp::{Irp, PendingIrp};
+pub use result::{IntoNtResult, NtError, NtResult, complete_request, completion, nt_status};
+pub use stack::{Parameters, StackLocation};
+
 use wdk_sys::{
    PIO_STACK_LOCATION, PIRP, MDL, MDL_MAPPED_TO_SYSTEM_VA, MDL_SOURCE_IS_NONPAGED_POOL,
 };
@@ -0,0 +1,123 @@
+//! `Result`-based status handling, so dispatch and initialization routines can
+//! propagate failures with `?` instead of threading a mutable status through
+//! `loop { ... break; }` blocks.
+//!
+//! ```ignore
+//! unsafe extern "C" fn dispatch_read(_device: PDEVICE_OBJECT, irp: PIRP) -> NTSTATUS {
+//!     unsafe { complete_request(irp, read(irp)) }
+//! }
+//!
+//! unsafe fn read(irp: PIRP) -> NtResult<usize> {
+//!     let stack = unsafe { IoGetCurrentIrpStackLocation(irp).as_ref() }.into_nt_result()?;
+//!     ...
+//!     Ok(bytes_read)
+//! }
+//! ```
+//!
+//! Requests completing with another status and a byte count, typically
+//! `STATUS_BUFFER_OVERFLOW` with the part of the output that fit, return
+//! an [`NtError`] carrying both:
+//!
+//! ```ignore
+//! fn get_names(irp: &mut Irp) -> Result<usize, NtError> {
+//!     let mut output = irp.output()?;
+//!     let written = write_names(&mut output);
+//!     if written < NAMES.len() {
+//!         return Err(NtError::new(STATUS_BUFFER_OVERFLOW, written));
+//!     }
+//!     Ok(written)
+//! }
+//! ```
+
+use wdk_sys::{NT_SUCCESS, NTSTATUS, PIRP, STATUS_INVALID_PARAMETER, STATUS_SUCCESS};
+
+use crate::irp::Irp;
+
+/// The outcome of a kernel operation: a value, or the failure status.
+pub type NtResult<T = ()> = Result<T, NTSTATUS>;
+
+/// Conversion of the ways kernel routines report failure into an [`NtResult`].
+pub trait IntoNtResult {
+    type Output;
+
+    fn into_nt_result(self) -> NtResult<Self::Output>;
+}
+
+/// Success and informational values, as tested by `NT_SUCCESS`, become `Ok`.
+impl IntoNtResult for NTSTATUS {
+    type Output = ();
+
+    fn into_nt_result(self) -> NtResult {
+        if NT_SUCCESS(self) { Ok(()) } else { Err(self) }
+    }
+}
+
+/// `None`, typically a null pointer seen through `as_ref` or `NonNull::new`,
+/// becomes `STATUS_INVALID_PARAMETER`.
+impl<T> IntoNtResult for Option<T> {
+    type Output = T;
+
+    fn into_nt_result(self) -> NtResult<T> {
+        self.ok_or(STATUS_INVALID_PARAMETER)
+    }
+}
+
+/// The status and `Information` of a request that did not simply succeed:
+/// a failure, or a warning or informational status that still transferred
+/// data, e.g. `STATUS_BUFFER_OVERFLOW` with the number of bytes that fit.
+///
+/// An `NTSTATUS` converts into one without information, so `?` works on
+/// [`NtResult`]s in routines returning `Result<usize, NtError>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtError {
+    pub status: NTSTATUS,
+    pub information: usize,
+}
+
+impl NtError {
+    pub const fn new(status: NTSTATUS, information: usize) -> Self {
+        Self {
+            status,
+            information,
+        }
+    }
+}
+
+impl From<NTSTATUS> for NtError {
+    fn from(status: NTSTATUS) -> Self {
+        Self::new(status, 0)
+    }
+}
+
+/// The status and information to complete a request with: `Ok(information)`
+/// becomes `STATUS_SUCCESS` and the number of bytes transferred, an error
+/// its status and information, zero for a plain `NTSTATUS`.
+pub fn completion<E: Into<NtError>>(result: Result<usize, E>) -> (NTSTATUS, usize) {
+    match result {
+        Ok(information) => (STATUS_SUCCESS, information),
+        Err(error) => {
+            let error: NtError = error.into();
+            (error.status, error.information)
+        }
+    }
+}
+
+/// The status to return for `result`: `STATUS_SUCCESS` or the error's.
+pub fn nt_status<T, E: Clone + Into<NtError>>(result: &Result<T, E>) -> NTSTATUS {
+    match result {
+        Ok(_) => STATUS_SUCCESS,
+        Err(error) => Into::<NtError>::into(error.clone()).status,
+    }
+}
+
+/// Completes `irp` with the status and information derived from `result`,
+/// see [`completion`].
+///
+/// # Returns
+/// The completion status, to be returned from the dispatch routine.
+///
+/// # Safety
+/// As for [`Irp::from_raw`]; the caller must not access `irp` afterwards.
+pub unsafe fn complete_request<E: Into<NtError>>(irp: PIRP, result: Result<usize, E>) -> NTSTATUS {
+    unsafe { Irp::from_raw(irp) }.complete_with(result)
+}
@@ -0,0 +1,150 @@
+//! A typed view of the current `IO_STACK_LOCATION` of an IRP.
+//!
+//! The meaning of `Parameters` depends on `MajorFunction`. [`Parameters`]
+//! reads the union arm that matches it, so code cannot read
+//! `Parameters.Write.Length` of a read request:
+//!
+//! ```ignore
+//! let Parameters::Read { length, .. } = irp.stack_location().parameters() else {
+//!     return Err(STATUS_INVALID_DEVICE_REQUEST);
+//! };
+//! ```
+
+use wdk_sys::IO_STACK_LOCATION;
+use windows_driver_common_util::irp::{
+    IRP_MJ_CLEANUP, IRP_MJ_CLOSE, IRP_MJ_CREATE, IRP_MJ_DEVICE_CONTROL,
+    IRP_MJ_INTERNAL_DEVICE_CONTROL, IRP_MJ_READ, IRP_MJ_SHUTDOWN, IRP_MJ_WRITE,
+};
+
+use crate::{IoGetCurrentIrpStackLocation, Irp};
+
+/// The parameters of a request, by major function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parameters {
+    Create {
+        /// The `DesiredAccess` of the security context.
+        access: u32,
+        /// `CreateDisposition` in the high byte, the `FILE_*` create options
+        /// in the low 24 bits.
+        options: u32,
+        /// The `FILE_SHARE_*` flags.
+        share: u16,
+    },
+    Read {
+        length: u32,
+        offset: i64,
+        key: u32,
+    },
+    Write {
+        length: u32,
+        offset: i64,
+        key: u32,
+    },
+    DeviceControl {
+        code: u32,
+        in_len: u32,
+        out_len: u32,
+    },
+    InternalDeviceControl {
+        code: u32,
+        in_len: u32,
+        out_len: u32,
+    },
+    Cleanup,
+    Close,
+    Shutdown,
+    /// A major function without a typed view.
+    Other {
+        major: u8,
+        minor: u8,
+    },
+}
+
+/// The stack location of the driver processing an [`Irp`].
+#[derive(Clone, Copy)]
+pub struct StackLocation<'a> {
+    stack: &'a IO_STACK_LOCATION,
+}
+
+impl<'a> StackLocation<'a> {
+    /// The current stack location of `irp`.
+    pub fn current(irp: &'a Irp) -> Self {
+        // SAFETY: The IRP is owned, so it has a current stack location that
+        // stays valid while it is borrowed.
+        let stack = unsafe { &*IoGetCurrentIrpStackLocation(irp.as_ptr()) };
+        Self { stack }
+    }
+
+    pub fn major_function(&self) -> u8 {
+        self.stack.MajorFunction
+    }
+
+    pub fn minor_function(&self) -> u8 {
+        self.stack.MinorFunction
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        let parameters = &self.stack.Parameters;
+        // SAFETY: Each arm reads the union member the I/O manager fills in
+        // for the major function.
+        unsafe {
+            match self.stack.MajorFunction {
+                IRP_MJ_CREATE => {
+                    let create = &parameters.Create;
+                    Parameters::Create {
+                        access: create
+                            .SecurityContext
+                            .as_ref()
+                            .map_or(0, |context| context.DesiredAccess),
+                        options: create.Options,
+                        share: create.ShareAccess,
+                    }
+                }
+                IRP_MJ_READ => {
+                    let read = &parameters.Read;
+                    Parameters::Read {
+                        length: read.Length,
+                        offset: read.ByteOffset.QuadPart,
+                        key: read.Key,
+                    }
+                }
+                IRP_MJ_WRITE => {
+                    let write = &parameters.Write;
+                    Parameters::Write {
+                        length: write.Length,
+                        offset: write.ByteOffset.QuadPart,
+                        key: write.Key,
+                    }
+                }
+                IRP_MJ_DEVICE_CONTROL => {
+                    let control = &parameters.DeviceIoControl;
+                    Parameters::DeviceControl {
+                        code: control.IoControlCode,
+                        in_len: control.InputBufferLength,
+                        out_len: control.OutputBufferLength,
+                    }
+                }
+                IRP_MJ_INTERNAL_DEVICE_CONTROL => {
+                    let control = &parameters.DeviceIoControl;
+                    Parameters::InternalDeviceControl {
+                        code: control.IoControlCode,
+                        in_len: control.InputBufferLength,
+                        out_len: control.OutputBufferLength,
+                    }
+                }
+                IRP_MJ_CLEANUP => Parameters::Cleanup,
+                IRP_MJ_CLOSE => Parameters::Close,
+                IRP_MJ_SHUTDOWN => Parameters::Shutdown,
+                _ => Parameters::Other {
+                    major: self.stack.MajorFunction,
+                    minor: self.stack.MinorFunction,
+                },
+            }
+        }
+    }
+
+    /// The raw stack location, for parameters without a typed view.
+    pub fn as_raw(&self) -> &'a IO_STACK_LOCATION {
+        self.stack
+    }
+}